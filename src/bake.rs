@@ -0,0 +1,352 @@
+use crate::tween::{Interpolator, ParallelPolicy, RepeatDecay, RepeatTimes, Tween, TweenApplier};
+use std::borrow::BorrowMut;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A tween precomputed by [`Tween::bake`]: the eased values of each step are sampled into a table,
+/// which is looked up instead of evaluating easings and walking the tween. Clones share the tables,
+/// so many targets can play the same baked tween - each at its own time.
+#[derive(Clone)]
+pub struct BakedTween<T> {
+    root: BakedNode<T>,
+}
+
+#[derive(Clone)]
+enum BakedNode<T> {
+    Step(BakedStep<T>),
+    Pause(Duration),
+    /// The children with their start times.
+    Sequence {
+        children: Vec<(Duration, BakedNode<T>)>,
+        duration: Duration,
+    },
+    /// The body is baked once and played `times` times.
+    Repeat {
+        body: Box<BakedNode<T>>,
+        times: u32,
+        duration: Duration,
+    },
+    Parallel {
+        children: Vec<BakedNode<T>>,
+        duration: Duration,
+    },
+}
+
+#[derive(Clone)]
+struct BakedStep<T> {
+    duration: Duration,
+    /// Eased values, evenly spaced from the start to the end of the step.
+    values: Arc<[f32]>,
+    applier: Box<dyn TweenApplier<T>>,
+}
+
+impl<T> BakedTween<T> {
+    pub fn duration(&self) -> Duration {
+        self.root.duration()
+    }
+
+    /// Applies the value of the step active at `position`, the time played. During a pause, the
+    /// step before it is applied with its final value.
+    pub fn apply_at(&mut self, target: &mut T, position: Duration) {
+        self.apply_batch(std::slice::from_mut(target), position);
+    }
+
+    /// Applies the values at `position` to all `targets`, like [`BakedTween::apply_at`]. The active
+    /// steps are found and their values looked up once, then applied to the whole slice.
+    pub fn apply_batch<R: BorrowMut<T>>(&mut self, targets: &mut [R], position: Duration) {
+        let position = position.min(self.duration());
+        self.root.apply(targets, position);
+    }
+}
+
+impl<T> BakedNode<T> {
+    fn duration(&self) -> Duration {
+        match self {
+            BakedNode::Step(step) => step.duration,
+            BakedNode::Pause(duration)
+            | BakedNode::Sequence { duration, .. }
+            | BakedNode::Repeat { duration, .. }
+            | BakedNode::Parallel { duration, .. } => *duration,
+        }
+    }
+
+    /// Applies the step active at `position` to all `targets`, `position` must not be after the
+    /// end. Returns `false` if nothing was applied (ie. in a pause).
+    fn apply<R: BorrowMut<T>>(&mut self, targets: &mut [R], position: Duration) -> bool {
+        match self {
+            BakedNode::Step(step) => {
+                let value = step.value_at(position);
+                for target in targets {
+                    step.applier.apply(target.borrow_mut(), value);
+                }
+                true
+            }
+            BakedNode::Pause(_) => false,
+            BakedNode::Sequence { children, .. } => {
+                let started = children.partition_point(|(start, _)| *start <= position);
+                // Fall back to the end of earlier steps, if the active one applies nothing
+                children[..started].iter_mut().rev().any(|(start, child)| {
+                    let position = (position - *start).min(child.duration());
+                    child.apply(targets, position)
+                })
+            }
+            BakedNode::Repeat { body, times, .. } => {
+                let length = body.duration().as_nanos();
+                if *times == 0 {
+                    return false;
+                }
+                if length == 0 {
+                    return body.apply(targets, Duration::ZERO);
+                }
+                let iteration = position.as_nanos() / length;
+                if iteration >= *times as u128 {
+                    return body.apply(targets, body.duration());
+                }
+                let position = Duration::from_nanos((position.as_nanos() % length) as u64);
+                body.apply(targets, position)
+                    || (iteration > 0 && body.apply(targets, body.duration()))
+            }
+            BakedNode::Parallel { children, .. } => {
+                let mut applied = false;
+                for child in children {
+                    applied |= child.apply(targets, position.min(child.duration()));
+                }
+                applied
+            }
+        }
+    }
+}
+
+impl<T> BakedStep<T> {
+    /// The eased value at `position` of this step.
+    fn value_at(&self, position: Duration) -> f32 {
+        let fraction = if self.duration.is_zero() {
+            1.0
+        } else {
+            (position.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+        };
+        let scaled = fraction * (self.values.len() - 1) as f32;
+        let index = scaled as usize;
+        let next = (index + 1).min(self.values.len() - 1);
+        self.values[index] + (self.values[next] - self.values[index]) * (scaled - index as f32)
+    }
+}
+
+impl<T, E> Tween<T, E> {
+    /// Samples the eased values of this tween `sample_rate` times per second into a
+    /// [`BakedTween`], for hot paths - ie. thousands of UI elements playing the same tween. Only
+    /// steps, pauses, sequences, parallel tweens, repeats and alternations of fixed count and
+    /// duration can be baked (`None` otherwise), events are dropped. Repeated tweens are baked once.
+    pub fn bake(&self, sample_rate: f32) -> Option<BakedTween<T>> {
+        Some(BakedTween {
+            root: self.bake_node(sample_rate)?,
+        })
+    }
+
+    fn bake_node(&self, sample_rate: f32) -> Option<BakedNode<T>> {
+        Some(match self {
+            Tween::Once {
+                duration,
+                function,
+                applier,
+                random_duration: None,
+                ..
+            } => {
+                let samples = ((duration.as_secs_f32() * sample_rate).ceil() as usize).max(1);
+                let values = (0..=samples)
+                    .map(|sample| function.interpolate(sample as f32 / samples as f32))
+                    .collect();
+                BakedNode::Step(BakedStep {
+                    duration: *duration,
+                    values,
+                    applier: applier.clone(),
+                })
+            }
+            Tween::Pause {
+                duration,
+                random_duration: None,
+                ..
+            } => BakedNode::Pause(*duration),
+            Tween::Sequence { tweens, .. } => {
+                let mut duration = Duration::ZERO;
+                let mut children = Vec::with_capacity(tweens.len());
+                for tween in tweens {
+                    let child = tween.bake_node(sample_rate)?;
+                    let start = duration;
+                    duration += child.duration();
+                    children.push((start, child));
+                }
+                BakedNode::Sequence { children, duration }
+            }
+            Tween::Repeat {
+                tween,
+                times: RepeatTimes::N(times),
+                decay,
+                ..
+            } if *decay == RepeatDecay::default() => {
+                let body = tween.bake_node(sample_rate)?;
+                let times = u32::try_from(*times).ok()?;
+                BakedNode::Repeat {
+                    duration: body.duration().checked_mul(times)?,
+                    body: Box::new(body),
+                    times,
+                }
+            }
+            Tween::Alternate {
+                tweens,
+                times: RepeatTimes::N(times),
+                ..
+            } => {
+                let sequence = |tweens: &[Tween<T, E>]| {
+                    let mut duration = Duration::ZERO;
+                    let mut children = Vec::with_capacity(tweens.len());
+                    for tween in tweens {
+                        let child = tween.bake_node(sample_rate)?;
+                        let start = duration;
+                        duration += child.duration();
+                        children.push((start, child));
+                    }
+                    Some(BakedNode::Sequence { children, duration })
+                };
+                // Whole cycles are baked once and repeated, followed by the rest of the last one
+                let length = tweens.len().max(1);
+                let body = sequence(tweens)?;
+                let cycles = u32::try_from(times / length).ok()?;
+                let repeat = BakedNode::Repeat {
+                    duration: body.duration().checked_mul(cycles)?,
+                    body: Box::new(body),
+                    times: cycles,
+                };
+                let rest = sequence(&tweens[..times % length])?;
+                let rest_start = repeat.duration();
+                BakedNode::Sequence {
+                    duration: rest_start + rest.duration(),
+                    children: vec![(Duration::ZERO, repeat), (rest_start, rest)],
+                }
+            }
+            Tween::Parallel { tweens, policy, .. } => {
+                let children = tweens
+                    .iter()
+                    .map(|tween| tween.bake_node(sample_rate))
+                    .collect::<Option<Vec<_>>>()?;
+                let durations = children.iter().map(BakedNode::duration);
+                let duration = match policy {
+                    ParallelPolicy::WhenAll => durations.max(),
+                    ParallelPolicy::WhenAny => durations.min(),
+                };
+                BakedNode::Parallel {
+                    children,
+                    duration: duration.unwrap_or_default(),
+                }
+            }
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::easing::Easing;
+    use crate::tween::{NoEvent, TweenF64};
+
+    #[test]
+    fn tween_bake() {
+        let step = |start, end, easing| {
+            Tween::new(Duration::from_secs(1), easing, TweenF64 { start, end })
+        };
+        let mut tween = Tween::sequence([
+            step(0.0, 1.0, Easing::QuadraticIn),
+            Tween::pause(Duration::from_secs(1)),
+            Tween::repeat(RepeatTimes::N(2), step(1.0, 2.0, Easing::Linear)),
+        ]);
+        let mut baked = tween.bake(100.0).unwrap();
+        assert_eq!(baked.duration(), Duration::from_secs(4));
+
+        let mut played = 0.0;
+        for millis in (0..=4000).step_by(250) {
+            let position = Duration::from_millis(millis);
+            let mut value = 0.0;
+            baked.apply_at(&mut value, position);
+            tween.seek(&mut played, position);
+            assert!(
+                (value - played).abs() < 1e-4,
+                "{position:?}: {value} != {played}"
+            );
+        }
+
+        for millis in [0, 450, 1500, 3250, 5000] {
+            let position = Duration::from_millis(millis);
+            let mut batch = vec![-1.0; 10];
+            baked.apply_batch(&mut batch, position);
+            let mut single = 0.0;
+            baked.apply_at(&mut single, position);
+            assert!(batch.iter().all(|value| *value == single), "{position:?}");
+        }
+
+        let mut tween = Tween::repeat(
+            RepeatTimes::N(1000),
+            Tween::parallel([
+                step(0.0, 1.0, Easing::Linear),
+                Tween::pause(Duration::from_secs(2)),
+            ]),
+        );
+        let mut baked = tween.bake(100.0).unwrap();
+        assert_eq!(baked.duration(), Duration::from_secs(2000));
+        let BakedNode::Repeat { body, .. } = &baked.root else {
+            panic!("repeat is baked once");
+        };
+        assert!(matches!(**body, BakedNode::Parallel { .. }));
+        for millis in [500, 1500, 2250, 1_999_999] {
+            let position = Duration::from_millis(millis);
+            let mut value = 0.0;
+            baked.apply_at(&mut value, position);
+            tween.reset();
+            tween.seek(&mut played, position);
+            assert!(
+                (value - played).abs() < 1e-4,
+                "{position:?}: {value} != {played}"
+            );
+        }
+
+        assert!(Tween::<f64, NoEvent>::repeat(
+            RepeatTimes::Infinite,
+            step(0.0, 1.0, Easing::Linear)
+        )
+        .bake(100.0)
+        .is_none());
+    }
+
+    #[test]
+    fn tween_bake_alternate() {
+        let step = |start, end| {
+            Tween::new(
+                Duration::from_secs(1),
+                Easing::QuadraticOut,
+                TweenF64 { start, end },
+            )
+        };
+        let mut tween = Tween::<_, NoEvent>::alternate(
+            RepeatTimes::N(5),
+            [step(0.0, 1.0), step(1.0, 0.5), Tween::pause(Duration::ZERO)],
+        );
+        let mut baked = tween.bake(100.0).unwrap();
+        assert_eq!(baked.duration(), Duration::from_secs(4));
+
+        // Forward and back again, so the alternation is rewound as well
+        let mut played = 0.0;
+        for millis in (0..=16)
+            .map(|i| i * 250)
+            .chain((0..14).rev().map(|i| i * 300))
+        {
+            let position = Duration::from_millis(millis);
+            let mut value = 0.0;
+            baked.apply_at(&mut value, position);
+            tween.seek(&mut played, position);
+            assert!(
+                (value - played).abs() < 1e-4,
+                "{position:?}: {value} != {played}"
+            );
+        }
+    }
+}
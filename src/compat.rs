@@ -0,0 +1,150 @@
+//! Helps migrating from `bevy_tweening`: its `Lens` trait and common lenses are mirrored here, so
+//! existing lenses only need a different import to be used as [`TweenApplier`]s.
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use std::time::Duration;
+//! use be_tween::compat::*;
+//! use be_tween::{EaseFunction, PlayTween};
+//!
+//! # fn system(mut commands: Commands) {
+//! let tween = tween(
+//!     EaseFunction::QuadraticInOut,
+//!     Duration::from_secs(1),
+//!     TransformPositionLens {
+//!         start: Vec3::ZERO,
+//!         end: Vec3::X,
+//!     },
+//! );
+//! commands.spawn((TransformBundle::default(), PlayTween::new(tween)));
+//! # }
+//! ```
+use crate::tween::{EaseFunction, NoEvent, Tween, TweenApplier};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Same as the `Lens` of `bevy_tweening`.
+pub trait Lens<T> {
+    fn lerp(&mut self, target: &mut T, ratio: f32);
+}
+
+/// Uses a [`Lens`] as [`TweenApplier`].
+#[derive(Clone, Debug)]
+pub struct LensApplier<L>(pub L);
+
+impl<T, L: Lens<T> + Clone + Send + Sync> TweenApplier<T> for LensApplier<L> {
+    fn apply(&mut self, target: &mut T, value: f32) {
+        self.0.lerp(target, value);
+    }
+}
+
+/// Like `bevy_tweening::Tween::new`.
+pub fn tween<T, L: Lens<T> + Clone + Send + Sync + 'static>(
+    ease_function: EaseFunction,
+    duration: Duration,
+    lens: L,
+) -> Tween<T, NoEvent> {
+    Tween::new(duration, ease_function, LensApplier(lens))
+}
+
+/// Like `bevy_tweening::Sequence::new`.
+pub fn sequence<T>(tweens: impl IntoIterator<Item = Tween<T, NoEvent>>) -> Tween<T, NoEvent> {
+    Tween::sequence(tweens.into_iter().collect::<Vec<_>>())
+}
+
+/// Like `bevy_tweening::Delay::new`.
+pub fn delay<T>(duration: Duration) -> Tween<T, NoEvent> {
+    Tween::pause(duration)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransformPositionLens {
+    pub start: Vec3,
+    pub end: Vec3,
+}
+
+impl Lens<Transform> for TransformPositionLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        target.translation = self.start.lerp(self.end, ratio);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransformRotationLens {
+    pub start: Quat,
+    pub end: Quat,
+}
+
+impl Lens<Transform> for TransformRotationLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        target.rotation = self.start.slerp(self.end, ratio);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransformScaleLens {
+    pub start: Vec3,
+    pub end: Vec3,
+}
+
+impl Lens<Transform> for TransformScaleLens {
+    fn lerp(&mut self, target: &mut Transform, ratio: f32) {
+        target.scale = self.start.lerp(self.end, ratio);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpriteColorLens {
+    pub start: Color,
+    pub end: Color,
+}
+
+impl Lens<Sprite> for SpriteColorLens {
+    fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
+        target.color = self.start.mix(&self.end, ratio);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UiBackgroundColorLens {
+    pub start: Color,
+    pub end: Color,
+}
+
+impl Lens<BackgroundColor> for UiBackgroundColorLens {
+    fn lerp(&mut self, target: &mut BackgroundColor, ratio: f32) {
+        target.0 = self.start.mix(&self.end, ratio);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tween::TweenProgress;
+
+    #[derive(Clone)]
+    struct OpacityLens(f32);
+
+    impl Lens<Sprite> for OpacityLens {
+        fn lerp(&mut self, target: &mut Sprite, ratio: f32) {
+            target.color.set_alpha(self.0 * ratio);
+        }
+    }
+
+    #[test]
+    fn lenses_as_appliers() {
+        let mut tween = sequence([
+            delay(Duration::from_secs(1)),
+            tween(
+                EaseFunction::QuadraticIn,
+                Duration::from_secs(1),
+                OpacityLens(0.8),
+            ),
+        ]);
+
+        let mut sprite = Sprite::default();
+        let progress = tween.advance(&mut sprite, &mut NoEvent, Duration::from_millis(1500));
+        assert_eq!(progress, TweenProgress::Running);
+        assert_eq!(sprite.color.alpha(), 0.2);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::tween::{EventSender, Tween, TweenProgress, TweenState, TweenStateMismatch};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// An immutable tween, shared by everything playing it. Each player keeps its own
+/// [`TweenCursor`], so the tween isn't cloned for each of them.
+///
+/// Playing a cursor restores it onto a scratch copy of the tween, so appliers should not keep
+/// state of their own (ie. [`TweenOffset`](crate::TweenOffset) would be shared by all cursors).
+pub struct TweenDef<T, E> {
+    tween: Arc<Tween<T, E>>,
+}
+
+/// The playback state of a [`TweenDef`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TweenCursor {
+    state: TweenState,
+}
+
+impl<T, E> Clone for TweenDef<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            tween: self.tween.clone(),
+        }
+    }
+}
+
+impl<T, E> TweenDef<T, E> {
+    pub fn new(mut tween: Tween<T, E>) -> Self {
+        tween.reset_deep();
+        Self {
+            tween: Arc::new(tween),
+        }
+    }
+
+    /// A cursor at the start of the tween.
+    pub fn cursor(&self) -> TweenCursor {
+        TweenCursor {
+            state: self.tween.state(),
+        }
+    }
+
+    /// Whether both share the same tween.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.tween, &other.tween)
+    }
+
+    pub fn duration(&self) -> Option<Duration> {
+        self.tween.duration()
+    }
+
+    /// Whether anything besides this handle still uses the tween.
+    #[cfg(feature = "bevy")]
+    pub(crate) fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.tween) > 1
+    }
+}
+
+impl<T: Clone, E: Clone> TweenDef<T, E> {
+    /// A copy of the tween to play cursors with, it can be reused for all of them.
+    pub fn scratch(&self) -> Tween<T, E> {
+        (*self.tween).clone()
+    }
+
+    /// Advances the cursor, using a `scratch` copy of this tween. Returns whether anything was
+    /// applied to the target, like [`Tween::advance_changed`]. Fails for a cursor of another
+    /// tween, leaving it untouched.
+    pub fn advance<ES: EventSender<E>>(
+        &self,
+        scratch: &mut Tween<T, E>,
+        cursor: &mut TweenCursor,
+        target: &mut T,
+        event_sender: &mut ES,
+        duration: Duration,
+    ) -> Result<(TweenProgress, bool), TweenStateMismatch> {
+        if scratch.restore_state(&cursor.state).is_err() {
+            // Not a copy of this tween
+            *scratch = self.scratch();
+            scratch.restore_state(&cursor.state)?;
+        }
+        let result = scratch.advance_changed(target, event_sender, duration);
+        scratch.state_into(&mut cursor.state);
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tween::{Lerp, NoEvent, RepeatTimes, SetValue};
+
+    #[test]
+    fn cursors_share_def() {
+        let def = TweenDef::<f32, NoEvent>::new(Tween::repeat(
+            RepeatTimes::N(2),
+            Tween::new(Duration::from_secs(2), Lerp, SetValue),
+        ));
+        let mut scratch = def.scratch();
+        let (mut first, mut second) = (def.cursor(), def.cursor());
+        let (mut a, mut b) = (0.0, 0.0);
+
+        def.advance(
+            &mut scratch,
+            &mut first,
+            &mut a,
+            &mut NoEvent,
+            Duration::from_secs(3),
+        )
+        .unwrap();
+        def.advance(
+            &mut scratch,
+            &mut second,
+            &mut b,
+            &mut NoEvent,
+            Duration::from_secs(1),
+        )
+        .unwrap();
+        assert_eq!((a, b), (0.5, 0.5));
+
+        def.advance(
+            &mut scratch,
+            &mut second,
+            &mut b,
+            &mut NoEvent,
+            Duration::from_millis(500),
+        )
+        .unwrap();
+        assert_eq!(b, 0.75);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn cursor_of_another_def() {
+        let def = TweenDef::<f32, NoEvent>::new(Tween::new(Duration::from_secs(2), Lerp, SetValue));
+        let other = TweenDef::<f32, NoEvent>::new(Tween::sequence([
+            Tween::new(Duration::from_secs(1), Lerp, SetValue),
+            Tween::new(Duration::from_secs(1), Lerp, SetValue),
+        ]));
+        let mut scratch = def.scratch();
+        let mut cursor = other.cursor();
+
+        let result = def.advance(
+            &mut scratch,
+            &mut cursor,
+            &mut 0.0,
+            &mut NoEvent,
+            Duration::from_secs(1),
+        );
+        assert_eq!(result, Err(TweenStateMismatch));
+        assert_eq!(cursor, other.cursor());
+    }
+}
@@ -0,0 +1,293 @@
+//! Tweens on components only known at runtime (ie. named in data files or scripts), resolved
+//! through reflection. Slower than [`PlayTween`](crate::PlayTween), which should be preferred
+//! where the component type is known.
+use crate::plugin::ApplyErrorPolicy;
+use crate::tween::{ApplyError, NoEvent, Tween, TweenApplier, TweenProgress};
+use bevy::ecs::reflect::{AppTypeRegistry, ReflectComponent};
+use bevy::prelude::*;
+use bevy::reflect::GetPath;
+use bevy::utils::{Entry, HashMap};
+use std::any::TypeId;
+
+/// Values of reflected fields [`TweenReflectField`] can tween.
+pub trait ReflectLerp: Reflect + Clone + Send + Sync {
+    fn lerp(&self, end: &Self, value: f32) -> Self;
+}
+
+impl ReflectLerp for f32 {
+    fn lerp(&self, end: &Self, value: f32) -> Self {
+        self + (end - self) * value
+    }
+}
+
+impl ReflectLerp for Vec2 {
+    fn lerp(&self, end: &Self, value: f32) -> Self {
+        Vec2::lerp(*self, *end, value)
+    }
+}
+
+impl ReflectLerp for Vec3 {
+    fn lerp(&self, end: &Self, value: f32) -> Self {
+        Vec3::lerp(*self, *end, value)
+    }
+}
+
+impl ReflectLerp for Vec4 {
+    fn lerp(&self, end: &Self, value: f32) -> Self {
+        Vec4::lerp(*self, *end, value)
+    }
+}
+
+impl ReflectLerp for Quat {
+    fn lerp(&self, end: &Self, value: f32) -> Self {
+        self.slerp(*end, value)
+    }
+}
+
+impl ReflectLerp for Color {
+    fn lerp(&self, end: &Self, value: f32) -> Self {
+        self.mix(end, value)
+    }
+}
+
+/// Tweens the field at `path` (ie. `"translation.x"`) of a reflected component.
+#[derive(Clone, Debug)]
+pub struct TweenReflectField<V> {
+    pub path: String,
+    pub start: V,
+    pub end: V,
+}
+
+impl<V: ReflectLerp> TweenApplier<Box<dyn Reflect>> for TweenReflectField<V> {
+    fn apply(&mut self, target: &mut Box<dyn Reflect>, value: f32) {
+        let _ = self.try_apply(target, value as f64);
+    }
+
+    fn try_apply(&mut self, target: &mut Box<dyn Reflect>, value: f64) -> Result<(), ApplyError> {
+        let field = target
+            .reflect_path_mut(self.path.as_str())
+            .map_err(|error| ApplyError(error.to_string()))?;
+        field
+            .try_apply(&self.start.lerp(&self.end, value as f32))
+            .map_err(|error| ApplyError(error.to_string()))
+    }
+}
+
+/// Plays a tween on the component with the given type path, which has to be registered with
+/// `#[reflect(Component)]`. Short type paths (ie. `"Transform"`) are accepted, if unambiguous.
+/// Only played if enabled with [`DefaultTweenPlugin::dynamic`](crate::DefaultTweenPlugin::dynamic).
+#[derive(Component)]
+pub struct PlayTweenDynamic<E = NoEvent> {
+    component: String,
+    tween: Tween<Box<dyn Reflect>, E>,
+    remove: bool,
+    paused: bool,
+    error_policy: ApplyErrorPolicy,
+}
+
+impl<E> PlayTweenDynamic<E> {
+    pub fn new(component: impl Into<String>, tween: Tween<Box<dyn Reflect>, E>) -> Self {
+        Self {
+            component: component.into(),
+            tween,
+            remove: false,
+            paused: false,
+            error_policy: ApplyErrorPolicy::default(),
+        }
+    }
+
+    /// After completing this tween, remove it (the component).
+    pub fn remove(self) -> Self {
+        Self {
+            remove: true,
+            ..self
+        }
+    }
+
+    /// What to do if a field can't be set, see [`ApplyErrorPolicy`].
+    pub fn with_error_policy(self, error_policy: ApplyErrorPolicy) -> Self {
+        Self {
+            error_policy,
+            ..self
+        }
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn component(&self) -> &str {
+        &self.component
+    }
+
+    pub fn tween(&self) -> &Tween<Box<dyn Reflect>, E> {
+        &self.tween
+    }
+}
+
+/// Plays all [`PlayTweenDynamic`]s in virtual time. Tweens whose component can't be resolved are
+/// removed. The tweens play on a reflected copy per component type, which is kept between frames.
+pub fn play_tween_dynamic<E: Event + Clone>(
+    world: &mut World,
+    query: &mut QueryState<Entity, With<PlayTweenDynamic<E>>>,
+    mut entities: Local<Vec<Entity>>,
+    mut scratches: Local<HashMap<TypeId, Box<dyn Reflect>>>,
+    mut events: Local<Vec<E>>,
+) {
+    let delta = world.resource::<Time>().delta();
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    entities.clear();
+    entities.extend(query.iter(world));
+    for &entity in entities.iter() {
+        let mut entity_mut = world.entity_mut(entity);
+        let play = entity_mut
+            .get::<PlayTweenDynamic<E>>()
+            .expect("Entity was queried with PlayTweenDynamic");
+        if play.paused {
+            continue;
+        }
+        let registration = registry
+            .get_with_type_path(&play.component)
+            .or_else(|| registry.get_with_short_type_path(&play.component));
+        let component = registration.and_then(|registration| {
+            let reflect_component = registration.data::<ReflectComponent>()?;
+            Some((
+                registration.type_id(),
+                reflect_component,
+                reflect_component.reflect(&entity_mut)?,
+            ))
+        });
+        let Some((type_id, reflect_component, component)) = component else {
+            warn!(
+                "{entity:?} has no reflected component {}, removing its tween",
+                play.component
+            );
+            entity_mut.remove::<PlayTweenDynamic<E>>();
+            continue;
+        };
+        let target = match scratches.entry(type_id) {
+            Entry::Occupied(entry) => {
+                let target = entry.into_mut();
+                target.apply(component);
+                target
+            }
+            Entry::Vacant(entry) => entry.insert(component.clone_value()),
+        };
+        let mut play = entity_mut
+            .get_mut::<PlayTweenDynamic<E>>()
+            .expect("Entity was queried with PlayTweenDynamic");
+        let (result, changed, error) = play.tween.advance_checked(target, &mut *events, delta);
+        let mut remove = play.remove && matches!(result, TweenProgress::Done { .. });
+        if let Some(error) = error {
+            warn!("Tween of {entity:?} failed to apply a value: {error}");
+            match play.error_policy {
+                ApplyErrorPolicy::Log => (),
+                ApplyErrorPolicy::StopTween => play.pause(),
+                ApplyErrorPolicy::RemoveComponent => remove = true,
+            }
+        }
+        if changed {
+            reflect_component.apply(&mut entity_mut, &**target);
+        }
+        if remove {
+            entity_mut.remove::<PlayTweenDynamic<E>>();
+        }
+    }
+    world.send_event_batch(events.drain(..));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tween::Lerp;
+    use std::time::Duration;
+
+    fn world() -> World {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Transform>();
+        world.insert_resource(registry);
+        world
+    }
+
+    fn tween(path: &str) -> Tween<Box<dyn Reflect>, NoEvent> {
+        Tween::new(
+            Duration::from_secs(1),
+            Lerp,
+            TweenReflectField {
+                path: path.to_string(),
+                start: 0.0,
+                end: 10.0,
+            },
+        )
+    }
+
+    #[test]
+    fn dynamic_tween_on_reflected_component() {
+        // GIVEN
+        let mut world = world();
+        let play_tween_id = world.register_system(play_tween_dynamic::<NoEvent>);
+        let entity = world
+            .spawn((
+                Transform::default(),
+                PlayTweenDynamic::new("Transform", tween("translation.x")),
+            ))
+            .id();
+        let other = world
+            .spawn((
+                Transform::from_xyz(0.0, 1.0, 0.0),
+                PlayTweenDynamic::new("Transform", tween("translation.z")),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        let translation = |entity| world.get::<Transform>(entity).unwrap().translation;
+        assert_eq!(translation(entity), Vec3::new(10.0, 0.0, 0.0));
+        assert_eq!(translation(other), Vec3::new(0.0, 1.0, 10.0));
+    }
+
+    #[test]
+    fn dynamic_tween_error_policy() {
+        // GIVEN
+        let mut world = world();
+        let play_tween_id = world.register_system(play_tween_dynamic::<NoEvent>);
+        let stopped = world
+            .spawn((
+                Transform::default(),
+                PlayTweenDynamic::new("Transform", tween("missing"))
+                    .with_error_policy(ApplyErrorPolicy::StopTween),
+            ))
+            .id();
+        let removed = world
+            .spawn((
+                Transform::default(),
+                PlayTweenDynamic::new("Transform", tween("missing"))
+                    .with_error_policy(ApplyErrorPolicy::RemoveComponent),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        assert!(world.get::<PlayTweenDynamic>(stopped).unwrap().is_paused());
+        assert!(world.get::<PlayTweenDynamic>(removed).is_none());
+    }
+}
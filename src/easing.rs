@@ -0,0 +1,312 @@
+use crate::tween::{Ease, EaseFunction, Interpolator};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Easing curves implemented by this crate, so new curves don't depend on other crates.
+///
+/// Unlike [`EaseFunction`], the sine and elastic curves use the common quarter period - so they
+/// differ slightly when converting between both.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+
+    QuadraticIn,
+    QuadraticOut,
+    QuadraticInOut,
+
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+
+    QuarticIn,
+    QuarticOut,
+    QuarticInOut,
+
+    QuinticIn,
+    QuinticOut,
+    QuinticInOut,
+
+    SineIn,
+    SineOut,
+    SineInOut,
+
+    CircularIn,
+    CircularOut,
+    CircularInOut,
+
+    ExponentialIn,
+    ExponentialOut,
+    ExponentialInOut,
+
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+
+    BackIn,
+    BackOut,
+    BackInOut,
+
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+
+    SmoothStep,
+    SmootherStep,
+    /// Jumps in the given number of equal steps.
+    Steps(u32),
+}
+
+impl Easing {
+    pub fn ease(self, position: f32) -> f32 {
+        self.ease_f64(position as f64) as f32
+    }
+
+    /// Like [`Easing::ease`], but in full precision.
+    pub fn ease_f64(self, position: f64) -> f64 {
+        let p = position.clamp(0.0, 1.0);
+        // Curves `*Out` and `*InOut` are derived from `*In`
+        let out = |ease_in: fn(f64) -> f64| 1.0 - ease_in(1.0 - p);
+        let in_out = |ease_in: fn(f64) -> f64| {
+            if p < 0.5 {
+                ease_in(2.0 * p) / 2.0
+            } else {
+                1.0 - ease_in(2.0 - 2.0 * p) / 2.0
+            }
+        };
+        match self {
+            Easing::Linear => p,
+
+            Easing::QuadraticIn => quadratic(p),
+            Easing::QuadraticOut => out(quadratic),
+            Easing::QuadraticInOut => in_out(quadratic),
+
+            Easing::CubicIn => cubic(p),
+            Easing::CubicOut => out(cubic),
+            Easing::CubicInOut => in_out(cubic),
+
+            Easing::QuarticIn => quartic(p),
+            Easing::QuarticOut => out(quartic),
+            Easing::QuarticInOut => in_out(quartic),
+
+            Easing::QuinticIn => quintic(p),
+            Easing::QuinticOut => out(quintic),
+            Easing::QuinticInOut => in_out(quintic),
+
+            Easing::SineIn => sine(p),
+            Easing::SineOut => out(sine),
+            Easing::SineInOut => in_out(sine),
+
+            Easing::CircularIn => circular(p),
+            Easing::CircularOut => out(circular),
+            Easing::CircularInOut => in_out(circular),
+
+            Easing::ExponentialIn => exponential(p),
+            Easing::ExponentialOut => out(exponential),
+            Easing::ExponentialInOut => in_out(exponential),
+
+            Easing::ElasticIn => elastic(p),
+            Easing::ElasticOut => out(elastic),
+            Easing::ElasticInOut => in_out(elastic),
+
+            Easing::BackIn => back(p),
+            Easing::BackOut => out(back),
+            Easing::BackInOut => in_out(back),
+
+            Easing::BounceIn => 1.0 - bounce_out(1.0 - p),
+            Easing::BounceOut => bounce_out(p),
+            Easing::BounceInOut => in_out(|p| 1.0 - bounce_out(1.0 - p)),
+
+            Easing::SmoothStep => p * p * (3.0 - 2.0 * p),
+            Easing::SmootherStep => p * p * p * (p * (6.0 * p - 15.0) + 10.0),
+            Easing::Steps(steps) => {
+                let steps = steps.max(1) as f64;
+                (p * steps).floor() / steps
+            }
+        }
+    }
+}
+
+fn quadratic(p: f64) -> f64 {
+    p * p
+}
+
+fn cubic(p: f64) -> f64 {
+    p * p * p
+}
+
+fn quartic(p: f64) -> f64 {
+    p * p * p * p
+}
+
+fn quintic(p: f64) -> f64 {
+    p * p * p * p * p
+}
+
+fn sine(p: f64) -> f64 {
+    1.0 - (p * FRAC_PI_2).cos()
+}
+
+fn circular(p: f64) -> f64 {
+    1.0 - (1.0 - p * p).sqrt()
+}
+
+fn exponential(p: f64) -> f64 {
+    if p <= 0.0 {
+        0.0
+    } else {
+        2.0_f64.powf(10.0 * (p - 1.0))
+    }
+}
+
+fn elastic(p: f64) -> f64 {
+    (13.0 * FRAC_PI_2 * p).sin() * 2.0_f64.powf(10.0 * (p - 1.0))
+}
+
+fn back(p: f64) -> f64 {
+    p * p * p - p * (p * PI).sin()
+}
+
+fn bounce_out(p: f64) -> f64 {
+    if p < 4.0 / 11.0 {
+        (121.0 * p * p) / 16.0
+    } else if p < 8.0 / 11.0 {
+        (363.0 / 40.0 * p * p) - (99.0 / 10.0 * p) + 17.0 / 5.0
+    } else if p < 9.0 / 10.0 {
+        (4356.0 / 361.0 * p * p) - (35442.0 / 1805.0 * p) + 16061.0 / 1805.0
+    } else {
+        (54.0 / 5.0 * p * p) - (513.0 / 25.0 * p) + 268.0 / 25.0
+    }
+}
+
+impl Interpolator for Easing {
+    fn interpolate(&self, position: f32) -> f32 {
+        self.ease(position)
+    }
+
+    fn interpolate_f64(&self, position: f64) -> f64 {
+        self.ease_f64(position)
+    }
+
+    fn into_ease(self) -> Ease {
+        Ease::Easing(self)
+    }
+}
+
+impl From<EaseFunction> for Easing {
+    fn from(function: EaseFunction) -> Self {
+        match function {
+            EaseFunction::QuadraticIn => Easing::QuadraticIn,
+            EaseFunction::QuadraticOut => Easing::QuadraticOut,
+            EaseFunction::QuadraticInOut => Easing::QuadraticInOut,
+            EaseFunction::CubicIn => Easing::CubicIn,
+            EaseFunction::CubicOut => Easing::CubicOut,
+            EaseFunction::CubicInOut => Easing::CubicInOut,
+            EaseFunction::QuarticIn => Easing::QuarticIn,
+            EaseFunction::QuarticOut => Easing::QuarticOut,
+            EaseFunction::QuarticInOut => Easing::QuarticInOut,
+            EaseFunction::QuinticIn => Easing::QuinticIn,
+            EaseFunction::QuinticOut => Easing::QuinticOut,
+            EaseFunction::QuinticInOut => Easing::QuinticInOut,
+            EaseFunction::SineIn => Easing::SineIn,
+            EaseFunction::SineOut => Easing::SineOut,
+            EaseFunction::SineInOut => Easing::SineInOut,
+            EaseFunction::CircularIn => Easing::CircularIn,
+            EaseFunction::CircularOut => Easing::CircularOut,
+            EaseFunction::CircularInOut => Easing::CircularInOut,
+            EaseFunction::ExponentialIn => Easing::ExponentialIn,
+            EaseFunction::ExponentialOut => Easing::ExponentialOut,
+            EaseFunction::ExponentialInOut => Easing::ExponentialInOut,
+            EaseFunction::ElasticIn => Easing::ElasticIn,
+            EaseFunction::ElasticOut => Easing::ElasticOut,
+            EaseFunction::ElasticInOut => Easing::ElasticInOut,
+            EaseFunction::BackIn => Easing::BackIn,
+            EaseFunction::BackOut => Easing::BackOut,
+            EaseFunction::BackInOut => Easing::BackInOut,
+            EaseFunction::BounceIn => Easing::BounceIn,
+            EaseFunction::BounceOut => Easing::BounceOut,
+            EaseFunction::BounceInOut => Easing::BounceInOut,
+        }
+    }
+}
+
+/// Fails for curves [`EaseFunction`] doesn't have, returning them unchanged.
+impl TryFrom<Easing> for EaseFunction {
+    type Error = Easing;
+
+    fn try_from(easing: Easing) -> Result<Self, Self::Error> {
+        Ok(match easing {
+            Easing::QuadraticIn => EaseFunction::QuadraticIn,
+            Easing::QuadraticOut => EaseFunction::QuadraticOut,
+            Easing::QuadraticInOut => EaseFunction::QuadraticInOut,
+            Easing::CubicIn => EaseFunction::CubicIn,
+            Easing::CubicOut => EaseFunction::CubicOut,
+            Easing::CubicInOut => EaseFunction::CubicInOut,
+            Easing::QuarticIn => EaseFunction::QuarticIn,
+            Easing::QuarticOut => EaseFunction::QuarticOut,
+            Easing::QuarticInOut => EaseFunction::QuarticInOut,
+            Easing::QuinticIn => EaseFunction::QuinticIn,
+            Easing::QuinticOut => EaseFunction::QuinticOut,
+            Easing::QuinticInOut => EaseFunction::QuinticInOut,
+            Easing::SineIn => EaseFunction::SineIn,
+            Easing::SineOut => EaseFunction::SineOut,
+            Easing::SineInOut => EaseFunction::SineInOut,
+            Easing::CircularIn => EaseFunction::CircularIn,
+            Easing::CircularOut => EaseFunction::CircularOut,
+            Easing::CircularInOut => EaseFunction::CircularInOut,
+            Easing::ExponentialIn => EaseFunction::ExponentialIn,
+            Easing::ExponentialOut => EaseFunction::ExponentialOut,
+            Easing::ExponentialInOut => EaseFunction::ExponentialInOut,
+            Easing::ElasticIn => EaseFunction::ElasticIn,
+            Easing::ElasticOut => EaseFunction::ElasticOut,
+            Easing::ElasticInOut => EaseFunction::ElasticInOut,
+            Easing::BackIn => EaseFunction::BackIn,
+            Easing::BackOut => EaseFunction::BackOut,
+            Easing::BackInOut => EaseFunction::BackInOut,
+            Easing::BounceIn => EaseFunction::BounceIn,
+            Easing::BounceOut => EaseFunction::BounceOut,
+            Easing::BounceInOut => EaseFunction::BounceInOut,
+            Easing::Linear | Easing::SmoothStep | Easing::SmootherStep | Easing::Steps(_) => {
+                return Err(easing)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easing_matches_ease_function() {
+        for function in [
+            EaseFunction::QuadraticInOut,
+            EaseFunction::CubicOut,
+            EaseFunction::QuinticInOut,
+            EaseFunction::CircularInOut,
+            EaseFunction::ExponentialInOut,
+            EaseFunction::BackInOut,
+            EaseFunction::BounceInOut,
+        ] {
+            let easing = Easing::from(function);
+            assert_eq!(EaseFunction::try_from(easing), Ok(function));
+            for i in 0..=20 {
+                let p = i as f32 / 20.0;
+                let expected = function.interpolate(p);
+                assert!(
+                    (easing.ease(p) - expected).abs() < 1e-5,
+                    "{easing:?} at {p}: {} != {expected}",
+                    easing.ease(p)
+                );
+            }
+        }
+        assert_eq!(Easing::Steps(4).ease(0.6), 0.5);
+        // Positions closer than `f32` can tell apart still ease differently
+        let (a, b) = (0.5, 0.5 + 1e-12);
+        assert_ne!(
+            Easing::CubicInOut.interpolate_f64(a),
+            Easing::CubicInOut.interpolate_f64(b)
+        );
+        assert_eq!(EaseFunction::try_from(Easing::Linear), Err(Easing::Linear));
+    }
+}
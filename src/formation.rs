@@ -0,0 +1,169 @@
+use crate::easing::Easing;
+use crate::plugin::{PlayTween, TweenId, TweenTranslation};
+use crate::tween::Tween;
+use bevy::prelude::*;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// Moves a group of entities to a destination with one call, see [`MoveFormation::spawn`]. Each
+/// member gets its own [`PlayTween`] of its [`Transform`].
+pub struct MoveFormation<E> {
+    entities: Vec<Entity>,
+    destination: Vec3,
+    arrangement: Vec<Vec3>,
+    duration: Duration,
+    easing: Easing,
+    _event: PhantomData<E>,
+}
+
+/// A group of tweens started by [`MoveFormation`], despawned once all of them completed.
+#[derive(Component, Debug, Clone)]
+pub struct Formation {
+    members: Vec<(Entity, TweenId)>,
+}
+
+/// Sent when all members of a [`Formation`] arrived.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormationCompleted {
+    pub formation: Entity,
+}
+
+impl<E: Send + Sync + 'static> MoveFormation<E> {
+    /// Moves the center of `entities` to `destination`, keeping their offsets to each other.
+    pub fn new(
+        entities: impl IntoIterator<Item = Entity>,
+        destination: Vec3,
+        duration: Duration,
+        easing: Easing,
+    ) -> Self {
+        Self {
+            entities: entities.into_iter().collect(),
+            destination,
+            arrangement: Vec::new(),
+            duration,
+            easing,
+            _event: PhantomData,
+        }
+    }
+
+    /// Moves the entities into a new arrangement instead, given as offsets to the destination in
+    /// the order of the entities. Entities without an offset keep their current one.
+    pub fn with_arrangement(self, offsets: impl Into<Vec<Vec3>>) -> Self {
+        Self {
+            arrangement: offsets.into(),
+            ..self
+        }
+    }
+
+    /// Starts moving, returns the [`Formation`] entity sending [`FormationCompleted`] at the end.
+    pub fn spawn(self, commands: &mut Commands) -> Entity {
+        let formation = commands.spawn_empty().id();
+        commands.add(move |world: &mut World| self.apply(world, formation));
+        formation
+    }
+
+    fn apply(self, world: &mut World, formation: Entity) {
+        let starts: Vec<_> = self
+            .entities
+            .iter()
+            .filter_map(|&entity| Some((entity, world.get::<Transform>(entity)?.translation)))
+            .collect();
+        let center =
+            starts.iter().map(|(_, start)| *start).sum::<Vec3>() / starts.len().max(1) as f32;
+        let mut members = Vec::with_capacity(starts.len());
+        for (index, (entity, start)) in starts.into_iter().enumerate() {
+            let offset = self
+                .arrangement
+                .get(index)
+                .copied()
+                .unwrap_or(start - center);
+            let play = PlayTween::new(Tween::<Transform, E>::once(
+                self.duration,
+                self.easing,
+                TweenTranslation {
+                    start,
+                    end: self.destination + offset,
+                },
+                None,
+            ));
+            members.push((entity, play.id()));
+            world.entity_mut(entity).insert(play);
+        }
+        if let Some(mut formation) = world.get_entity_mut(formation) {
+            formation.insert(Formation { members });
+        }
+    }
+}
+
+/// Sends [`FormationCompleted`] for formations whose members all completed (or were interrupted).
+pub fn complete_formations<E: Send + Sync + 'static>(
+    formations: Query<(Entity, &Formation)>,
+    tweens: Query<&PlayTween<Transform, E, ()>>,
+    mut completed: EventWriter<FormationCompleted>,
+    mut commands: Commands,
+) {
+    for (formation, Formation { members }) in formations.iter() {
+        let moving = members.iter().any(|&(entity, id)| {
+            tweens
+                .get(entity)
+                .is_ok_and(|play| play.id() == id && !play.is_finished())
+        });
+        if !moving {
+            completed.send(FormationCompleted { formation });
+            commands.entity(formation).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::play_tween_animation;
+    use crate::tween::NoEvent;
+    use bevy::ecs::world::CommandQueue;
+
+    #[test]
+    fn test_move_formation() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        world.init_resource::<Events<FormationCompleted>>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let complete_id = world.register_system(complete_formations::<NoEvent>);
+        let left = world.spawn(Transform::from_xyz(-1.0, 0.0, 0.0)).id();
+        let right = world.spawn(Transform::from_xyz(1.0, 0.0, 0.0)).id();
+        let mut queue = CommandQueue::default();
+        let formation = MoveFormation::<NoEvent>::new(
+            [left, right],
+            Vec3::Y * 5.0,
+            Duration::from_secs(2),
+            Easing::Linear,
+        )
+        .spawn(&mut Commands::new(&mut queue, &world));
+        queue.apply(&mut world);
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+        world.run_system(complete_id).unwrap();
+        let completed_halfway = world.resource::<Events<FormationCompleted>>().len();
+        world.run_system(play_tween_id).unwrap();
+        world.run_system(complete_id).unwrap();
+
+        // THEN
+        assert_eq!(completed_halfway, 0);
+        assert_eq!(
+            world.get::<Transform>(left).unwrap().translation,
+            Vec3::new(-1.0, 5.0, 0.0)
+        );
+        assert_eq!(
+            world.get::<Transform>(right).unwrap().translation,
+            Vec3::new(1.0, 5.0, 0.0)
+        );
+        let events = world.resource::<Events<FormationCompleted>>();
+        let completed: Vec<_> = events.get_reader().read(events).copied().collect();
+        assert_eq!(completed, [FormationCompleted { formation }]);
+    }
+}
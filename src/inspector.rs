@@ -0,0 +1,277 @@
+//! Widgets to inspect and tweak tweens live with [`egui`]. They aren't registered with an
+//! inspector, call them from your own window - ie. a `bevy_egui` system querying the [`PlayTween`]s.
+//!
+//! Appliers are boxed once they are part of a tween, so their endpoints can only be edited before
+//! building it - ie. when they are kept in a component or resource to rebuild the tween from.
+use crate::easing::Easing;
+use crate::plugin::{
+    PlayTween, TweenBackgroundColor, TweenScale, TweenSpriteColor, TweenTranslation,
+};
+use crate::tween::{Ease, Tween};
+use bevy::prelude::*;
+use egui::{ComboBox, ProgressBar, Slider, Ui};
+use std::time::Duration;
+
+/// Something which can be shown and edited in an [`egui::Ui`].
+pub trait InspectTween {
+    /// Returns whether anything was changed.
+    fn inspect(&mut self, ui: &mut Ui) -> bool;
+}
+
+/// Shows the progress (if the duration is known), and buttons to pause or restart the tween.
+impl<T, E, I> InspectTween for PlayTween<T, E, I> {
+    fn inspect(&mut self, ui: &mut Ui) -> bool {
+        let tween = self.tween();
+        let progress = tween
+            .elapsed()
+            .zip(tween.duration())
+            .filter(|(_, duration)| !duration.is_zero())
+            .map(|(elapsed, duration)| elapsed.as_secs_f32() / duration.as_secs_f32());
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            if let Some(progress) = progress {
+                ui.add(ProgressBar::new(progress).show_percentage());
+            }
+            let paused = self.is_paused();
+            if ui.button(if paused { "Resume" } else { "Pause" }).clicked() {
+                if paused {
+                    self.resume();
+                } else {
+                    self.pause();
+                }
+                changed = true;
+            }
+            if ui.button("Restart").clicked() {
+                self.restart();
+                changed = true;
+            }
+        });
+        changed
+    }
+}
+
+/// Easings offered by [`tween_timeline`].
+const EASINGS: [Easing; 34] = [
+    Easing::Linear,
+    Easing::QuadraticIn,
+    Easing::QuadraticOut,
+    Easing::QuadraticInOut,
+    Easing::CubicIn,
+    Easing::CubicOut,
+    Easing::CubicInOut,
+    Easing::QuarticIn,
+    Easing::QuarticOut,
+    Easing::QuarticInOut,
+    Easing::QuinticIn,
+    Easing::QuinticOut,
+    Easing::QuinticInOut,
+    Easing::SineIn,
+    Easing::SineOut,
+    Easing::SineInOut,
+    Easing::CircularIn,
+    Easing::CircularOut,
+    Easing::CircularInOut,
+    Easing::ExponentialIn,
+    Easing::ExponentialOut,
+    Easing::ExponentialInOut,
+    Easing::ElasticIn,
+    Easing::ElasticOut,
+    Easing::ElasticInOut,
+    Easing::BackIn,
+    Easing::BackOut,
+    Easing::BackInOut,
+    Easing::BounceIn,
+    Easing::BounceOut,
+    Easing::BounceInOut,
+    Easing::SmoothStep,
+    Easing::SmootherStep,
+    Easing::Steps(4),
+];
+
+/// A timeline of the steps of a tween (the tweens of a top level sequence, or the tween itself):
+/// Scrubbing moves the tween and applies its values to `target`, pausing it. The easing of single
+/// steps can be changed, which is written back into the tween. Returns whether anything was
+/// changed.
+pub fn tween_timeline<T, E, I>(ui: &mut Ui, play: &mut PlayTween<T, E, I>, target: &mut T) -> bool {
+    let mut changed = false;
+    let tween = play.tween();
+    let (elapsed, duration) = (tween.elapsed(), tween.duration());
+    ui.horizontal(|ui| {
+        let paused = play.is_paused();
+        if ui.button(if paused { "Play" } else { "Pause" }).clicked() {
+            if paused {
+                play.resume();
+            } else {
+                play.pause();
+            }
+            changed = true;
+        }
+        let (Some(elapsed), Some(duration)) = (elapsed, duration) else {
+            ui.label("Unknown duration");
+            return;
+        };
+        let mut position = elapsed.as_secs_f32();
+        let scrubbed = ui
+            .add(Slider::new(&mut position, 0.0..=duration.as_secs_f32()).suffix(" s"))
+            .changed();
+        if scrubbed {
+            play.pause();
+            play.tween_mut()
+                .seek(target, Duration::from_secs_f32(position));
+            changed = true;
+        }
+    });
+    let steps = match play.tween_mut() {
+        Tween::Sequence { tweens, .. } => tweens.iter_mut().collect(),
+        tween => vec![tween],
+    };
+    let total = duration.unwrap_or_default().as_secs_f32();
+    let width = ui.available_width();
+    for (index, step) in steps.into_iter().enumerate() {
+        ui.horizontal(|ui| {
+            ui.label(format!("{index}"));
+            changed |= easing_picker(ui, index, step);
+            let progress = step
+                .overall_progress()
+                .map_or(0.0, |(progress, _)| progress);
+            let step_width = step
+                .duration()
+                .filter(|_| total > 0.0)
+                .map_or(width, |duration| width * duration.as_secs_f32() / total);
+            ui.add(ProgressBar::new(progress).desired_width(step_width.max(4.0)));
+        });
+    }
+    changed
+}
+
+/// Picks the easing of a single tween, other tweens only get a placeholder.
+fn easing_picker<T, E>(ui: &mut Ui, id: usize, tween: &mut Tween<T, E>) -> bool {
+    let Tween::Once { function, .. } = tween else {
+        ui.label("-");
+        return false;
+    };
+    let mut easing = match function {
+        Ease::Lerp => Easing::Linear,
+        Ease::Easing(easing) => *easing,
+        Ease::Function(function) => Easing::from(*function),
+        Ease::Custom(_) => {
+            ui.label("Custom");
+            return false;
+        }
+    };
+    let mut changed = false;
+    ComboBox::from_id_source(("be_tween_easing", id))
+        .selected_text(format!("{easing:?}"))
+        .show_ui(ui, |ui| {
+            for option in EASINGS {
+                // Keeps the number of steps when picking steps again
+                let selected = std::mem::discriminant(&easing) == std::mem::discriminant(&option);
+                if ui
+                    .selectable_label(selected, format!("{option:?}"))
+                    .clicked()
+                    && !selected
+                {
+                    easing = option;
+                    changed = true;
+                }
+            }
+        });
+    if let Easing::Steps(steps) = &mut easing {
+        changed |= ui.add(egui::DragValue::new(steps).range(1..=100)).changed();
+    }
+    if changed {
+        *function = Ease::Easing(easing);
+    }
+    changed
+}
+
+fn vec3(ui: &mut Ui, label: &str, value: &mut Vec3) -> bool {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        let mut changed = false;
+        for axis in [&mut value.x, &mut value.y, &mut value.z] {
+            changed |= ui.add(egui::DragValue::new(axis).speed(0.1)).changed();
+        }
+        changed
+    })
+    .inner
+}
+
+fn color(ui: &mut Ui, label: &str, value: &mut Color) -> bool {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        let mut rgba = value.to_srgba().to_f32_array();
+        let changed = ui.color_edit_button_rgba_unmultiplied(&mut rgba).changed();
+        if changed {
+            *value = Srgba::from_f32_array(rgba).into();
+        }
+        changed
+    })
+    .inner
+}
+
+macro_rules! inspect_endpoints {
+    ($applier:ty, $widget:ident) => {
+        impl InspectTween for $applier {
+            fn inspect(&mut self, ui: &mut Ui) -> bool {
+                let start = $widget(ui, "Start", &mut self.start);
+                $widget(ui, "End", &mut self.end) || start
+            }
+        }
+    };
+}
+
+inspect_endpoints!(TweenTranslation, vec3);
+inspect_endpoints!(TweenScale, vec3);
+inspect_endpoints!(TweenSpriteColor, color);
+inspect_endpoints!(TweenBackgroundColor, color);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tween::{EaseFunction, NoEvent, Tween};
+    use std::time::Duration;
+
+    #[test]
+    fn inspect_play_tween() {
+        let mut play = PlayTween::new(Tween::<Transform, NoEvent>::pause(Duration::from_secs(1)));
+
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                assert!(!play.inspect(ui));
+                assert!(!TweenTranslation::default().inspect(ui));
+            });
+        });
+    }
+
+    #[test]
+    fn timeline_lists_steps() {
+        let mut play = PlayTween::new(Tween::<Transform, NoEvent>::sequence([
+            Tween::pause(Duration::from_secs(1)),
+            Tween::new(
+                Duration::from_secs(1),
+                Easing::QuadraticIn,
+                TweenTranslation::default(),
+            ),
+            Tween::new(
+                Duration::from_secs(1),
+                EaseFunction::SineOut,
+                TweenTranslation::default(),
+            ),
+            Tween::new(
+                Duration::from_secs(1),
+                Easing::Steps(3),
+                TweenTranslation::default(),
+            ),
+        ]));
+        let mut transform = Transform::default();
+
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                assert!(!tween_timeline(ui, &mut play, &mut transform));
+            });
+        });
+    }
+}
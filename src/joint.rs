@@ -0,0 +1,109 @@
+use crate::plugin::PlayTween;
+use crate::tween::Tween;
+use bevy::ecs::world::Command;
+use bevy::prelude::*;
+use bevy::render::mesh::skinning::SkinnedMesh;
+
+/// Plays a tween on a joint (bone) of a skinned mesh, found by its [`Name`] - ie. for procedural
+/// motion like a tail wag, next to animation clips. Add it with [`Commands::add`]. `root` is either
+/// the [`SkinnedMesh`] or an ancestor of the joint, like the armature.
+pub struct TweenJoint<E> {
+    pub root: Entity,
+    pub joint: String,
+    pub tween: Tween<Transform, E>,
+    /// Whether to play on top of the animation, see [`PlayTween::additive`].
+    pub additive: bool,
+}
+
+impl<E> TweenJoint<E> {
+    pub fn new(root: Entity, joint: impl Into<String>, tween: Tween<Transform, E>) -> Self {
+        Self {
+            root,
+            joint: joint.into(),
+            tween,
+            additive: false,
+        }
+    }
+
+    /// Plays the tween on top of the animation of the joint, see [`PlayTween::additive`].
+    pub fn additive(self) -> Self {
+        Self {
+            additive: true,
+            ..self
+        }
+    }
+}
+
+impl<E: Send + Sync + 'static> Command for TweenJoint<E> {
+    fn apply(self, world: &mut World) {
+        let Some(joint) = find_joint(world, self.root, &self.joint) else {
+            warn!("No joint named {} found below {:?}", self.joint, self.root);
+            return;
+        };
+        let play = PlayTween::new(self.tween);
+        world
+            .entity_mut(joint)
+            .insert(if self.additive { play.additive() } else { play });
+    }
+}
+
+/// Finds the joint named `name` of the [`SkinnedMesh`] `root`, or the descendant with that name.
+pub fn find_joint(world: &World, root: Entity, name: &str) -> Option<Entity> {
+    let named = |entity: Entity| {
+        world
+            .get::<Name>(entity)
+            .is_some_and(|joint| joint.as_str() == name)
+    };
+    if let Some(skinned_mesh) = world.get::<SkinnedMesh>(root) {
+        return skinned_mesh
+            .joints
+            .iter()
+            .copied()
+            .find(|&joint| named(joint));
+    }
+    let mut pending = vec![root];
+    while let Some(entity) = pending.pop() {
+        for &child in world.get::<Children>(entity).into_iter().flatten() {
+            if named(child) {
+                return Some(child);
+            }
+            pending.push(child);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::TweenRotation;
+    use crate::tween::{Lerp, NoEvent};
+    use std::time::Duration;
+
+    #[test]
+    fn test_tween_joint() {
+        // GIVEN
+        let mut world = World::new();
+        let tail = world.spawn((Name::new("Tail"), Transform::default())).id();
+        let spine = world.spawn(Name::new("Spine")).push_children(&[tail]).id();
+        let armature = world.spawn_empty().push_children(&[spine]).id();
+        let tween = Tween::<Transform, NoEvent>::new(
+            Duration::from_secs(1),
+            Lerp,
+            TweenRotation {
+                start: Quat::IDENTITY,
+                end: Quat::from_rotation_z(0.5),
+            },
+        );
+
+        // WHEN
+        TweenJoint::new(armature, "Tail", tween).apply(&mut world);
+
+        // THEN
+        assert!(world
+            .get::<PlayTween<Transform, NoEvent, ()>>(tail)
+            .is_some());
+        assert_eq!(find_joint(&world, armature, "Spine"), Some(spine));
+        assert_eq!(find_joint(&world, armature, "Head"), None);
+    }
+}
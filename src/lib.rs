@@ -1,118 +1,204 @@
 #![allow(clippy::type_complexity)]
 //! Be-Tween
 //!
-//! Provides tweening values over time.
-
-//! These examples run with Bevy:
-//! ```no_run
-//! use bevy::prelude::*;
-//! use be_tween::*;
-//!
-//! App::default()
-//!     .add_plugins(DefaultPlugins)
-//!     // Add the plugin - NoEvent means that no custom events will be used
-//!     .add_plugins(DefaultTweenPlugin::<NoEvent>::new())
-//!     // Add the systems performing the tweening
-//!     .add_systems(Update, (play_tween_animation::<Transform, NoEvent, ()>, play_tween_animation::<Sprite, NoEvent, ()>))
-//!     .run();
+//! Provides tweening values over time. Without Bevy, tweens can be played with a [`Tweener`]:
 //! ```
+//! use be_tween::*;
+//! use std::time::Duration;
 //!
-//! Animate the position ([`Transform::translation`]) of an [`Entity`]:
-//! ```
-//! # use bevy::prelude::*;
-//! # use be_tween::*;
-//! # use std::time::Duration;
-//! # fn system(mut commands: Commands) {
-//! // Create a single animation (tween) to move an entity.
-//! let tween = Tween::new(
-//!     // Animation time.
+//! let tween = Tween::new_with_event(
 //!     Duration::from_secs(1),
-//!     // Use a quadratic easing on both endpoints.
-//!     EaseFunction::QuadraticInOut,
-//!     // What we want to tween - the translation part of a transform
-//!     TweenTranslation {
-//!         start: Vec3::ZERO,
-//!         end: Vec3::X,
+//!     Easing::Linear,
+//!     TweenF64 {
+//!         start: 0.0,
+//!         end: 10.0,
 //!     },
+//!     "done",
 //! );
+//! let mut tweener = Tweener::new(tween);
+//! let mut value = 0.0;
 //!
-//! commands.spawn((
-//!     // Spawn an entity to animate the position of.
-//!     TransformBundle::default(),
-//!     // Add an Animator component to control and execute the animation.
-//!     PlayTween::new(tween),
-//! ));
-//! # }
-//! ```
-//! A more elaborate example:
+//! tweener.update(Duration::from_millis(500), &mut value);
+//! assert_eq!(value, 5.0);
+//! tweener.update(Duration::from_millis(500), &mut value);
+//! assert_eq!(value, 10.0);
+//! assert!(tweener.is_finished());
+//! assert_eq!(tweener.drain_events().collect::<Vec<_>>(), ["done"]);
 //! ```
-//! # use bevy::prelude::*;
-//! # use be_tween::*;
-//! # use std::time::Duration;
-//! # fn system(mut commands: Commands) {
-//! let tween_translation = Tween::sequence([
-//!   // First move to Vec3::X
-//!   Tween::new(
-//!     Duration::from_secs(1),
-//!     EaseFunction::QuadraticInOut,
-//!     TweenTranslation {
-//!         start: Vec3::ZERO,
-//!         end: Vec3::X,
-//!     }),
-//!   // Then move from Vec3::X to Vec3::Y 12 times
-//!   Tween::repeat(
-//!     RepeatTimes::N(12),
-//!     Tween::new(
-//!      Duration::from_secs(1),
-//!      EaseFunction::QuadraticInOut,
-//!      TweenTranslation {
-//!          start: Vec3::X,
-//!          end: Vec3::Y,
-//!      })),
-//!   // Then move up and down forever
-//!   Tween::repeat(
-//!     RepeatTimes::Infinite,
-//!     Tween::sequence([
-//!       Tween::new(
-//!         Duration::from_secs(1),
-//!         EaseFunction::QuadraticInOut,
-//!         TweenTranslation {
-//!             start: Vec3::Y,
-//!             end: -Vec3::Y,
-//!         }),
-//!       Tween::new(
-//!         Duration::from_secs(1),
-//!         EaseFunction::QuadraticInOut,
-//!         TweenTranslation {
-//!             start: -Vec3::Y,
-//!             end: Vec3::Y,
-//!         }),
-//!     ]))
-//!   ]);
-//! let tween_color = Tween::sequence([Tween::new(
-//!     Duration::from_secs(1),
-//!     EaseFunction::QuadraticInOut,
-//!     TweenSpriteColor {
-//!         start: LinearRgba::WHITE.into(),
-//!         end: LinearRgba::RED.into()
-//!     })]
-//! );
 //!
-//! commands.spawn((
-//!     // Spawn an entity to animate the position of.
-//!     TransformBundle::default(),
-//!     // Now play the tweens
-//!     PlayTween::new(tween_translation),
-//!     // Note that both will be played in parallel!
-//!     PlayTween::new(tween_color),
-//! ));
-//! # }
-//! ```
+#![cfg_attr(
+    feature = "bevy",
+    doc = r#"
+These examples run with Bevy:
+```no_run
+use bevy::prelude::*;
+use be_tween::*;
+
+App::default()
+    .add_plugins(DefaultPlugins)
+    // Add the plugin - NoEvent means that no custom events will be used
+    .add_plugins(DefaultTweenPlugin::<NoEvent>::new())
+    // Add the systems performing the tweening
+    .add_systems(Update, (play_tween_animation::<Transform, NoEvent, ()>, play_tween_animation::<Sprite, NoEvent, ()>))
+    .run();
+```
+
+Animate the position ([`Transform::translation`]) of an [`Entity`]:
+```
+# use bevy::prelude::*;
+# use be_tween::*;
+# use std::time::Duration;
+# fn system(mut commands: Commands) {
+// Create a single animation (tween) to move an entity.
+let tween = Tween::new(
+    // Animation time.
+    Duration::from_secs(1),
+    // Use a quadratic easing on both endpoints.
+    EaseFunction::QuadraticInOut,
+    // What we want to tween - the translation part of a transform
+    TweenTranslation {
+        start: Vec3::ZERO,
+        end: Vec3::X,
+    },
+);
 
+commands.spawn((
+    // Spawn an entity to animate the position of.
+    TransformBundle::default(),
+    // Add an Animator component to control and execute the animation.
+    PlayTween::new(tween),
+));
+# }
+```
+A more elaborate example:
+```
+# use bevy::prelude::*;
+# use be_tween::*;
+# use std::time::Duration;
+# fn system(mut commands: Commands) {
+let tween_translation = Tween::sequence([
+  // First move to Vec3::X
+  Tween::new(
+    Duration::from_secs(1),
+    EaseFunction::QuadraticInOut,
+    TweenTranslation {
+        start: Vec3::ZERO,
+        end: Vec3::X,
+    }),
+  // Then move from Vec3::X to Vec3::Y 12 times
+  Tween::repeat(
+    RepeatTimes::N(12),
+    Tween::new(
+     Duration::from_secs(1),
+     EaseFunction::QuadraticInOut,
+     TweenTranslation {
+         start: Vec3::X,
+         end: Vec3::Y,
+     })),
+  // Then move up and down forever
+  Tween::repeat(
+    RepeatTimes::Infinite,
+    Tween::sequence([
+      Tween::new(
+        Duration::from_secs(1),
+        EaseFunction::QuadraticInOut,
+        TweenTranslation {
+            start: Vec3::Y,
+            end: -Vec3::Y,
+        }),
+      Tween::new(
+        Duration::from_secs(1),
+        EaseFunction::QuadraticInOut,
+        TweenTranslation {
+            start: -Vec3::Y,
+            end: Vec3::Y,
+        }),
+    ]))
+  ]);
+let tween_color = Tween::sequence([Tween::new(
+    Duration::from_secs(1),
+    EaseFunction::QuadraticInOut,
+    TweenSpriteColor {
+        start: LinearRgba::WHITE.into(),
+        end: LinearRgba::RED.into()
+    })]
+);
+
+commands.spawn((
+    // Spawn an entity to animate the position of.
+    TransformBundle::default(),
+    // Now play the tweens
+    PlayTween::new(tween_translation),
+    // Note that both will be played in parallel!
+    PlayTween::new(tween_color),
+));
+# }
+```
+"#
+)]
+
+mod bake;
+#[cfg(feature = "bevy")]
+pub mod compat;
+mod def;
+#[cfg(feature = "bevy")]
+mod dynamic;
+mod easing;
+#[cfg(feature = "bevy")]
+mod formation;
+#[cfg(feature = "inspector")]
+pub mod inspector;
+#[cfg(feature = "bevy")]
+mod joint;
+#[cfg(feature = "bevy")]
+mod many;
+#[cfg(feature = "bevy")]
+mod path;
+#[cfg(feature = "physics")]
+pub mod physics;
 #[cfg(feature = "bevy")]
 mod plugin;
+mod pool;
+#[cfg(feature = "bevy")]
+pub mod presets;
+mod random;
+#[cfg(feature = "bevy")]
+mod record;
+mod registry;
+#[cfg(feature = "replication")]
+mod replication;
+mod seq;
+#[cfg(feature = "bevy")]
+pub mod test_utils;
 mod tween;
+mod tweener;
+#[cfg(feature = "bevy")]
+mod ui;
 
+pub use bake::*;
+pub use def::*;
+#[cfg(feature = "bevy")]
+pub use dynamic::*;
+pub use easing::*;
+#[cfg(feature = "bevy")]
+pub use formation::*;
+#[cfg(feature = "bevy")]
+pub use joint::*;
+#[cfg(feature = "bevy")]
+pub use many::*;
+#[cfg(feature = "bevy")]
+pub use path::*;
 #[cfg(feature = "bevy")]
 pub use plugin::*;
+pub use pool::*;
+pub use random::*;
+#[cfg(feature = "bevy")]
+pub use record::*;
+pub use registry::*;
+#[cfg(feature = "replication")]
+pub use replication::*;
+pub use seq::*;
 pub use tween::*;
+pub use tweener::*;
+#[cfg(feature = "bevy")]
+pub use ui::*;
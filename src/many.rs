@@ -0,0 +1,216 @@
+use crate::plugin::TweenTime;
+use crate::tween::{NoEvent, Tween, TweenProgress, TweenState};
+use bevy::ecs::query::QueryFilter;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use std::marker::PhantomData;
+
+/// Plays one tween on every entity matching the filter `F`, all at the same progress - ie. to
+/// flash all enemies red without adding a [`PlayTween`](crate::PlayTween) to each. Add it to any
+/// entity, it's played by [`play_tween_many`]. Events are sent once, not per entity.
+#[derive(Component)]
+pub struct PlayTweenMany<T, F, E, I> {
+    tween: Tween<T, E>,
+    paused: bool,
+    despawn: bool,
+    _filter: PhantomData<fn() -> (F, I)>,
+}
+
+impl<T, F, E> PlayTweenMany<T, F, E, ()> {
+    pub fn new(tween: Tween<T, E>) -> Self {
+        Self::new_with_time(tween)
+    }
+}
+
+impl<T, F, E, I> PlayTweenMany<T, F, E, I> {
+    pub fn new_with_time(tween: Tween<T, E>) -> Self {
+        Self {
+            tween,
+            paused: false,
+            despawn: false,
+            _filter: PhantomData,
+        }
+    }
+
+    /// After completing the tween, despawn this entity - instead of just removing this component.
+    pub fn despawn(self) -> Self {
+        Self {
+            despawn: true,
+            ..self
+        }
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn tween(&self) -> &Tween<T, E> {
+        &self.tween
+    }
+}
+
+/// Plays the [`PlayTweenMany`]s, removing them once completed.
+///
+/// The first target is played with the tween itself. The other targets are played one after
+/// another with a scratch copy, which is restored to the state of the tween before each of them.
+/// Appliers keep their own state (like [`TweenOffset`](crate::TweenOffset)) once for all
+/// targets, so only appliers setting absolute values are played correctly on more than one.
+pub fn play_tween_many<
+    T: Component + Clone,
+    F: QueryFilter + 'static,
+    E: Event + Clone,
+    I: Default + Send + Sync + 'static,
+>(
+    time: TweenTime<I>,
+    mut controllers: Query<(Entity, &mut PlayTweenMany<T, F, E, I>)>,
+    mut targets: Query<&mut T, F>,
+    mut event_writer: EventWriter<E>,
+    mut commands: Commands,
+    mut scratches: Local<HashMap<Entity, (Tween<T, E>, TweenState)>>,
+) {
+    scratches.retain(|&entity, _| controllers.contains(entity));
+    let delta = time.delta_with_max(None);
+    for (entity, mut play) in controllers.iter_mut() {
+        if play.paused {
+            continue;
+        }
+        let mut targets = targets.iter_mut();
+        let first = targets.next();
+        let mut others = targets.peekable();
+        if others.peek().is_none() {
+            // Cloned again once needed, so it doesn't miss rolls of randomized appliers
+            scratches.remove(&entity);
+        } else {
+            let (scratch, state) = scratches
+                .entry(entity)
+                .or_insert_with(|| (play.tween.clone(), play.tween.state()));
+            play.tween.state_into(state);
+            for mut target in others {
+                // Every target starts from the same state, so all see the same progress
+                if scratch.restore_state(state).is_err() {
+                    *scratch = play.tween.clone();
+                }
+                scratch.copy_rngs_from(&play.tween);
+                scratch.advance(&mut target, &mut NoEvent, delta);
+            }
+        }
+        let result = match first {
+            Some(mut target) => play.tween.advance(&mut target, &mut event_writer, delta),
+            None => play.tween.skip(delta),
+        };
+        if matches!(result, TweenProgress::Done { .. }) {
+            if play.despawn {
+                commands.entity(entity).despawn();
+            } else {
+                commands
+                    .entity(entity)
+                    .remove::<PlayTweenMany<T, F, E, I>>();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::TweenSpriteColor;
+    use crate::random::Rand;
+    use crate::tween::{Lerp, RepeatTimes};
+    use std::time::Duration;
+
+    #[test]
+    fn test_play_tween_many() {
+        // GIVEN
+        #[derive(Component)]
+        struct Enemy;
+
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let play_id = world.register_system(play_tween_many::<Sprite, With<Enemy>, NoEvent, ()>);
+        let enemies: Vec<_> = (0..3)
+            .map(|_| world.spawn((Sprite::default(), Enemy)).id())
+            .collect();
+        let friend = world.spawn(Sprite::default()).id();
+        let controller = world
+            .spawn(PlayTweenMany::<_, With<Enemy>, _, _>::new(Tween::new(
+                Duration::from_secs(1),
+                Lerp,
+                TweenSpriteColor {
+                    start: Color::WHITE,
+                    end: Color::BLACK,
+                },
+            )))
+            .id();
+
+        // WHEN
+        world.run_system(play_id).unwrap();
+        let halfway: Vec<_> = enemies
+            .iter()
+            .map(|&enemy| world.get::<Sprite>(enemy).unwrap().color)
+            .collect();
+        world.run_system(play_id).unwrap();
+
+        // THEN
+        assert!(halfway.iter().all(|color| *color == halfway[0]));
+        assert_ne!(halfway[0], Color::WHITE);
+        for enemy in enemies {
+            assert_eq!(world.get::<Sprite>(enemy).unwrap().color, Color::BLACK);
+        }
+        assert_eq!(world.get::<Sprite>(friend).unwrap().color, Color::WHITE);
+        assert!(world
+            .get::<PlayTweenMany<Sprite, With<Enemy>, NoEvent, ()>>(controller)
+            .is_none());
+    }
+
+    #[test]
+    fn test_play_tween_many_randomized() {
+        // GIVEN
+        #[derive(Component)]
+        struct Enemy;
+
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.init_resource::<Events<NoEvent>>();
+        let play_id = world.register_system(play_tween_many::<Sprite, With<Enemy>, NoEvent, ()>);
+        let enemies: Vec<_> = (0..3)
+            .map(|_| world.spawn((Sprite::default(), Enemy)).id())
+            .collect();
+        world.spawn(PlayTweenMany::<_, With<Enemy>, _, _>::new(Tween::repeat(
+            RepeatTimes::Infinite,
+            Tween::new(
+                Duration::from_secs(1),
+                Lerp,
+                TweenSpriteColor {
+                    start: Color::WHITE,
+                    end: Color::BLACK,
+                },
+            )
+            .with_random_duration(Rand::new(
+                Duration::from_millis(200),
+                Duration::from_millis(900),
+            )),
+        )));
+
+        for _ in 0..20 {
+            // WHEN
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(170));
+            world.run_system(play_id).unwrap();
+
+            // THEN
+            let colors: Vec<_> = enemies
+                .iter()
+                .map(|&enemy| world.get::<Sprite>(enemy).unwrap().color)
+                .collect();
+            assert!(colors.iter().all(|color| *color == colors[0]));
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::easing::Easing;
+use crate::plugin::TweenTranslation;
+use crate::tween::{Tween, TweenApplier};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// How long [`Tween::through_points`] takes.
+#[derive(Debug, Clone, Copy)]
+pub enum PathTiming {
+    /// Units per second.
+    Speed(f32),
+    /// Duration of the whole path.
+    Duration(Duration),
+}
+
+/// How [`Tween::through_points`] eases.
+#[derive(Debug, Clone, Copy)]
+pub enum PathEasing {
+    /// Each segment is eased on its own, ie. stopping at each waypoint.
+    PerSegment(Easing),
+    /// The whole path is eased at once.
+    WholePath(Easing),
+}
+
+/// Moves the translation along a path of points, at constant speed.
+#[derive(Debug, Clone, Default)]
+pub struct TweenPath {
+    points: Vec<Vec3>,
+    /// Distance from the start up to each point.
+    distances: Vec<f32>,
+}
+
+impl TweenPath {
+    pub fn new(points: impl Into<Vec<Vec3>>) -> Self {
+        let points = points.into();
+        let distances = points
+            .iter()
+            .scan(
+                (0.0, points.first().copied()),
+                |(distance, last), &point| {
+                    *distance += last.map_or(0.0, |last| last.distance(point));
+                    *last = Some(point);
+                    Some(*distance)
+                },
+            )
+            .collect();
+        Self { points, distances }
+    }
+
+    pub fn length(&self) -> f32 {
+        self.distances.last().copied().unwrap_or_default()
+    }
+
+    /// The point at `distance` along the path, extrapolating beyond its ends.
+    pub fn at(&self, distance: f32) -> Vec3 {
+        match self.points.len() {
+            0 => Vec3::ZERO,
+            1 => self.points[0],
+            len => {
+                let i = self
+                    .distances
+                    .partition_point(|&d| d <= distance)
+                    .clamp(1, len - 1);
+                let segment = self.distances[i] - self.distances[i - 1];
+                if segment == 0.0 {
+                    return self.points[i];
+                }
+                let t = (distance - self.distances[i - 1]) / segment;
+                self.points[i - 1].lerp(self.points[i], t)
+            }
+        }
+    }
+}
+
+impl TweenApplier<Transform> for TweenPath {
+    fn apply(&mut self, target: &mut Transform, value: f32) {
+        target.translation = self.at(value * self.length());
+    }
+}
+
+impl<E> Tween<Transform, E> {
+    /// Moves the translation through the given waypoints, ie. a path found by pathfinding.
+    /// Returns `None` for a [`PathTiming::Speed`] which isn't positive, or too slow to cover the
+    /// path in a [`Duration`].
+    pub fn through_points(points: &[Vec3], timing: PathTiming, easing: PathEasing) -> Option<Self> {
+        let path = TweenPath::new(points);
+        let length = path.length();
+        let duration = match timing {
+            PathTiming::Speed(speed) if length > 0.0 => {
+                Duration::try_from_secs_f32(length / speed).ok()?
+            }
+            PathTiming::Speed(speed) if speed > 0.0 => Duration::ZERO,
+            PathTiming::Speed(_) => return None,
+            PathTiming::Duration(duration) => duration,
+        };
+        Some(match easing {
+            PathEasing::WholePath(easing) => Tween::once(duration, easing, path, None),
+            PathEasing::PerSegment(easing) => Tween::sequence(
+                points
+                    .windows(2)
+                    .map(|segment| {
+                        let fraction = if length > 0.0 {
+                            segment[0].distance(segment[1]) / length
+                        } else {
+                            0.0
+                        };
+                        Tween::once(
+                            duration.mul_f32(fraction),
+                            easing,
+                            TweenTranslation {
+                                start: segment[0],
+                                end: segment[1],
+                            },
+                            None,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tween::NoEvent;
+
+    #[test]
+    fn tween_through_points() {
+        let points = [Vec3::ZERO, Vec3::X, Vec3::X + Vec3::Y * 3.0];
+        for easing in [
+            PathEasing::PerSegment(Easing::Linear),
+            PathEasing::WholePath(Easing::Linear),
+        ] {
+            let mut tween =
+                Tween::<_, NoEvent>::through_points(&points, PathTiming::Speed(2.0), easing)
+                    .unwrap();
+            assert_eq!(tween.duration(), Some(Duration::from_secs(2)));
+
+            let mut transform = Transform::default();
+            tween.advance(&mut transform, &mut NoEvent, Duration::from_secs(1));
+            assert_eq!(transform.translation, Vec3::new(1.0, 1.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn through_points_rejects_invalid_speed() {
+        let points = [Vec3::ZERO, Vec3::X];
+        let easing = PathEasing::WholePath(Easing::Linear);
+        for speed in [0.0, -1.0, f32::NAN, 1e-40] {
+            assert!(
+                Tween::<_, NoEvent>::through_points(&points, PathTiming::Speed(speed), easing)
+                    .is_none()
+            );
+        }
+        let tween = Tween::<_, NoEvent>::through_points(&[Vec3::X], PathTiming::Speed(1.0), easing);
+        assert_eq!(tween.unwrap().duration(), Some(Duration::ZERO));
+    }
+}
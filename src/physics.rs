@@ -0,0 +1,314 @@
+//! Tweens for physics bodies. Instead of teleporting the [`Transform`], tweens move a
+//! [`KinematicTarget`] and [`kinematic_velocity`] derives the velocity reaching it within a frame.
+//! Copy the [`KinematicVelocity`] into the velocity of your physics engine (ie. avian's
+//! `LinearVelocity`/`AngularVelocity` or rapier's `Velocity`) for kinematic bodies, so tweened
+//! platforms push and carry other bodies.
+use crate::plugin::play_tween_animation;
+use crate::random::TweenRng;
+use crate::tween::{ApplyError, TweenApplier};
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+
+/// Where a kinematic body should be, tweened instead of its [`Transform`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct KinematicTarget {
+    pub translation: Vec3,
+    pub rotation: Quat,
+}
+
+/// Velocity moving a body to its [`KinematicTarget`], in units (radians) per second.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct KinematicVelocity {
+    pub linear: Vec3,
+    pub angular: Vec3,
+}
+
+/// Applies a [`Transform`] applier (ie. [`TweenTranslation`](crate::TweenTranslation)) to a [`KinematicTarget`].
+#[derive(Clone, Copy, Debug)]
+pub struct Kinematic<A>(pub A);
+
+/// Runs `f` on a [`Transform`] at `target`, moving the target to where the transform ends up.
+fn with_transform<R>(target: &mut KinematicTarget, f: impl FnOnce(&mut Transform) -> R) -> R {
+    let mut transform =
+        Transform::from_translation(target.translation).with_rotation(target.rotation);
+    let result = f(&mut transform);
+    target.translation = transform.translation;
+    target.rotation = transform.rotation;
+    result
+}
+
+impl<A: TweenApplier<Transform> + Clone> TweenApplier<KinematicTarget> for Kinematic<A> {
+    fn apply(&mut self, target: &mut KinematicTarget, value: f32) {
+        with_transform(target, |transform| self.0.apply(transform, value));
+    }
+
+    fn apply_f64(&mut self, target: &mut KinematicTarget, value: f64) {
+        with_transform(target, |transform| self.0.apply_f64(transform, value));
+    }
+
+    fn try_apply(&mut self, target: &mut KinematicTarget, value: f64) -> Result<(), ApplyError> {
+        with_transform(target, |transform| self.0.try_apply(transform, value))
+    }
+
+    fn randomize(&mut self, rng: &mut TweenRng) {
+        self.0.randomize(rng);
+    }
+
+    fn on_start(&mut self, target: &mut KinematicTarget) {
+        with_transform(target, |transform| self.0.on_start(transform));
+    }
+
+    fn on_complete(&mut self, target: &mut KinematicTarget) {
+        with_transform(target, |transform| self.0.on_complete(transform));
+    }
+
+    fn on_loop(&mut self, target: &mut KinematicTarget) {
+        with_transform(target, |transform| self.0.on_loop(transform));
+    }
+}
+
+/// Values a [`TweenField`] can tween.
+pub trait TweenValue: Copy + Send + Sync + 'static {
+    fn interpolate(start: Self, end: Self, value: f32) -> Self;
+}
+
+/// Tweens a field of a physics component, ie. `LinearVelocity`, `AngularVelocity`, `GravityScale`
+/// or the limits of a joint - for force ramps and slow-downs:
+/// ```ignore
+/// let slow_down = TweenField::new(Vec3::X * 5.0, Vec3::ZERO, |velocity: &mut LinearVelocity| {
+///     &mut velocity.0
+/// });
+/// let ramp_gravity = TweenField::new(0.0, 1.0, |gravity: &mut GravityScale| &mut gravity.0);
+/// ```
+pub struct TweenField<C, V> {
+    pub start: V,
+    pub end: V,
+    field: fn(&mut C) -> &mut V,
+}
+
+impl<C, V: Copy> Clone for TweenField<C, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, V: Copy> Copy for TweenField<C, V> {}
+
+impl<C, V> TweenField<C, V> {
+    pub fn new(start: V, end: V, field: fn(&mut C) -> &mut V) -> Self {
+        Self { start, end, field }
+    }
+}
+
+impl<C: 'static, V: TweenValue> TweenApplier<C> for TweenField<C, V> {
+    fn apply(&mut self, target: &mut C, value: f32) {
+        *(self.field)(target) = V::interpolate(self.start, self.end, value);
+    }
+}
+
+impl TweenValue for f32 {
+    fn interpolate(start: Self, end: Self, value: f32) -> Self {
+        start + (end - start) * value
+    }
+}
+
+impl TweenValue for Vec2 {
+    fn interpolate(start: Self, end: Self, value: f32) -> Self {
+        start.lerp(end, value)
+    }
+}
+
+impl TweenValue for Vec3 {
+    fn interpolate(start: Self, end: Self, value: f32) -> Self {
+        start.lerp(end, value)
+    }
+}
+
+impl TweenValue for Quat {
+    fn interpolate(start: Self, end: Self, value: f32) -> Self {
+        start.slerp(end, value)
+    }
+}
+
+/// Derives the [`KinematicVelocity`] reaching the [`KinematicTarget`] within a step of
+/// [`Time<I>`]. Run it in the schedule the physics engine steps in, with the matching clock - ie.
+/// `kinematic_velocity::<()>` in [`FixedUpdate`], where [`Time`] is the fixed clock.
+pub fn kinematic_velocity<I: Default + Send + Sync + 'static>(
+    time: Res<Time<I>>,
+    mut bodies: Query<(&Transform, &KinematicTarget, &mut KinematicVelocity)>,
+) {
+    let dt = time.delta_seconds();
+    if dt <= 0.0 {
+        return;
+    }
+    for (transform, target, mut velocity) in bodies.iter_mut() {
+        let (axis, mut angle) = (target.rotation * transform.rotation.inverse()).to_axis_angle();
+        // Shortest way
+        if angle > std::f32::consts::PI {
+            angle -= std::f32::consts::TAU;
+        }
+        velocity.set_if_neq(KinematicVelocity {
+            linear: (target.translation - transform.translation) / dt,
+            angular: axis * angle / dt,
+        });
+    }
+}
+
+/// Plays tweens of [`KinematicTarget`]s and updates the [`KinematicVelocity`] afterwards, in
+/// [`FixedUpdate`] by default. Tweens in [`Real`] time are played in [`Update`], the velocity
+/// catches up with them in the next step.
+pub struct KinematicTweenPlugin<E> {
+    schedule: InternedScheduleLabel,
+    _phantom: std::marker::PhantomData<E>,
+}
+
+impl<E> Default for KinematicTweenPlugin<E> {
+    fn default() -> Self {
+        Self {
+            schedule: FixedUpdate.intern(),
+            _phantom: Default::default(),
+        }
+    }
+}
+
+impl<E> KinematicTweenPlugin<E> {
+    /// Plays the tweens and updates the velocities in `schedule` - ie. the one your physics engine
+    /// steps in.
+    pub fn in_schedule(self, schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            ..self
+        }
+    }
+}
+
+impl<E: Event + Clone> Plugin for KinematicTweenPlugin<E> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            self.schedule,
+            (
+                play_tween_animation::<KinematicTarget, E, ()>,
+                kinematic_velocity::<()>,
+            )
+                .chain(),
+        )
+        .add_systems(
+            Update,
+            play_tween_animation::<KinematicTarget, E, Real>.before(kinematic_velocity::<()>),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::{PlayTween, TweenTranslation};
+    use crate::tween::{Lerp, NoEvent, Tween};
+    use std::time::Duration;
+
+    #[test]
+    fn test_kinematic_velocity() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let play_tween_id =
+            world.register_system(play_tween_animation::<KinematicTarget, NoEvent, ()>);
+        let velocity_id = world.register_system(kinematic_velocity::<()>);
+        let body = world
+            .spawn((
+                Transform::default(),
+                KinematicTarget::default(),
+                KinematicVelocity::default(),
+                PlayTween::new(Tween::new(
+                    Duration::from_secs(1),
+                    Lerp,
+                    Kinematic(TweenTranslation {
+                        start: Vec3::ZERO,
+                        end: Vec3::X,
+                    }),
+                )),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+        world.run_system(velocity_id).unwrap();
+
+        // THEN
+        assert_eq!(
+            world.get::<Transform>(body).unwrap().translation,
+            Vec3::ZERO
+        );
+        assert_eq!(
+            *world.get::<KinematicVelocity>(body).unwrap(),
+            KinematicVelocity {
+                linear: Vec3::X,
+                angular: Vec3::ZERO
+            }
+        );
+    }
+
+    #[test]
+    fn kinematic_plugin_in_any_schedule() {
+        for plugin in [
+            KinematicTweenPlugin::<NoEvent>::default(),
+            KinematicTweenPlugin::default().in_schedule(Update),
+        ] {
+            let mut app = App::new();
+            app.add_plugins((bevy::time::TimePlugin, plugin))
+                .add_event::<NoEvent>();
+            app.update();
+            app.update();
+        }
+    }
+
+    #[test]
+    fn kinematic_forwards_full_precision() {
+        #[derive(Clone)]
+        struct Precise;
+
+        impl TweenApplier<Transform> for Precise {
+            fn apply(&mut self, _: &mut Transform, _: f32) {
+                unreachable!("Rounded to f32");
+            }
+
+            fn apply_f64(&mut self, target: &mut Transform, value: f64) {
+                target.translation.x = value as f32;
+            }
+        }
+
+        let mut target = KinematicTarget::default();
+        let mut tween = Tween::<_, NoEvent>::new(Duration::from_secs(1), Lerp, Kinematic(Precise));
+        tween.advance(&mut target, &mut NoEvent, Duration::from_millis(250));
+        assert_eq!(target.translation, Vec3::X * 0.25);
+    }
+
+    #[test]
+    fn test_tween_field() {
+        #[derive(Clone, Copy)]
+        struct JointLimits {
+            min: f32,
+            max: f32,
+        }
+
+        let mut tween = Tween::parallel([
+            Tween::new(
+                Duration::from_secs(1),
+                Lerp,
+                TweenField::new(0.0, -1.0, |limits: &mut JointLimits| &mut limits.min),
+            ),
+            Tween::new(
+                Duration::from_secs(1),
+                Lerp,
+                TweenField::new(0.0, 2.0, |limits: &mut JointLimits| &mut limits.max),
+            ),
+        ]);
+
+        let mut limits = JointLimits { min: 0.0, max: 0.0 };
+        tween.advance(&mut limits, &mut NoEvent, Duration::from_millis(500));
+        assert_eq!((limits.min, limits.max), (-0.5, 1.0));
+    }
+}
@@ -1,8 +1,30 @@
+use crate::bake::BakedTween;
+use crate::def::*;
+use crate::dynamic::*;
+use crate::easing::Easing;
+use crate::formation::*;
+use crate::random::*;
+use crate::record::TweenRecording;
 use crate::tween::*;
+use crate::ui::*;
 use bevy::audio::Volume;
 use bevy::color::ColorRange;
+use bevy::core_pipeline::dof::DepthOfFieldSettings;
+use bevy::core_pipeline::Skybox;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::render::camera::Viewport;
+use bevy::render::mesh::morph::MorphWeights;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::sprite::Anchor;
+use bevy::transform::TransformSystem;
+use bevy::utils::{HashMap, HashSet, Instant, Parallel};
+use std::any::TypeId;
+use std::borrow::Cow;
 use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 
 #[derive(Clone)]
 pub struct Start<T>(pub T);
@@ -40,10 +62,37 @@ pub struct PlayBufferedTweenBundle<
     B: 'static + Send + Sync,
     I: 'static + Send + Sync = (),
 > {
-    pub play_tween: PlayTween<(T, TweenBuffer<B>), E, I>,
+    pub play_tween: PlayTween<Buffered<T, B>, E, I>,
     pub buffer: TweenBuffer<B>,
 }
 
+impl<T: Component, E: Event, B: 'static + Send + Sync, I: 'static + Send + Sync>
+    PlayBufferedTweenBundle<T, E, B, I>
+{
+    pub fn new(play_tween: PlayTween<Buffered<T, B>, E, I>, buffer: B) -> Self {
+        Self {
+            play_tween,
+            buffer: TweenBuffer::new(buffer),
+        }
+    }
+}
+
+/// Target of a buffered tween. Only the progress is recorded, which is then applied to `T` with
+/// the applier of the [`TweenBuffer<W>`] - without copying the target or the buffer.
+pub struct Buffered<T, W> {
+    value: Option<f32>,
+    _phantom: PhantomData<(T, W)>,
+}
+
+impl<T, W> Default for Buffered<T, W> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct BufferApplier<T> {
     _phantom: PhantomData<T>,
@@ -57,6 +106,14 @@ impl<T> BufferApplier<T> {
     }
 }
 
+impl TweenApplier<Buffered<Transform, TweenTranslation>> for BufferApplier<TweenTranslation> {
+    fn apply(&mut self, target: &mut Buffered<Transform, TweenTranslation>, value: f32) {
+        target.value = Some(value);
+    }
+}
+
+/// For the `(T, TweenBuffer<W>)` target used before [`Buffered`], see
+/// [`play_buffered_pair_tween_animation`].
 impl TweenApplier<(Transform, TweenBuffer<TweenTranslation>)> for BufferApplier<TweenTranslation> {
     fn apply(&mut self, target: &mut (Transform, TweenBuffer<TweenTranslation>), value: f32) {
         target.1.tween.apply(&mut target.0, value);
@@ -69,20 +126,347 @@ pub struct TweenTweenTranslation {
     pub end: Vec3,
 }
 
-#[derive(Component, Clone, Default)]
+/// Plays a tween on a component of the same entity, or as a resource on a resource (see
+/// [`play_resource_tween`]).
+#[derive(Component, Resource, Clone, Default)]
 pub struct PlayTween<T, E, I> {
     tween: Tween<T, E>,
+    /// Reused for stepping the tween each frame.
+    stack: TweenStack<T, E>,
     despawn: bool,
     remove: bool,
+    paused: bool,
+    finishing: bool,
+    halt: Option<Halt>,
+    /// Whether anything was applied yet.
+    started: bool,
+    /// Whether the tween completed, so it is reported once.
+    completed: bool,
+    id: TweenId,
+    max_delta: Option<Duration>,
+    substep: Option<Duration>,
+    error_policy: ApplyErrorPolicy,
+    blend: Option<Blend<T, E>>,
+    target_path: Option<TargetPath>,
+    additive: Option<Additive<T>>,
+    /// Progress to play at instead of advancing with time, see [`PlayTween::set_progress`].
+    progress: Option<f32>,
+    gate: Option<Gate>,
+    priority: i32,
+    /// The exclusive group, and whether this tween claimed it yet.
+    group: Option<(Cow<'static, str>, bool)>,
+    recorder: Option<Recorder<T>>,
+    watchdog: Option<Watchdog>,
     _time: PhantomData<I>,
 }
 
+/// Forces a [`PlayTween`] to complete after a while, see [`PlayTween::with_max_lifetime`].
+#[derive(Clone, Copy)]
+struct Watchdog {
+    max_lifetime: Duration,
+    /// When the tween was first played.
+    since: Option<Instant>,
+}
+
+impl Watchdog {
+    fn expired(&mut self) -> bool {
+        self.since.get_or_insert_with(Instant::now).elapsed() >= self.max_lifetime
+    }
+}
+
+/// The descendant animated by a [`PlayTween`], see [`PlayTween::with_target_path`].
+#[derive(Clone)]
+struct TargetPath {
+    names: Vec<String>,
+    /// The descendant found last.
+    resolved: Option<Entity>,
+}
+
+/// Components which [`PlayTween::with_target_path`] can animate. [`DefaultTweenPlugin`] plays
+/// [`Transform`]s, [`Sprite`]s and [`BackgroundColor`]s, for other components add
+/// [`play_tween_on_path`] and implement this.
+pub trait TweenPathTarget: Component {}
+
+impl TweenPathTarget for Transform {}
+
+impl TweenPathTarget for Sprite {}
+
+impl TweenPathTarget for BackgroundColor {}
+
+/// Records the values applied by a [`PlayTween`], see [`PlayTween::record`].
+#[derive(Clone)]
+struct Recorder<T> {
+    recording: TweenRecording<T>,
+    /// Time played so far.
+    elapsed: Duration,
+    push: fn(&TweenRecording<T>, Duration, &T),
+}
+
+/// The tweens which last started in each exclusive group of an entity, see
+/// [`PlayTween::exclusive_group`]. Added automatically.
+#[derive(Component, Debug, Clone, Default)]
+pub struct TweenGroups(HashMap<Cow<'static, str>, TweenId>);
+
+impl TweenGroups {
+    /// The tween playing in the group `label`.
+    pub fn get(&self, label: &str) -> Option<TweenId> {
+        self.0.get(label).copied()
+    }
+}
+
+/// Advances a [`PlayTween`] by steps on events instead of with time, see [`PlayTween::step_on`].
+#[derive(Clone, Copy)]
+struct Gate {
+    event: TypeId,
+    step: Duration,
+    /// Steps received, but not played yet.
+    pending: u32,
+}
+
+/// The state of an additive [`PlayTween`], see [`PlayTween::additive`].
+#[derive(Clone)]
+struct Additive<T> {
+    /// The output of the tween, added to `base`.
+    delta: T,
+    base: T,
+    /// What was written last, to tell whether something else changed the target since.
+    written: Option<T>,
+}
+
+/// The [`SystemSet`] of [`play_additive_tweens`] in [`PostUpdate`], before transforms are
+/// propagated. Order it after systems overwriting transforms, ie. `bevy_animation`s.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AdditiveTweens;
+
+/// Targets whose values can be mixed, to blend an interrupted tween into its replacement - see
+/// [`PlayTween::replace_tween_blended`].
+pub trait TweenBlend: Clone + Send + Sync + 'static {
+    /// Mixes `from` into this value, keeping only `weight` (0 to 1) of this one.
+    fn blend_from(&mut self, from: &Self, weight: f32);
+}
+
+impl TweenBlend for Transform {
+    fn blend_from(&mut self, from: &Self, weight: f32) {
+        self.translation = from.translation.lerp(self.translation, weight);
+        self.rotation = from.rotation.slerp(self.rotation, weight);
+        self.scale = from.scale.lerp(self.scale, weight);
+    }
+}
+
+impl TweenBlend for Sprite {
+    fn blend_from(&mut self, from: &Self, weight: f32) {
+        self.color = from.color.mix(&self.color, weight);
+    }
+}
+
+impl TweenBlend for BackgroundColor {
+    fn blend_from(&mut self, from: &Self, weight: f32) {
+        self.0 = from.0.mix(&self.0, weight);
+    }
+}
+
+/// A replaced tween, which keeps on playing while its output is faded out.
+#[derive(Clone)]
+struct Blend<T, E> {
+    tween: Tween<T, E>,
+    duration: Duration,
+    elapsed: Duration,
+    clone: fn(&T) -> T,
+    blend_from: fn(&mut T, &T, f32),
+}
+
+/// What a [`PlayTween`] does when applying a value fails, see [`TweenApplier::try_apply`]. The
+/// error is logged in any case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApplyErrorPolicy {
+    /// Keep on playing.
+    #[default]
+    Log,
+    /// Pause the tween, it can be resumed with [`PlayTween::resume`].
+    StopTween,
+    /// Remove the [`PlayTween`].
+    RemoveComponent,
+}
+
+/// The outcome of [`PlayTween::play`].
+struct Played {
+    result: TweenProgress,
+    changed: bool,
+    /// Whether this was the first change.
+    started: bool,
+    /// Whether the [`PlayTween`] has to be removed because of an error.
+    remove: bool,
+    /// Whether the tween just completed.
+    completed: bool,
+}
+
+/// The outcome of [`PlayTween::update`], which the play systems act on.
+#[derive(Default)]
+struct Updated {
+    /// Whether the target changed.
+    changed: bool,
+    /// Whether this was the first change.
+    started: bool,
+    /// Whether the tween just completed.
+    completed: bool,
+    /// Whether the [`PlayTween`] has to be removed.
+    remove: bool,
+    despawn: bool,
+    /// Groups to add to the entity, see [`PlayTween::claim_group`].
+    groups: Option<TweenGroups>,
+}
+
+/// Whether a [`PlayTween`] may write its target in this frame, see [`TweenConflicts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Claim {
+    Write,
+    /// Skip this frame, another tween writes the target.
+    Wait,
+    /// Stop the tween.
+    Reject,
+}
+
+/// Identifies a [`PlayTween`] in [`TweenStarted`] and [`TweenEvent`]s, ie. to tell apart multiple
+/// tweens on the same entity. Unique unless set by hand, clones of a [`PlayTween`] share the id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TweenId(pub u64);
+
+static NEXT_TWEEN_ID: AtomicU64 = AtomicU64::new(0);
+
+impl TweenId {
+    /// A new unique id.
+    pub fn new() -> Self {
+        Self(NEXT_TWEEN_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for TweenId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Halt {
+    Stop,
+    Cancel,
+    /// Cancelled, with the target already restored from its [`Snapshot`].
+    Restored,
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 pub struct TweenTranslation {
     pub start: Vec3,
     pub end: Vec3,
 }
 
+/// A separate easing for each axis of a vector.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct AxisEasing {
+    pub x: Easing,
+    pub y: Easing,
+    pub z: Easing,
+}
+
+impl AxisEasing {
+    pub fn new(x: impl Into<Easing>, y: impl Into<Easing>, z: impl Into<Easing>) -> Self {
+        Self {
+            x: x.into(),
+            y: y.into(),
+            z: z.into(),
+        }
+    }
+
+    pub fn lerp_vec2(&self, start: Vec2, end: Vec2, value: f32) -> Vec2 {
+        let eased = Vec2::new(self.x.ease(value), self.y.ease(value));
+        start + (end - start) * eased
+    }
+
+    pub fn lerp_vec3(&self, start: Vec3, end: Vec3, value: f32) -> Vec3 {
+        let eased = Vec3::new(self.x.ease(value), self.y.ease(value), self.z.ease(value));
+        start + (end - start) * eased
+    }
+}
+
+/// Animates the translation with a separate easing per axis, ie. linear on X and bouncing on Y
+/// for a jump. The easing of the tween itself is applied first, so it's usually [`Lerp`].
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenTranslationPerAxis {
+    pub start: Vec3,
+    pub end: Vec3,
+    pub easing: AxisEasing,
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenRotation {
+    pub start: Quat,
+    pub end: Quat,
+}
+
+/// Rotation around the z axis (radians), ie. of a 2D sprite. Unlike [`TweenRotation`] it can
+/// also spin the long way around, see [`ArcPath`].
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenRotationZ {
+    pub start: f32,
+    pub end: f32,
+    pub path: ArcPath,
+}
+
+/// Rotates by `angle` (radians) around the (normalized) `axis` in world space, starting from the
+/// rotation the target had when the tween started. Unlike [`TweenRotation`] it can spin any number
+/// of full turns, ie. `4.0 * TAU` for a continuous spin.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenRotateBy {
+    pub axis: Vec3,
+    pub angle: f32,
+    /// The rotation when the tween started.
+    start: Option<Quat>,
+}
+
+impl TweenRotateBy {
+    pub fn new(axis: Vec3, angle: f32) -> Self {
+        Self {
+            axis,
+            angle,
+            start: None,
+        }
+    }
+}
+
+/// The hue (degrees) of a [`Sprite`] or [`BackgroundColor`], keeping its other channels. HSV, HSL
+/// and OKLCH colors keep their color space, others are converted to OKLCH.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenHue {
+    pub start: f32,
+    pub end: f32,
+    pub path: ArcPath,
+}
+
+/// Which way around angles are interpolated, see [`TweenRotationZ`] and [`TweenHue`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArcPath {
+    /// Across the 0/360° boundary, if that's shorter.
+    #[default]
+    Shortest,
+    /// The other way around, ie. for a full spin.
+    Longest,
+}
+
+impl ArcPath {
+    /// Interpolates between the angles `start` and `end`, which repeat every `period` - ie. 360
+    /// for degrees.
+    pub fn lerp(self, start: f32, end: f32, value: f32, period: f32) -> f32 {
+        let mut delta = (end - start).rem_euclid(period);
+        if delta > period / 2.0 {
+            delta -= period;
+        }
+        if self == ArcPath::Longest && delta != 0.0 {
+            delta -= period.copysign(delta);
+        }
+        start + delta * value
+    }
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 pub struct TweenScale {
     pub start: Vec3,
@@ -95,12 +479,147 @@ pub struct TweenSpriteColor {
     pub end: Color,
 }
 
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenSpriteAlpha {
+    pub start: f32,
+    pub end: f32,
+}
+
+/// Translation in world space, which [`apply_global_translation`] writes to the local
+/// [`Transform`] while it changes - compensating for the transform of the parent.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct GlobalTranslation(pub Vec3);
+
+/// Animates a [`GlobalTranslation`], to move children of moving parents to world positions.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenGlobalTranslation {
+    pub start: Vec3,
+    pub end: Vec3,
+}
+
+/// Animates [`Sprite::anchor`] as [`Anchor::Custom`] offsets.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenAnchor {
+    pub start: Vec2,
+    pub end: Vec2,
+}
+
+/// Animates [`Sprite::rect`], ie. to reveal a sprite or to scroll its texture.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenSpriteRect {
+    pub start: Rect,
+    pub end: Rect,
+}
+
+/// Sets [`Sprite::flip_x`] and/or [`Sprite::flip_y`] once the tween reaches `at` (`0.0` to `1.0`),
+/// the opposite before. Ie. to turn around at the right moment of a movement, or at a point in a
+/// sequence with [`Tween::set`].
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenSpriteFlip {
+    pub flip_x: Option<bool>,
+    pub flip_y: Option<bool>,
+    pub at: f32,
+}
+
+/// Swaps the image through `frames`, each shown from its position (0 to 1) on, ie. for spinners
+/// or simple animations without a texture atlas. The frames must be ordered by position.
+#[derive(Default, Debug, Clone)]
+pub struct TweenImage {
+    pub frames: Vec<(f32, Handle<Image>)>,
+}
+
+impl TweenImage {
+    /// Shows each of `images` for the same time.
+    pub fn evenly(images: impl IntoIterator<Item = Handle<Image>>) -> Self {
+        let images: Vec<_> = images.into_iter().collect();
+        let count = images.len() as f32;
+        Self {
+            frames: images
+                .into_iter()
+                .enumerate()
+                .map(|(index, image)| (index as f32 / count, image))
+                .collect(),
+        }
+    }
+
+    fn frame(&self, value: f32) -> Option<&Handle<Image>> {
+        self.frames
+            .iter()
+            .rev()
+            .find(|(at, _)| value >= *at)
+            .or(self.frames.first())
+            .map(|(_, image)| image)
+    }
+}
+
+/// Animates [`Camera::viewport`] in physical pixels, ie. for split screen transitions or picture in
+/// picture zooms. The depth range of an existing viewport is kept.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenViewport {
+    pub start: URect,
+    pub end: URect,
+}
+
+/// Animates [`Skybox::brightness`], ie. for sky transitions.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenSkyboxBrightness {
+    pub start: f32,
+    pub end: f32,
+}
+
+/// Animates [`DepthOfFieldSettings::focal_distance`], ie. to rack focus in cutscenes.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenFocalDistance {
+    pub start: f32,
+    pub end: f32,
+}
+
+/// Animates [`DepthOfFieldSettings::aperture_f_stops`].
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenAperture {
+    pub start: f32,
+    pub end: f32,
+}
+
+/// Animates a single weight of [`MorphWeights`], ie. a blend shape. Weights out of range are
+/// ignored.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenMorphWeight {
+    pub index: usize,
+    pub start: f32,
+    pub end: f32,
+}
+
+/// Moves the translation by `offset`, relative to wherever it currently is. Only the change since
+/// the last application is applied, so it can be combined with other tweens moving the target.
+/// Starts over from the current translation whenever the tween starts or loops.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenOffset {
+    pub offset: Vec3,
+    applied: Vec3,
+}
+
+impl TweenOffset {
+    pub fn new(offset: Vec3) -> Self {
+        Self {
+            offset,
+            applied: Vec3::ZERO,
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 pub struct TweenBackgroundColor {
     pub start: Color,
     pub end: Color,
 }
 
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenClearColor {
+    pub start: Color,
+    pub end: Color,
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 pub struct TweenVolume {
     pub start: Volume,
@@ -113,200 +632,3174 @@ impl TweenApplier<AudioSink> for TweenVolume {
     }
 }
 
-pub struct DefaultTweenPlugin<E> {
-    _phantom: std::marker::PhantomData<E>,
+/// Animates [`Time::relative_speed`] of the virtual clock. Should be played in [`Real`] time, so
+/// it doesn't slow itself down.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenRelativeSpeed {
+    pub start: f32,
+    pub end: f32,
 }
 
-impl Event for NoEvent {}
+impl TweenApplier<Time<Virtual>> for TweenRelativeSpeed {
+    fn apply(&mut self, target: &mut Time<Virtual>, value: f32) {
+        // Overshooting easings would pass below zero, which the clock rejects
+        target.set_relative_speed(self.start.lerp(self.end, value).max(0.0));
+    }
+}
 
-impl<T, E> PlayTween<T, E, ()> {
-    pub fn new(tween: Tween<T, E>) -> Self {
-        Self::new_with_time(tween)
+/// Plays a shared [`TweenDef`] on the target `T` of the same entity, see [`play_tween_def`].
+#[derive(Component, Clone)]
+pub struct PlayTweenDef<T, E, I> {
+    def: TweenDef<T, E>,
+    cursor: TweenCursor,
+    id: TweenId,
+    finished: bool,
+    remove: bool,
+    despawn: bool,
+    _time: PhantomData<I>,
+}
+
+impl<T, E> PlayTweenDef<T, E, ()> {
+    pub fn new(def: &TweenDef<T, E>) -> Self {
+        Self::new_with_time(def)
     }
 }
 
-impl<T, E> PlayTween<T, E, Real> {
-    pub fn new_real_time(tween: Tween<T, E>) -> Self {
-        Self::new_with_time(tween)
+impl<T, E> PlayTweenDef<T, E, Real> {
+    pub fn new_real_time(def: &TweenDef<T, E>) -> Self {
+        Self::new_with_time(def)
     }
 }
 
-impl<T, E, I> PlayTween<T, E, I> {
-    pub fn new_with_time(tween: Tween<T, E>) -> Self {
+impl<T, E, I> PlayTweenDef<T, E, I> {
+    pub fn new_with_time(def: &TweenDef<T, E>) -> Self {
         Self {
-            tween,
-            despawn: false,
+            def: def.clone(),
+            cursor: def.cursor(),
+            id: TweenId::default(),
+            finished: false,
             remove: false,
-            _time: default(),
+            despawn: false,
+            _time: PhantomData,
         }
     }
 
-    /// After completing this tween, despawn the entity.
-    pub fn despawn(self) -> Self {
-        Self {
-            despawn: true,
-            ..self
-        }
+    /// Removes the [`PlayTweenDef`] once it's done.
+    pub fn remove(mut self) -> Self {
+        self.remove = true;
+        self
     }
 
-    // After completing this tween, remove it (the component).
-    pub fn remove(self) -> Self {
-        Self {
-            remove: true,
-            ..self
-        }
+    /// Despawns the entity once the tween is done.
+    pub fn despawn(mut self) -> Self {
+        self.despawn = true;
+        self
+    }
+
+    pub fn id(&self) -> TweenId {
+        self.id
+    }
+
+    pub fn cursor(&self) -> &TweenCursor {
+        &self.cursor
     }
 }
 
-impl<E> DefaultTweenPlugin<E> {
+/// Captures the target `T` when a [`PlayTween`] of it is added to the same entity. It's restored
+/// when the tween is [cancelled](PlayTween::cancel), or on demand with [`Snapshot::restore`].
+/// Captured and restored by [`snapshot_tween_targets`].
+#[derive(Component, Debug, Clone)]
+pub struct Snapshot<T> {
+    value: Option<T>,
+}
+
+impl<T> Default for Snapshot<T> {
+    fn default() -> Self {
+        Self { value: None }
+    }
+}
+
+impl<T: Clone> Snapshot<T> {
     pub fn new() -> Self {
-        Self {
-            _phantom: Default::default(),
+        Self::default()
+    }
+
+    pub fn get(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    pub fn capture(&mut self, target: &T) {
+        self.value = Some(target.clone());
+    }
+
+    /// Restores the captured target, returns `false` if nothing was captured yet.
+    pub fn restore(&self, target: &mut T) -> bool {
+        if let Some(value) = &self.value {
+            target.clone_from(value);
         }
+        self.value.is_some()
     }
 }
 
-impl<E> Default for DefaultTweenPlugin<E> {
+/// State of the [`PlayTween`] playing on the target `T` of the same entity. Kept up to date by the
+/// play systems, if present.
+#[derive(Component, Debug, Clone)]
+pub struct TweenPlayState<T> {
+    /// Progress of the whole tween, `0.0` if its duration is unknown.
+    pub progress: f32,
+    /// [`Duration::MAX`] if the duration is unknown.
+    pub remaining: Duration,
+    /// Index of the current step, if the tween is a sequence.
+    pub step: usize,
+    _target: PhantomData<T>,
+}
+
+impl<T> Default for TweenPlayState<T> {
     fn default() -> Self {
-        Self::new()
+        Self {
+            progress: 0.0,
+            remaining: Duration::MAX,
+            step: 0,
+            _target: PhantomData,
+        }
     }
 }
 
-impl<E: Event + Clone> Plugin for DefaultTweenPlugin<E> {
-    fn build(&self, app: &mut App) {
-        app.add_event::<E>().add_systems(
-            Update,
-            (
-                play_tween_animation::<Transform, E, ()>,
-                play_tween_animation::<Transform, E, Real>,
-                play_tween_animation::<Sprite, E, ()>,
-                play_tween_animation::<Sprite, E, Real>,
-                play_tween_animation::<BackgroundColor, E, ()>,
-                play_tween_animation::<BackgroundColor, E, Real>,
-                play_tween_animation::<AudioSink, E, ()>,
-                play_tween_animation::<AudioSink, E, Real>,
-                play_tween_animation::<TweenBuffer<TweenTranslation>, E, ()>,
-                play_tween_animation::<TweenBuffer<TweenTranslation>, E, Real>,
-                play_buffered_tween_animation::<Transform, TweenTranslation, E, ()>,
-                play_buffered_tween_animation::<Transform, TweenTranslation, E, Real>,
-            )
-                .chain(),
-        );
+impl<T> TweenPlayState<T> {
+    fn update<E>(&mut self, tween: &Tween<T, E>) {
+        (self.progress, self.remaining) = tween.overall_progress().unwrap_or((0.0, Duration::MAX));
+        self.step = match tween {
+            Tween::Sequence { index, .. } => *index,
+            _ => 0,
+        };
     }
 }
 
-impl<'w, E: Event + Clone> EventSender<E> for EventWriter<'w, E> {
+/// Sent the first time a [`PlayTween`] applies anything to its target - so after leading pauses,
+/// or once it is resumed.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TweenStarted {
+    pub entity: Entity,
+    pub id: TweenId,
+}
+
+/// Every event sent by a [`PlayTween`] on an entity is also sent as [`TweenEvent`], along with
+/// where it came from.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct TweenEvent<E> {
+    pub entity: Entity,
+    pub id: TweenId,
+    pub event: E,
+}
+
+/// All [`PlayTween`]s completed in a frame, sent once per frame in [`PostUpdate`] if enabled with
+/// [`DefaultTweenPlugin::batch_completions`]. Cheaper than handling an event per tween, ie. for
+/// thousands of particles. Also used as resource to collect the completions.
+#[derive(Event, Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TweenCompletedBatch {
+    pub completed: Vec<(Entity, TweenId)>,
+}
+
+/// Sends events to an [`EventWriter`] and, if registered, as [`TweenEvent`]s.
+struct TaggedSender<'a, 'w, E: Event> {
+    writer: &'a mut EventWriter<'w, E>,
+    tagged: Option<&'a mut Events<TweenEvent<E>>>,
+    entity: Entity,
+    id: TweenId,
+}
+
+/// Collects events along with where they came from, to send them later.
+struct TaggingSender<'a, E> {
+    events: &'a mut Vec<(Entity, TweenId, E)>,
+    entity: Entity,
+    id: TweenId,
+}
+
+impl<'a, E: Clone> EventSender<E> for TaggingSender<'a, E> {
     fn send(&mut self, event: &E) {
-        EventWriter::send(self, event.clone());
+        self.events.push((self.entity, self.id, event.clone()));
     }
 }
 
-pub fn play_buffered_tween_animation<
-    T: Component + Clone,
-    W: TweenApplier<T> + 'static + Clone,
-    E: Event + Clone,
-    I: Default + Send + Sync + 'static,
->(
-    time: Res<Time<I>>,
-    mut tweens_to_play: Query<(
-        Entity,
-        &mut PlayTween<(T, TweenBuffer<W>), E, I>,
-        &mut T,
-        Option<&mut TweenBuffer<W>>,
-    )>,
-    mut event_writer: EventWriter<E>,
-    mut commands: Commands,
-) {
-    for (entity, mut play, mut target, tween_buffer) in tweens_to_play.iter_mut() {
-        let Some(mut tween_buffer) = tween_buffer else {
-            error!("Buffered PlayTween without Buffer component");
-            continue;
+impl<'a, 'w, E: Event + Clone> EventSender<E> for TaggedSender<'a, 'w, E> {
+    fn send(&mut self, event: &E) {
+        self.writer.send(event.clone());
+        if let Some(tagged) = self.tagged.as_mut() {
+            tagged.send(TweenEvent {
+                entity: self.entity,
+                id: self.id,
+                event: event.clone(),
+            });
+        }
+    }
+}
+
+/// Limits the time a [`PlayTween`] is advanced in one frame, so long frames (ie. after switching
+/// tabs or halting in a debugger) don't make all tweens jump to their end. See
+/// [`DefaultTweenPlugin::max_delta`] and [`PlayTween::with_max_delta`].
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TweenMaxDelta(pub Duration);
+
+/// How multiple [`PlayTween`]s writing the same target in a frame are resolved, ie. a tween in
+/// game time and one in real time. To layer tweens instead, use [`PlayTween::additive`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictResolution {
+    /// All tweens are played, the one played last wins - depending on the system order.
+    #[default]
+    LastWriter,
+    /// Only the tween with the highest [`PlayTween::with_priority`] writes, the first one on ties.
+    Priority,
+    /// Only the first tween played writes, the others are stopped with a warning.
+    Reject,
+}
+
+/// Detects [`PlayTween`]s writing the same target in a frame, see [`ConflictResolution`]. Enabled
+/// with [`DefaultTweenPlugin::conflicts`]. Additive tweens never conflict. Paused tweens don't
+/// claim their target, tweens waiting for a target with a higher priority are paused meanwhile.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TweenConflicts {
+    resolution: ConflictResolution,
+    warn: bool,
+    /// The tweens which wrote targets in this frame, along with their priority.
+    claims: HashMap<(Entity, TypeId), (TweenId, i32)>,
+    /// Conflicting tweens found in this and the last frame, so each conflict is only logged once.
+    conflicts: HashSet<(TweenId, TweenId)>,
+    previous: HashSet<(TweenId, TweenId)>,
+}
+
+impl TweenConflicts {
+    pub fn new(resolution: ConflictResolution) -> Self {
+        Self {
+            resolution,
+            ..default()
+        }
+    }
+
+    /// Logs a warning for every conflict, ie. to find them while debugging.
+    pub fn with_warnings(self) -> Self {
+        Self { warn: true, ..self }
+    }
+
+    /// Whether the tween `id` may write the target `T` of `entity` in this frame.
+    fn claim<T: 'static>(&mut self, entity: Entity, id: TweenId, priority: i32) -> Claim {
+        let claim = self
+            .claims
+            .entry((entity, TypeId::of::<T>()))
+            .or_insert((id, priority));
+        if claim.0 == id {
+            return Claim::Write;
+        }
+        let other = claim.0;
+        let result = match self.resolution {
+            ConflictResolution::LastWriter => Claim::Write,
+            ConflictResolution::Priority if priority > claim.1 => Claim::Write,
+            ConflictResolution::Priority => Claim::Wait,
+            ConflictResolution::Reject => Claim::Reject,
         };
-        // TODO find a way without moving data around
-        let mut tmp_target = (target.clone(), tween_buffer.clone());
-        let result = play
-            .tween
-            .advance(&mut tmp_target, &mut event_writer, time.delta());
-        *target = tmp_target.0;
-        *tween_buffer = tmp_target.1;
-        if matches!(result, TweenProgress::Done { .. }) {
-            if play.remove {
-                commands
-                    .entity(entity)
-                    .remove::<PlayTween<(T, TweenBuffer<W>), E, I>>();
-            }
-            if play.despawn {
-                commands.entity(entity).despawn();
-            }
+        if result == Claim::Write {
+            *claim = (id, priority);
         }
+        let pair = (other.min(id), other.max(id));
+        let new = self.conflicts.insert(pair) && !self.previous.contains(&pair);
+        if new && (self.warn || result == Claim::Reject) {
+            warn!(
+                "Tweens {other:?} and {id:?} both write {} of {entity:?}",
+                std::any::type_name::<T>()
+            );
+        }
+        result
     }
 }
 
-pub fn play_tween_animation<T: Component, E: Event + Clone, I: Default + Send + Sync + 'static>(
-    time: Res<Time<I>>,
-    mut tweens_to_play: Query<(Entity, &mut PlayTween<T, E, I>, &mut T)>,
-    mut event_writer: EventWriter<E>,
-    mut commands: Commands,
-) {
-    for (entity, mut play, mut target) in tweens_to_play.iter_mut() {
-        let result = play
-            .tween
-            .advance(&mut target, &mut event_writer, time.delta());
-        if matches!(result, TweenProgress::Done { .. }) {
-            if play.remove {
-                commands.entity(entity).remove::<PlayTween<T, E, I>>();
-            }
-            if play.despawn {
-                commands.entity(entity).despawn();
-            }
+/// Forgets which tweens wrote which targets, at the start of a frame.
+pub fn clear_tween_conflicts(mut conflicts: ResMut<TweenConflicts>) {
+    let conflicts = &mut *conflicts;
+    conflicts.claims.clear();
+    conflicts.previous = std::mem::take(&mut conflicts.conflicts);
+}
+
+/// Where the play systems put the results of [`PlayTween`]s: events, completions, conflicts and
+/// commands.
+#[derive(SystemParam)]
+pub struct TweenOutputs<'w, 's, E: Event> {
+    event_writer: EventWriter<'w, E>,
+    started_events: Option<ResMut<'w, Events<TweenStarted>>>,
+    tagged_events: Option<ResMut<'w, Events<TweenEvent<E>>>>,
+    completed: Option<ResMut<'w, TweenCompletedBatch>>,
+    conflicts: Option<ResMut<'w, TweenConflicts>>,
+    commands: Commands<'w, 's>,
+}
+
+impl<'w, 's, E: Event + Clone> TweenOutputs<'w, 's, E> {
+    /// Updates the `play` of `entity` on `target`, see [`PlayTween::update`]. Conflicts are
+    /// checked for the component `C` of `conflict_target`, if any. Returns whether the target
+    /// changed.
+    #[allow(clippy::too_many_arguments)]
+    fn update<T, C: 'static, I>(
+        &mut self,
+        entity: Entity,
+        play: &mut PlayTween<T, E, I>,
+        target: &mut T,
+        delta: Duration,
+        groups: Option<Mut<TweenGroups>>,
+        conflict_target: Option<Entity>,
+    ) -> bool
+    where
+        T: Send + Sync + 'static,
+        I: Send + Sync + 'static,
+    {
+        let mut event_sender = TaggedSender {
+            writer: &mut self.event_writer,
+            tagged: self.tagged_events.as_deref_mut(),
+            entity,
+            id: play.id,
+        };
+        let conflicts = &mut self.conflicts;
+        let updated = play.update(
+            target,
+            &mut event_sender,
+            delta,
+            groups,
+            |id, priority| match (conflicts.as_mut(), conflict_target) {
+                (Some(conflicts), Some(target)) => conflicts.claim::<C>(target, id, priority),
+                _ => Claim::Write,
+            },
+            entity,
+        );
+        if let Some(groups) = updated.groups {
+            self.commands.entity(entity).insert(groups);
+        }
+        if let Some(started_events) = self.started_events.as_mut().filter(|_| updated.started) {
+            started_events.send(TweenStarted {
+                entity,
+                id: play.id,
+            });
+        }
+        if let Some(completed) = self.completed.as_mut().filter(|_| updated.completed) {
+            completed.completed.push((entity, play.id));
         }
+        if updated.remove {
+            self.commands.entity(entity).remove::<PlayTween<T, E, I>>();
+        }
+        if updated.despawn {
+            self.commands.entity(entity).despawn();
+        }
+        updated.changed
     }
 }
 
-impl TweenApplier<Transform> for TweenTranslation {
-    fn apply(&mut self, target: &mut Transform, value: f32) {
-        target.translation = self.start.lerp(self.end, value);
+/// The time [`PlayTween`]s are advanced by in the current frame.
+#[derive(SystemParam)]
+pub struct TweenTime<'w, I: Default + Send + Sync + 'static> {
+    time: Res<'w, Time<I>>,
+    max_delta: Option<Res<'w, TweenMaxDelta>>,
+}
+
+impl<'w, I: Default + Send + Sync + 'static> TweenTime<'w, I> {
+    pub fn delta<T, E>(&self, play: &PlayTween<T, E, I>) -> Duration {
+        self.delta_with_max(play.max_delta)
+    }
+
+    /// The delta limited by `max_delta`, or the [`TweenMaxDelta`] if there is none.
+    pub fn delta_with_max(&self, max_delta: Option<Duration>) -> Duration {
+        let max_delta = max_delta.or(self.max_delta.as_ref().map(|max_delta| max_delta.0));
+        max_delta.map_or(self.time.delta(), |max_delta| {
+            self.time.delta().min(max_delta)
+        })
     }
 }
 
-impl TweenApplier<BackgroundColor> for TweenBackgroundColor {
-    fn apply(&mut self, target: &mut BackgroundColor, value: f32) {
-        target.0 = (self.start..self.end).at(value);
+pub struct DefaultTweenPlugin<E> {
+    parallel: bool,
+    max_delta: Option<Duration>,
+    batch_completions: bool,
+    conflicts: Option<TweenConflicts>,
+    dynamic: bool,
+    _phantom: std::marker::PhantomData<E>,
+}
+
+impl Event for NoEvent {}
+
+impl<T, E> PlayTween<T, E, ()> {
+    pub fn new(tween: Tween<T, E>) -> Self {
+        Self::new_with_time(tween)
     }
 }
 
-/// Please note this uses LCH color space and RGB
-impl TweenApplier<Sprite> for TweenSpriteColor {
-    fn apply(&mut self, target: &mut Sprite, value: f32) {
-        target.color = (self.start..self.end).at(value);
+impl<T: TweenPathTarget, E, I> PlayTween<T, E, I> {
+    /// Animates the descendant found by following the children with the given [`Name`]s, instead
+    /// of this entity - ie. a bone of a spawned scene. Played by [`play_tween_on_path`], nothing is
+    /// animated until the descendant exists. It's looked up again only once it's gone.
+    pub fn with_target_path(self, path: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            target_path: Some(TargetPath {
+                names: path.into_iter().map(Into::into).collect(),
+                resolved: None,
+            }),
+            ..self
+        }
+    }
+}
+
+impl<E, I> PlayTween<Transform, E, I> {
+    /// Adds the output of the tween to the target instead of overwriting it, ie. to layer a recoil
+    /// over an animation clip. The tween animates an offset, starting from [`Transform::IDENTITY`].
+    /// Played by [`play_additive_tweens`].
+    pub fn additive(self) -> Self {
+        Self {
+            additive: Some(Additive {
+                delta: Transform::IDENTITY,
+                base: Transform::IDENTITY,
+                written: None,
+            }),
+            ..self
+        }
+    }
+}
+
+impl<T, E> PlayTween<T, E, Real> {
+    pub fn new_real_time(tween: Tween<T, E>) -> Self {
+        Self::new_with_time(tween)
+    }
+}
+
+impl<T, E, I> PlayTween<T, E, I> {
+    pub fn new_with_time(tween: Tween<T, E>) -> Self {
+        Self {
+            tween,
+            stack: default(),
+            despawn: false,
+            remove: false,
+            paused: false,
+            finishing: false,
+            halt: None,
+            started: false,
+            completed: false,
+            id: default(),
+            max_delta: None,
+            substep: None,
+            error_policy: default(),
+            blend: None,
+            target_path: None,
+            additive: None,
+            progress: None,
+            gate: None,
+            priority: 0,
+            group: None,
+            recorder: None,
+            watchdog: None,
+            _time: default(),
+        }
+    }
+
+    /// After completing this tween, despawn the entity.
+    pub fn despawn(self) -> Self {
+        Self {
+            despawn: true,
+            ..self
+        }
+    }
+
+    // After completing this tween, remove it (the component).
+    pub fn remove(self) -> Self {
+        Self {
+            remove: true,
+            ..self
+        }
+    }
+
+    /// Advances in steps of at most `substep` within a frame, so appliers and events see every
+    /// intermediate state instead of one large jump. Zero disables substepping.
+    pub fn with_substep(self, substep: Duration) -> Self {
+        Self {
+            substep: Some(substep).filter(|substep| !substep.is_zero()),
+            ..self
+        }
+    }
+
+    /// Sets how errors applying values are handled.
+    pub fn with_error_policy(self, error_policy: ApplyErrorPolicy) -> Self {
+        Self {
+            error_policy,
+            ..self
+        }
+    }
+
+    /// Uses the given id instead of a generated one.
+    pub fn with_id(self, id: TweenId) -> Self {
+        Self { id, ..self }
+    }
+
+    pub fn id(&self) -> TweenId {
+        self.id
+    }
+
+    /// Plays the tween at `progress` (0 to 1) instead of advancing it with time, ie. to link it to
+    /// a scroll amount or a health fraction. Update it whenever the source changes. Only works for
+    /// tweens of known duration. Moving forward sends events as usual and completes the tween at
+    /// 1.0, moving backward sends none. Non-finite values are ignored.
+    pub fn set_progress(&mut self, progress: f32) {
+        if progress.is_finite() {
+            self.progress = Some(progress.clamp(0.0, 1.0));
+        }
+    }
+
+    /// Advances the tween with time again, after it was driven by [`PlayTween::set_progress`].
+    pub fn release_progress(&mut self) {
+        self.progress = None;
+    }
+
+    /// Records every change of the target into `recording`, along with the time played. Ie. to
+    /// compare runs, or to replay them with [`TweenRecording::replay`].
+    pub fn record(self, recording: TweenRecording<T>) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        Self {
+            recorder: Some(Recorder {
+                recording,
+                elapsed: Duration::ZERO,
+                push: TweenRecording::push,
+            }),
+            ..self
+        }
+    }
+
+    /// Stops the other tweens of the exclusive group `label` on the same entity, once this one
+    /// is played - and stops this one once another tween of the group is played. Ie. movements
+    /// exclude each other, while a tint may play along.
+    pub fn exclusive_group(self, label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            group: Some((label.into(), false)),
+            ..self
+        }
+    }
+
+    /// Claims the exclusive group when played for the first time, or stops if another tween took
+    /// it over. Returns the groups to add to the entity, if it has none yet.
+    fn claim_group(&mut self, groups: Option<Mut<TweenGroups>>) -> Option<TweenGroups> {
+        let id = self.id;
+        let (label, claimed) = self.group.as_mut()?;
+        let Some(mut groups) = groups else {
+            *claimed = true;
+            return Some(TweenGroups(HashMap::from([(label.clone(), id)])));
+        };
+        if !std::mem::replace(claimed, true) {
+            groups.0.insert(label.clone(), id);
+        } else if groups.get(label).is_some_and(|owner| owner != id) {
+            self.halt = Some(Halt::Stop);
+        }
+        None
+    }
+
+    /// Completes the tween once it played for `max_lifetime` of wall-clock time, applying the final
+    /// values (if known) and logging a warning. A safety net against tweens that never end, ie.
+    /// infinite repeats left running by mistake: those stop where they are, sending their
+    /// completion event and being removed or despawned as requested.
+    pub fn with_max_lifetime(self, max_lifetime: Duration) -> Self {
+        Self {
+            watchdog: Some(Watchdog {
+                max_lifetime,
+                since: None,
+            }),
+            ..self
+        }
+    }
+
+    /// Decides which tween writes a target first, see [`ConflictResolution::Priority`].
+    pub fn with_priority(self, priority: i32) -> Self {
+        Self { priority, ..self }
+    }
+
+    /// Advances the tween by `step` whenever a `G` event is sent, instead of with time - ie. to lock
+    /// it to music beats or game turns. The events are counted by [`step_tweens_on`], which has to
+    /// run before the tween is played.
+    pub fn step_on<G: Event>(self, step: Duration) -> Self {
+        Self {
+            gate: Some(Gate {
+                event: TypeId::of::<G>(),
+                step,
+                pending: 0,
+            }),
+            ..self
+        }
+    }
+
+    /// Advances this tween by at most `max_delta` per frame, instead of the global
+    /// [`TweenMaxDelta`]. Use [`Duration::MAX`] to not limit it at all.
+    pub fn with_max_delta(self, max_delta: Duration) -> Self {
+        Self {
+            max_delta: Some(max_delta),
+            ..self
+        }
+    }
+
+    /// Stops advancing the tween until [`PlayTween::resume`] is called.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Jumps to the end on the next update (even if paused), applying the final values and sending
+    /// the events on the way. See [`Tween::finish`].
+    pub fn finish(&mut self) {
+        self.finishing = true;
+    }
+
+    /// Stops playing on the next update, leaving the target at its current values. The
+    /// [`PlayTween`] is removed, but no events are sent and the entity is not despawned.
+    pub fn stop(&mut self) {
+        self.halt = Some(Halt::Stop);
+    }
+
+    /// Like [`PlayTween::stop`], but first restores the values the target had before the tween
+    /// started from its [`Snapshot`]. Without a (captured) snapshot, the tween is moved back to
+    /// its start instead, which only restores the values the tween itself starts with.
+    pub fn cancel(&mut self) {
+        self.halt = Some(Halt::Cancel);
+    }
+
+    /// Handles a requested [`PlayTween::stop`] or [`PlayTween::cancel`], returning whether the
+    /// target changed - or `None` if playing goes on.
+    fn halt(&mut self, target: &mut T) -> Option<bool> {
+        let halt = self.halt.take()?;
+        if halt == Halt::Cancel {
+            self.tween.rewind(target, Duration::MAX);
+        }
+        Some(halt != Halt::Stop)
+    }
+
+    /// Updates the tween for one frame, shared by all play systems: claims the exclusive group,
+    /// handles [`PlayTween::stop`] and [`PlayTween::cancel`], skips paused tweens, resolves
+    /// conflicts with `claim` and plays.
+    fn update<ES: EventSender<E>>(
+        &mut self,
+        target: &mut T,
+        event_sender: &mut ES,
+        delta: Duration,
+        groups: Option<Mut<TweenGroups>>,
+        claim: impl FnOnce(TweenId, i32) -> Claim,
+        source: impl std::fmt::Debug,
+    ) -> Updated {
+        let groups = self.claim_group(groups);
+        if let Some(changed) = self.halt(target) {
+            return Updated {
+                changed,
+                remove: true,
+                groups,
+                ..default()
+            };
+        }
+        if self.paused && !self.finishing {
+            return Updated {
+                groups,
+                ..default()
+            };
+        }
+        match claim(self.id, self.priority) {
+            Claim::Write => (),
+            Claim::Wait => {
+                return Updated {
+                    groups,
+                    ..default()
+                }
+            }
+            Claim::Reject => {
+                return Updated {
+                    remove: true,
+                    groups,
+                    ..default()
+                }
+            }
+        }
+        let played = self.play(target, event_sender, delta, source);
+        let done = matches!(played.result, TweenProgress::Done { .. });
+        Updated {
+            changed: played.changed,
+            started: played.started,
+            completed: played.completed,
+            remove: played.remove || (done && self.remove),
+            despawn: done && self.despawn,
+            groups,
+        }
+    }
+
+    /// Advances the tween, or finishes it if requested. Errors applying values are logged and
+    /// handled according to the [`ApplyErrorPolicy`], `source` tells where they came from.
+    fn play<ES: EventSender<E>>(
+        &mut self,
+        target: &mut T,
+        event_sender: &mut ES,
+        duration: Duration,
+        source: impl std::fmt::Debug,
+    ) -> Played {
+        let expired = self.watchdog.as_mut().is_some_and(Watchdog::expired);
+        if expired {
+            warn!("Tween of {source:?} exceeded its maximum lifetime, completing it");
+            self.watchdog = None;
+            self.finishing = true;
+        }
+        let duration = match self.gate.as_mut() {
+            Some(gate) => gate.step * std::mem::take(&mut gate.pending),
+            None => duration,
+        };
+        if self.gate.is_some() && duration.is_zero() && !self.finishing {
+            return Played {
+                result: TweenProgress::Running,
+                changed: false,
+                started: false,
+                remove: false,
+                completed: false,
+            };
+        }
+        let blended = self.blend.as_mut().map(|blend| {
+            let mut from = (blend.clone)(target);
+            blend.tween.advance(&mut from, &mut NoEvent, duration);
+            from
+        });
+        let (result, mut changed, error) = if let Some(progress) = self.progress {
+            match (self.tween.duration(), self.tween.elapsed()) {
+                (Some(duration), Some(elapsed)) => {
+                    let position = duration.mul_f32(progress);
+                    if position < elapsed {
+                        self.tween.rewind(target, elapsed - position);
+                        (TweenProgress::Running, true, None)
+                    } else if position == elapsed && progress < 1.0 {
+                        (TweenProgress::Running, false, None)
+                    } else {
+                        self.tween.advance_checked_with(
+                            &mut self.stack,
+                            target,
+                            event_sender,
+                            position - elapsed,
+                        )
+                    }
+                }
+                _ => (TweenProgress::Running, false, None),
+            }
+        } else if std::mem::take(&mut self.finishing) {
+            let (ended, changed, error) = self.tween.finish_checked(target, event_sender);
+            if expired && !ended {
+                // Tweens without a known end can't be finished, they complete where they are
+                self.tween.send_completed(event_sender);
+                self.paused = true;
+            }
+            let result = if ended || expired {
+                TweenProgress::Done {
+                    surplus: Duration::ZERO,
+                }
+            } else {
+                TweenProgress::Running
+            };
+            (result, changed, error)
+        } else if let Some(substep) = self.substep {
+            let (mut remaining, mut changed, mut first_error) = (duration, false, None);
+            loop {
+                let step = remaining.min(substep);
+                remaining -= step;
+                let (result, step_changed, error) =
+                    self.tween
+                        .advance_checked_with(&mut self.stack, target, event_sender, step);
+                changed |= step_changed;
+                first_error = first_error.or(error);
+                match result {
+                    TweenProgress::Done { surplus } => {
+                        break (
+                            TweenProgress::Done {
+                                surplus: surplus + remaining,
+                            },
+                            changed,
+                            first_error,
+                        );
+                    }
+                    TweenProgress::Running if remaining.is_zero() => {
+                        break (result, changed, first_error)
+                    }
+                    TweenProgress::Running => (),
+                }
+            }
+        } else {
+            self.tween
+                .advance_checked_with(&mut self.stack, target, event_sender, duration)
+        };
+        if let (Some(blend), Some(from)) = (&mut self.blend, blended) {
+            blend.elapsed += duration;
+            let weight = blend.elapsed.as_secs_f32() / blend.duration.as_secs_f32();
+            (blend.blend_from)(target, &from, weight.min(1.0));
+            changed = true;
+            if blend.elapsed >= blend.duration {
+                self.blend = None;
+            }
+        }
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.elapsed += duration;
+            if changed {
+                (recorder.push)(&recorder.recording, recorder.elapsed, target);
+            }
+        }
+        let started = changed && !self.started;
+        self.started |= changed;
+        let mut remove = false;
+        if let Some(error) = error {
+            warn!("Tween of {source:?} failed to apply a value: {error}");
+            match self.error_policy {
+                ApplyErrorPolicy::Log => (),
+                ApplyErrorPolicy::StopTween => self.pause(),
+                ApplyErrorPolicy::RemoveComponent => remove = true,
+            }
+        }
+        let done = matches!(result, TweenProgress::Done { .. });
+        let completed = done && !self.completed;
+        self.completed = done;
+        Played {
+            completed,
+            result,
+            changed,
+            started,
+            remove,
+        }
+    }
+
+    /// Starts the tween over. The target is not changed until the tween is advanced.
+    pub fn restart(&mut self) {
+        self.tween.reset();
+        self.started = false;
+        self.completed = false;
+    }
+
+    /// Whether the tween played to its end, see [`Tween::is_finished`].
+    pub fn is_finished(&self) -> bool {
+        self.tween.is_finished()
+    }
+
+    /// Swaps in another tween, ie. after its definition was reloaded. If the durations of both
+    /// are known, the new tween continues at the same progress fraction - otherwise it starts over.
+    /// Events of the skipped part are not sent, and the target is not changed until the tween is
+    /// advanced.
+    pub fn replace_tween(&mut self, mut tween: Tween<T, E>) -> Tween<T, E> {
+        if let (Some((progress, _)), Some(duration)) =
+            (self.tween.overall_progress(), tween.duration())
+        {
+            tween.skip(duration.mul_f32(progress));
+        }
+        self.completed = false;
+        std::mem::replace(&mut self.tween, tween)
+    }
+
+    /// Swaps in another tween, which starts over. For `blend`, the replaced tween keeps on playing
+    /// (without sending events) and both outputs are mixed - so the target doesn't jump, even if
+    /// the tweens are completely different.
+    pub fn replace_tween_blended(&mut self, tween: Tween<T, E>, blend: Duration) -> Tween<T, E>
+    where
+        T: TweenBlend,
+        E: Clone,
+    {
+        let replaced = std::mem::replace(&mut self.tween, tween);
+        self.completed = false;
+        self.blend = (!blend.is_zero()).then(|| Blend {
+            tween: replaced.clone(),
+            duration: blend,
+            elapsed: Duration::ZERO,
+            clone: T::clone,
+            blend_from: T::blend_from,
+        });
+        replaced
+    }
+
+    /// Interrupts the tween, passing its current velocity of the value `read` from `target` to
+    /// `next` - which creates the tween to continue with, ie. using [`Tween::from_velocity`]. This
+    /// keeps the motion continuous, instead of stopping dead.
+    pub fn hand_off(
+        &mut self,
+        target: &T,
+        read: impl Fn(&T) -> f32,
+        next: impl FnOnce(f32) -> Tween<T, E>,
+    ) -> Tween<T, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        let velocity = self.tween.output_velocity(target, read);
+        self.completed = false;
+        std::mem::replace(&mut self.tween, next(velocity))
+    }
+
+    /// Progress and remaining time of the whole tween, see [`Tween::overall_progress`].
+    pub fn overall_progress(&self) -> Option<(f32, Duration)> {
+        self.tween.overall_progress()
+    }
+
+    pub fn tween(&self) -> &Tween<T, E> {
+        &self.tween
+    }
+
+    /// The tween being played, ie. to edit it in tools.
+    pub fn tween_mut(&mut self) -> &mut Tween<T, E> {
+        self.completed = false;
+        &mut self.tween
+    }
+
+    /// The tween being played, ie. to put it back into a [`TweenPool`](crate::TweenPool).
+    pub fn into_tween(self) -> Tween<T, E> {
+        self.tween
+    }
+
+    /// Appends a tween, which is played after the current one completed. If it was already
+    /// completed (and not removed), playing resumes with the appended tween - and completes again,
+    /// see [`Tween::push`].
+    pub fn push(&mut self, tween: Tween<T, E>) {
+        self.completed = false;
+        self.tween.push(tween);
+    }
+
+    /// Moves the tween backwards, see [`Tween::rewind`].
+    pub fn rewind(&mut self, target: &mut T, duration: std::time::Duration) -> TweenProgress {
+        self.completed = false;
+        self.tween.rewind(target, duration)
+    }
+
+    /// The runtime state of the tween, ie. for save games. See [`Tween::state`].
+    pub fn state(&self) -> TweenState {
+        self.tween.state()
+    }
+
+    /// Restores a state taken with [`PlayTween::state`], see [`Tween::restore_state`].
+    pub fn restore_state(&mut self, state: &TweenState) -> Result<(), TweenStateMismatch> {
+        self.tween.restore_state(state)?;
+        // A restored finished tween was already reported
+        self.completed = self.tween.is_finished();
+        Ok(())
+    }
+
+    /// Seed all randomized parts of the tween, for reproducible results.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.tween.seed(seed);
+        self
     }
 }
 
-impl TweenApplier<Transform> for TweenScale {
-    fn apply(&mut self, target: &mut Transform, value: f32) {
-        target.scale = self.start.lerp(self.end, value);
+impl<E> DefaultTweenPlugin<E> {
+    pub fn new() -> Self {
+        Self {
+            parallel: false,
+            max_delta: None,
+            batch_completions: false,
+            conflicts: None,
+            dynamic: false,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Advances tweens by at most `max_delta` per frame, see [`TweenMaxDelta`].
+    pub fn max_delta(self, max_delta: Duration) -> Self {
+        Self {
+            max_delta: Some(max_delta),
+            ..self
+        }
+    }
+
+    /// Resolves tweens writing the same target, see [`TweenConflicts`].
+    pub fn conflicts(self, conflicts: TweenConflicts) -> Self {
+        Self {
+            conflicts: Some(conflicts),
+            ..self
+        }
+    }
+
+    /// Collects the completed tweens into a single [`TweenCompletedBatch`] event per frame.
+    pub fn batch_completions(self) -> Self {
+        Self {
+            batch_completions: true,
+            ..self
+        }
+    }
+
+    /// Use [`play_tween_animation_par`] instead of [`play_tween_animation`], for lots of tweens.
+    pub fn parallel(self) -> Self {
+        Self {
+            parallel: true,
+            ..self
+        }
+    }
+
+    /// Plays [`PlayTweenDynamic`]s with [`play_tween_dynamic`]. It's an exclusive system, so it's
+    /// not added unless requested.
+    pub fn dynamic(self) -> Self {
+        Self {
+            dynamic: true,
+            ..self
+        }
+    }
+}
+
+impl<E> Default for DefaultTweenPlugin<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Event + Clone> Plugin for DefaultTweenPlugin<E> {
+    fn build(&self, app: &mut App) {
+        macro_rules! systems {
+            ($play:ident) => {
+                (
+                    (
+                        snapshot_tween_targets::<Transform, E, ()>,
+                        snapshot_tween_targets::<Transform, E, Real>,
+                        snapshot_tween_targets::<Sprite, E, ()>,
+                        snapshot_tween_targets::<Sprite, E, Real>,
+                        snapshot_tween_targets::<BackgroundColor, E, ()>,
+                        snapshot_tween_targets::<BackgroundColor, E, Real>,
+                    ),
+                    (
+                        ui_transition::<TransitionBackgroundColor, E>,
+                        ui_transition::<TransitionScale, E>,
+                        $play::<Transform, E, ()>,
+                        $play::<Transform, E, Real>,
+                        $play::<Sprite, E, ()>,
+                        $play::<Sprite, E, Real>,
+                        $play::<BackgroundColor, E, ()>,
+                        $play::<BackgroundColor, E, Real>,
+                        $play::<MorphWeights, E, ()>,
+                        $play::<MorphWeights, E, Real>,
+                        $play::<AudioSink, E, ()>,
+                        $play::<AudioSink, E, Real>,
+                        $play::<TweenBuffer<TweenTranslation>, E, ()>,
+                        $play::<TweenBuffer<TweenTranslation>, E, Real>,
+                        play_buffered_tween_animation::<Transform, TweenTranslation, E, ()>,
+                        play_buffered_tween_animation::<Transform, TweenTranslation, E, Real>,
+                    )
+                        .chain(),
+                    (
+                        $play::<GlobalTranslation, E, ()>,
+                        $play::<GlobalTranslation, E, Real>,
+                        $play::<Style, E, ()>,
+                        $play::<Style, E, Real>,
+                        apply_global_translation,
+                        play_resource_tween::<Time<Virtual>, E, Real>,
+                        play_resource_tween::<ClearColor, E, ()>,
+                        play_resource_tween::<ClearColor, E, Real>,
+                    )
+                        .chain(),
+                    (
+                        $play::<Camera, E, ()>,
+                        $play::<Camera, E, Real>,
+                        $play::<Skybox, E, ()>,
+                        $play::<Skybox, E, Real>,
+                        $play::<DepthOfFieldSettings, E, ()>,
+                        $play::<DepthOfFieldSettings, E, Real>,
+                        $play::<Handle<Image>, E, ()>,
+                        $play::<Handle<Image>, E, Real>,
+                        $play::<UiImage, E, ()>,
+                        $play::<UiImage, E, Real>,
+                    ),
+                    (
+                        play_tween_on_path::<Transform, E, ()>,
+                        play_tween_on_path::<Transform, E, Real>,
+                        play_tween_on_path::<Sprite, E, ()>,
+                        play_tween_on_path::<Sprite, E, Real>,
+                        play_tween_on_path::<BackgroundColor, E, ()>,
+                        play_tween_on_path::<BackgroundColor, E, Real>,
+                    ),
+                )
+                    .chain()
+            };
+        }
+
+        app.add_event::<E>();
+        app.add_event::<TweenStarted>();
+        app.add_event::<TweenEvent<E>>();
+        if let Some(max_delta) = self.max_delta {
+            app.insert_resource(TweenMaxDelta(max_delta));
+        }
+        if let Some(conflicts) = self.conflicts.clone() {
+            app.insert_resource(conflicts)
+                .add_systems(First, clear_tween_conflicts);
+        }
+        if self.batch_completions {
+            app.init_resource::<TweenCompletedBatch>()
+                .add_event::<TweenCompletedBatch>()
+                .add_systems(PostUpdate, send_tween_completed_batch);
+        }
+        app.add_event::<FormationCompleted>();
+        app.configure_sets(
+            PostUpdate,
+            AdditiveTweens.before(TransformSystem::TransformPropagate),
+        );
+        app.add_systems(
+            PostUpdate,
+            (
+                play_additive_tweens::<E, ()>,
+                play_additive_tweens::<E, Real>,
+            )
+                .in_set(AdditiveTweens),
+        );
+        app.add_systems(Update, complete_formations::<E>);
+        if self.dynamic {
+            app.add_systems(Update, play_tween_dynamic::<E>);
+        }
+        app.add_systems(
+            Update,
+            (
+                play_tween_def::<Transform, E, ()>,
+                play_tween_def::<Transform, E, Real>,
+                play_tween_def::<Sprite, E, ()>,
+                play_tween_def::<Sprite, E, Real>,
+                play_tween_def::<BackgroundColor, E, ()>,
+                play_tween_def::<BackgroundColor, E, Real>,
+            ),
+        );
+        if self.parallel {
+            app.add_systems(Update, systems!(play_tween_animation_par));
+        } else {
+            app.add_systems(Update, systems!(play_tween_animation));
+        }
+    }
+}
+
+impl<'w, E: Event + Clone> EventSender<E> for EventWriter<'w, E> {
+    fn send(&mut self, event: &E) {
+        EventWriter::send(self, event.clone());
+    }
+}
+
+pub fn play_buffered_tween_animation<
+    T: Component,
+    W: TweenApplier<T> + 'static + Clone,
+    E: Event + Clone,
+    I: Default + Send + Sync + 'static,
+>(
+    time: TweenTime<I>,
+    mut tweens_to_play: Query<(
+        Entity,
+        &mut PlayTween<Buffered<T, W>, E, I>,
+        &mut T,
+        Option<&mut TweenBuffer<W>>,
+        Option<&mut TweenGroups>,
+    )>,
+    mut outputs: TweenOutputs<E>,
+) {
+    for (entity, mut play, mut target, tween_buffer, groups) in tweens_to_play.iter_mut() {
+        let Some(mut tween_buffer) = tween_buffer else {
+            error!("Buffered PlayTween without Buffer component");
+            continue;
+        };
+        let mut buffered = Buffered::default();
+        let delta = time.delta(&play);
+        outputs.update::<_, T, _>(
+            entity,
+            &mut play,
+            &mut buffered,
+            delta,
+            groups,
+            Some(entity),
+        );
+        if let Some(value) = buffered.value {
+            tween_buffer.tween.apply(&mut target, value);
+        }
+    }
+}
+
+/// Plays buffered tweens on the `(T, TweenBuffer<W>)` target used before [`Buffered`], copying
+/// the target and the buffer each frame.
+#[deprecated(
+    note = "play buffered tweens on `Buffered<T, W>` with `play_buffered_tween_animation`"
+)]
+pub fn play_buffered_pair_tween_animation<
+    T: Component + Clone,
+    W: TweenApplier<T> + 'static + Clone,
+    E: Event + Clone,
+    I: Default + Send + Sync + 'static,
+>(
+    time: TweenTime<I>,
+    mut tweens_to_play: Query<(
+        Entity,
+        &mut PlayTween<(T, TweenBuffer<W>), E, I>,
+        &mut T,
+        &mut TweenBuffer<W>,
+        Option<&mut TweenGroups>,
+    )>,
+    mut outputs: TweenOutputs<E>,
+) {
+    for (entity, mut play, mut target, mut tween_buffer, groups) in tweens_to_play.iter_mut() {
+        let mut pair = (target.clone(), tween_buffer.clone());
+        let delta = time.delta(&play);
+        outputs.update::<_, T, _>(entity, &mut play, &mut pair, delta, groups, Some(entity));
+        (*target, *tween_buffer) = pair;
+    }
+}
+
+pub fn play_tween_animation<T: Component, E: Event + Clone, I: Default + Send + Sync + 'static>(
+    time: TweenTime<I>,
+    mut tweens_to_play: Query<(
+        Entity,
+        &mut PlayTween<T, E, I>,
+        &mut T,
+        Option<&mut TweenPlayState<T>>,
+        Option<&mut TweenGroups>,
+    )>,
+    mut outputs: TweenOutputs<E>,
+) {
+    for (entity, mut play, mut target, play_state, groups) in tweens_to_play.iter_mut() {
+        if play.target_path.is_some() || play.additive.is_some() {
+            continue;
+        }
+        let delta = time.delta(&play);
+        let changed = outputs.update::<_, T, _>(
+            entity,
+            &mut play,
+            target.bypass_change_detection(),
+            delta,
+            groups,
+            Some(entity),
+        );
+        if changed {
+            target.set_changed();
+        }
+        if let Some(mut play_state) = play_state {
+            play_state.update(&play.tween);
+        }
+    }
+}
+
+/// Like [`play_tween_animation`], but for tweens animating a descendant - see
+/// [`PlayTween::with_target_path`].
+pub fn play_tween_on_path<T: Component, E: Event + Clone, I: Default + Send + Sync + 'static>(
+    time: TweenTime<I>,
+    mut tweens_to_play: Query<(Entity, &mut PlayTween<T, E, I>, Option<&mut TweenGroups>)>,
+    hierarchy: Query<(Option<&Children>, Option<&Name>)>,
+    mut targets: Query<&mut T>,
+    mut outputs: TweenOutputs<E>,
+) {
+    for (entity, mut play, groups) in tweens_to_play.iter_mut() {
+        if play.additive.is_some() {
+            continue;
+        }
+        let Some(path) = play.target_path.as_mut() else {
+            continue;
+        };
+        let resolved = path
+            .resolved
+            .filter(|&target| targets.contains(target))
+            .or_else(|| find_descendant(entity, &path.names, &hierarchy));
+        path.resolved = resolved;
+        let Some((target_entity, mut target)) =
+            resolved.and_then(|target| Some((target, targets.get_mut(target).ok()?)))
+        else {
+            continue;
+        };
+        let delta = time.delta(&play);
+        let changed = outputs.update::<_, T, _>(
+            entity,
+            &mut play,
+            target.bypass_change_detection(),
+            delta,
+            groups,
+            Some(target_entity),
+        );
+        if changed {
+            target.set_changed();
+        }
+    }
+}
+
+/// Plays [`PlayTween::additive`] tweens, adding their output to the [`Transform`] as changed by
+/// everything else in the frame - see [`AdditiveTweens`].
+pub fn play_additive_tweens<E: Event + Clone, I: Default + Send + Sync + 'static>(
+    time: TweenTime<I>,
+    mut tweens_to_play: Query<(
+        Entity,
+        &mut PlayTween<Transform, E, I>,
+        &mut Transform,
+        Option<&mut TweenGroups>,
+    )>,
+    mut outputs: TweenOutputs<E>,
+) {
+    for (entity, mut play, mut target, groups) in tweens_to_play.iter_mut() {
+        let Some(mut additive) = play.additive.take() else {
+            continue;
+        };
+        if additive.written.as_ref() != Some(&*target) {
+            additive.base = *target;
+        }
+        let delta = time.delta(&play);
+        outputs.update::<_, Transform, _>(
+            entity,
+            &mut play,
+            &mut additive.delta,
+            delta,
+            groups,
+            None,
+        );
+        let (base, delta) = (additive.base, additive.delta);
+        let value = Transform {
+            translation: base.translation + delta.translation,
+            rotation: base.rotation * delta.rotation,
+            scale: base.scale * delta.scale,
+        };
+        target.set_if_neq(value);
+        additive.written = Some(value);
+        play.additive = Some(additive);
+    }
+}
+
+/// Follows the children named like the elements of `path`, starting at `root`.
+fn find_descendant(
+    root: Entity,
+    path: &[String],
+    hierarchy: &Query<(Option<&Children>, Option<&Name>)>,
+) -> Option<Entity> {
+    path.iter().try_fold(root, |entity, name| {
+        let (children, _) = hierarchy.get(entity).ok()?;
+        children?.iter().copied().find(|&child| {
+            matches!(hierarchy.get(child), Ok((_, Some(child_name))) if child_name.as_str() == name)
+        })
+    })
+}
+
+/// Starting tweens with [`Commands`].
+pub trait TweenCommandsExt {
+    /// Adds a clone of `play` to every entity with the marker component `M`, replacing tweens of
+    /// the same type. The clones share the [`TweenId`].
+    fn tween_all<M: Component, T: Component + Clone, E, I>(&mut self, play: PlayTween<T, E, I>)
+    where
+        E: Clone + Send + Sync + 'static,
+        I: Clone + Send + Sync + 'static;
+}
+
+impl<'w, 's> TweenCommandsExt for Commands<'w, 's> {
+    fn tween_all<M: Component, T: Component + Clone, E, I>(&mut self, play: PlayTween<T, E, I>)
+    where
+        E: Clone + Send + Sync + 'static,
+        I: Clone + Send + Sync + 'static,
+    {
+        self.add(move |world: &mut World| {
+            let entities: Vec<_> = world
+                .query_filtered::<Entity, With<M>>()
+                .iter(world)
+                .collect();
+            let mut play = Some(play);
+            let count = entities.len();
+            let batch = entities.into_iter().enumerate().map(|(index, entity)| {
+                // The last entity gets the template itself, saving a clone
+                let play = if index + 1 == count {
+                    play.take()
+                } else {
+                    play.clone()
+                };
+                (entity, play.expect("template is taken last"))
+            });
+            if let Err(invalid) = world.insert_or_spawn_batch(batch.collect::<Vec<_>>()) {
+                warn!("Failed to start tweens on {invalid:?}");
+            }
+        });
+    }
+}
+
+/// Plays a [`BakedTween`] on all entities with the marker component `M`, see
+/// [`play_tween_crowd`]. Ie. for swarms playing the same idle animation.
+#[derive(Resource)]
+pub struct TweenCrowd<T, M> {
+    pub tween: BakedTween<T>,
+    /// Time played so far, wraps around at the end of the tween.
+    pub elapsed: Duration,
+    _marker: PhantomData<M>,
+}
+
+impl<T, M> TweenCrowd<T, M> {
+    pub fn new(tween: BakedTween<T>) -> Self {
+        Self {
+            tween,
+            elapsed: Duration::ZERO,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Offsets the time an entity of a [`TweenCrowd`] plays at, so it doesn't move in lockstep with the
+/// others.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct TweenPhase(pub Duration);
+
+/// Plays the [`TweenCrowd`] on all entities with the marker `M`, each at the crowd's time plus its
+/// [`TweenPhase`].
+pub fn play_tween_crowd<T: Component, M: Component, I: Default + Send + Sync + 'static>(
+    time: Res<Time<I>>,
+    crowd: Option<ResMut<TweenCrowd<T, M>>>,
+    mut targets: Query<(&mut T, Option<&TweenPhase>), With<M>>,
+) {
+    let Some(mut crowd) = crowd else {
+        return;
+    };
+    let duration = crowd.tween.duration();
+    let wrap = |position: Duration| {
+        if duration.is_zero() {
+            duration
+        } else {
+            Duration::from_nanos((position.as_nanos() % duration.as_nanos()) as u64)
+        }
+    };
+    crowd.elapsed = wrap(crowd.elapsed + time.delta());
+    let elapsed = crowd.elapsed;
+    for (mut target, phase) in &mut targets {
+        let position = wrap(elapsed + phase.map_or(Duration::ZERO, |phase| phase.0));
+        crowd.tween.apply_at(&mut target, position);
+    }
+}
+
+/// Counts the `G` events for tweens stepped by them, see [`PlayTween::step_on`].
+pub fn step_tweens_on<
+    G: Event,
+    T: Component,
+    E: Send + Sync + 'static,
+    I: Send + Sync + 'static,
+>(
+    mut events: EventReader<G>,
+    mut tweens: Query<&mut PlayTween<T, E, I>>,
+) {
+    let steps = events.read().count() as u32;
+    if steps == 0 {
+        return;
+    }
+    for mut play in tweens.iter_mut() {
+        if let Some(gate) = play
+            .gate
+            .as_mut()
+            .filter(|gate| gate.event == TypeId::of::<G>())
+        {
+            gate.pending += steps;
+        }
+    }
+}
+
+/// Sends the [`TweenCompletedBatch`] collected this frame, if anything completed.
+pub fn send_tween_completed_batch(
+    mut completed: ResMut<TweenCompletedBatch>,
+    mut events: EventWriter<TweenCompletedBatch>,
+) {
+    if !completed.completed.is_empty() {
+        events.send(std::mem::take(&mut completed));
+    }
+}
+
+/// Writes changed [`GlobalTranslation`]s to the local [`Transform`], using the [`GlobalTransform`]
+/// of the parent (as of the last transform propagation).
+pub fn apply_global_translation(
+    mut targets: Query<
+        (&GlobalTranslation, Option<&Parent>, &mut Transform),
+        Changed<GlobalTranslation>,
+    >,
+    parents: Query<&GlobalTransform>,
+) {
+    for (translation, parent, mut transform) in targets.iter_mut() {
+        let parent = parent
+            .and_then(|parent| parents.get(parent.get()).ok())
+            .copied()
+            .unwrap_or_default();
+        transform.translation = parent.affine().inverse().transform_point3(translation.0);
+    }
+}
+
+/// Draws the curve of `interpolator` into a `width` x `height` image, ie. to preview easings in
+/// tools. The curve is white on a transparent background, overshooting curves are scaled to fit.
+pub fn easing_preview(interpolator: &dyn Interpolator, width: u32, height: u32) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0; 4],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    let samples = interpolator.sample_n(width as usize);
+    let min = samples.iter().copied().fold(0.0, f32::min);
+    let max = samples.iter().copied().fold(1.0, f32::max);
+    let row = |value: f32| {
+        let top = (height.max(1) - 1) as f32;
+        (top - (value - min) / (max - min) * top).round() as u32
+    };
+    let mut last_row = None;
+    for (x, &value) in samples.iter().enumerate() {
+        let y = row(value);
+        let (from, to) = last_row.map_or((y, y), |last: u32| (last.min(y), last.max(y)));
+        for y in from..=to {
+            let pixel = (y * width + x as u32) as usize * 4;
+            image.data[pixel..pixel + 4].copy_from_slice(&[255; 4]);
+        }
+        last_row = Some(y);
+    }
+    image
+}
+
+/// Captures the [`Snapshot`]s of targets whose [`PlayTween`] was just added, and restores them
+/// for [cancelled](PlayTween::cancel) tweens. Must run before the tweens are played.
+pub fn snapshot_tween_targets<
+    T: Component + Clone,
+    E: Send + Sync + 'static,
+    I: Send + Sync + 'static,
+>(
+    mut snapshots: Query<(&mut Snapshot<T>, &mut T, &mut PlayTween<T, E, I>)>,
+) {
+    for (mut snapshot, mut target, mut play) in snapshots.iter_mut() {
+        if play.is_added() {
+            snapshot.capture(&target);
+        } else if play.halt == Some(Halt::Cancel)
+            && play.target_path.is_none()
+            && play.additive.is_none()
+            && snapshot.get().is_some()
+            && snapshot.restore(&mut target)
+        {
+            play.halt = Some(Halt::Restored);
+        }
+    }
+}
+
+/// Plays [`PlayTweenDef`]s, keeping one scratch copy for each [`TweenDef`] in use. Once done,
+/// the [`PlayTweenDef`] is removed or the entity despawned, if requested.
+pub fn play_tween_def<
+    T: Component + Clone,
+    E: Event + Clone,
+    I: Default + Send + Sync + 'static,
+>(
+    time: TweenTime<I>,
+    mut players: Query<(Entity, &mut PlayTweenDef<T, E, I>, &mut T)>,
+    mut scratches: Local<Vec<(TweenDef<T, E>, Tween<T, E>)>>,
+    mut event_writer: EventWriter<E>,
+    mut completed: Option<ResMut<TweenCompletedBatch>>,
+    mut commands: Commands,
+) {
+    scratches.retain(|(def, _)| def.is_shared());
+    for (entity, mut play, mut target) in players.iter_mut() {
+        if play.finished {
+            continue;
+        }
+        let play = &mut *play;
+        let index = match scratches.iter().position(|(def, _)| def.ptr_eq(&play.def)) {
+            Some(index) => index,
+            None => {
+                scratches.push((play.def.clone(), play.def.scratch()));
+                scratches.len() - 1
+            }
+        };
+        let result = play.def.advance(
+            &mut scratches[index].1,
+            &mut play.cursor,
+            target.bypass_change_detection(),
+            &mut event_writer,
+            time.delta_with_max(None),
+        );
+        let Ok((progress, changed)) = result else {
+            error!("PlayTweenDef with a cursor of another tween");
+            play.finished = true;
+            continue;
+        };
+        if changed {
+            target.set_changed();
+        }
+        if let TweenProgress::Done { .. } = progress {
+            play.finished = true;
+            if let Some(completed) = completed.as_mut() {
+                completed.completed.push((entity, play.id));
+            }
+            if play.remove {
+                commands.entity(entity).remove::<PlayTweenDef<T, E, I>>();
+            }
+            if play.despawn {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+/// Plays a [`PlayTween`] resource on the resource `T`. Once done, the [`PlayTween`] is removed if
+/// requested, despawning is ignored.
+pub fn play_resource_tween<T: Resource, E: Event + Clone, I: Default + Send + Sync + 'static>(
+    time: TweenTime<I>,
+    play: Option<ResMut<PlayTween<T, E, I>>>,
+    target: Option<ResMut<T>>,
+    mut event_writer: EventWriter<E>,
+    mut commands: Commands,
+) {
+    let (Some(mut play), Some(mut target)) = (play, target) else {
+        return;
+    };
+    let delta = time.delta(&play);
+    let updated = play.update(
+        target.bypass_change_detection(),
+        &mut event_writer,
+        delta,
+        None,
+        |_, _| Claim::Write,
+        std::any::type_name::<T>(),
+    );
+    if updated.changed {
+        target.set_changed();
+    }
+    if updated.remove {
+        commands.remove_resource::<PlayTween<T, E, I>>();
+    }
+}
+
+/// Like [`play_tween_animation`], but advances the tweens in parallel (if Bevy's `multi_threaded`
+/// feature is enabled). Completion events are sent in no particular order.
+pub fn play_tween_animation_par<
+    T: Component,
+    E: Event + Clone,
+    I: Default + Send + Sync + 'static,
+>(
+    time: TweenTime<I>,
+    mut tweens_to_play: Query<(
+        Entity,
+        &mut PlayTween<T, E, I>,
+        &mut T,
+        Option<&mut TweenPlayState<T>>,
+        Option<&mut TweenGroups>,
+    )>,
+    mut events: Local<
+        Parallel<(
+            Vec<(Entity, TweenId, E)>,
+            Vec<TweenStarted>,
+            Vec<(Entity, TweenId)>,
+        )>,
+    >,
+    mut event_writer: EventWriter<E>,
+    mut tagged_events: Option<ResMut<Events<TweenEvent<E>>>>,
+    (mut started_events, mut completed, conflicts): (
+        Option<ResMut<Events<TweenStarted>>>,
+        Option<ResMut<TweenCompletedBatch>>,
+        Option<ResMut<TweenConflicts>>,
+    ),
+    commands: ParallelCommands,
+) {
+    let conflicts = conflicts.map(std::sync::Mutex::new);
+    tweens_to_play
+        .par_iter_mut()
+        .for_each(|(entity, mut play, mut target, play_state, groups)| {
+            if play.target_path.is_some() || play.additive.is_some() {
+                return;
+            }
+            let delta = time.delta(&play);
+            let updated = events.scope(|(events, started, completed)| {
+                let mut events = TaggingSender {
+                    events,
+                    entity,
+                    id: play.id,
+                };
+                let updated = play.update(
+                    target.bypass_change_detection(),
+                    &mut events,
+                    delta,
+                    groups,
+                    |id, priority| match conflicts.as_ref() {
+                        Some(conflicts) => {
+                            conflicts.lock().unwrap().claim::<T>(entity, id, priority)
+                        }
+                        None => Claim::Write,
+                    },
+                    entity,
+                );
+                if updated.started {
+                    started.push(TweenStarted {
+                        entity,
+                        id: play.id,
+                    });
+                }
+                if updated.completed {
+                    completed.push((entity, play.id));
+                }
+                updated
+            });
+            if updated.changed {
+                target.set_changed();
+            }
+            if let Some(mut play_state) = play_state {
+                play_state.update(&play.tween);
+            }
+            if updated.groups.is_some() || updated.remove || updated.despawn {
+                commands.command_scope(|mut commands| {
+                    let mut entity = commands.entity(entity);
+                    if let Some(groups) = updated.groups {
+                        entity.insert(groups);
+                    }
+                    if updated.remove {
+                        entity.remove::<PlayTween<T, E, I>>();
+                    }
+                    if updated.despawn {
+                        entity.despawn();
+                    }
+                });
+            }
+        });
+    for (events, started, completions) in events.iter_mut() {
+        for (entity, id, event) in events.drain(..) {
+            if let Some(tagged_events) = tagged_events.as_mut() {
+                tagged_events.send(TweenEvent {
+                    entity,
+                    id,
+                    event: event.clone(),
+                });
+            }
+            event_writer.send(event);
+        }
+        if let Some(started_events) = started_events.as_mut() {
+            started_events.send_batch(started.drain(..));
+        } else {
+            started.clear();
+        }
+        if let Some(completed) = completed.as_mut() {
+            completed.completed.append(completions);
+        } else {
+            completions.clear();
+        }
+    }
+}
+
+impl TweenTo for Vec3 {
+    type Applier = TweenTranslation;
+
+    fn tween_to(self, end: Self) -> TweenTranslation {
+        TweenTranslation { start: self, end }
+    }
+}
+
+impl TweenTo for Quat {
+    type Applier = TweenRotation;
+
+    fn tween_to(self, end: Self) -> TweenRotation {
+        TweenRotation { start: self, end }
+    }
+}
+
+impl TweenTo for Color {
+    type Applier = TweenSpriteColor;
+
+    fn tween_to(self, end: Self) -> TweenSpriteColor {
+        TweenSpriteColor { start: self, end }
+    }
+}
+
+impl TweenTo for Volume {
+    type Applier = TweenVolume;
+
+    fn tween_to(self, end: Self) -> TweenVolume {
+        TweenVolume { start: self, end }
+    }
+}
+
+impl RandomValue for Vec2 {
+    fn random_between(min: &Self, max: &Self, rng: &mut TweenRng) -> Self {
+        Vec2::new(
+            f32::random_between(&min.x, &max.x, rng),
+            f32::random_between(&min.y, &max.y, rng),
+        )
+    }
+}
+
+impl RandomValue for Vec3 {
+    fn random_between(min: &Self, max: &Self, rng: &mut TweenRng) -> Self {
+        Vec3::new(
+            f32::random_between(&min.x, &max.x, rng),
+            f32::random_between(&min.y, &max.y, rng),
+            f32::random_between(&min.z, &max.z, rng),
+        )
+    }
+}
+
+impl RandomValue for Color {
+    fn random_between(min: &Self, max: &Self, rng: &mut TweenRng) -> Self {
+        (*min..*max).at(rng.next_f32())
+    }
+}
+
+impl RandomValue for TweenTranslation {
+    fn random_between(min: &Self, max: &Self, rng: &mut TweenRng) -> Self {
+        Self {
+            start: Vec3::random_between(&min.start, &max.start, rng),
+            end: Vec3::random_between(&min.end, &max.end, rng),
+        }
+    }
+}
+
+impl RandomValue for TweenScale {
+    fn random_between(min: &Self, max: &Self, rng: &mut TweenRng) -> Self {
+        Self {
+            start: Vec3::random_between(&min.start, &max.start, rng),
+            end: Vec3::random_between(&min.end, &max.end, rng),
+        }
+    }
+}
+
+impl RandomValue for TweenSpriteColor {
+    fn random_between(min: &Self, max: &Self, rng: &mut TweenRng) -> Self {
+        Self {
+            start: Color::random_between(&min.start, &max.start, rng),
+            end: Color::random_between(&min.end, &max.end, rng),
+        }
+    }
+}
+
+impl TweenApplier<Transform> for TweenTranslation {
+    fn apply(&mut self, target: &mut Transform, value: f32) {
+        target.translation = self.start.lerp(self.end, value);
+    }
+}
+
+impl TweenApplier<Transform> for TweenTranslationPerAxis {
+    fn apply(&mut self, target: &mut Transform, value: f32) {
+        target.translation = self.easing.lerp_vec3(self.start, self.end, value);
+    }
+}
+
+impl TweenApplier<Transform> for TweenRotation {
+    fn apply(&mut self, target: &mut Transform, value: f32) {
+        target.rotation = self.start.slerp(self.end, value);
+    }
+}
+
+impl TweenApplier<Transform> for TweenRotationZ {
+    fn apply(&mut self, target: &mut Transform, value: f32) {
+        let angle = self
+            .path
+            .lerp(self.start, self.end, value, std::f32::consts::TAU);
+        target.rotation = Quat::from_rotation_z(angle);
+    }
+}
+
+impl TweenApplier<Transform> for TweenRotateBy {
+    fn apply(&mut self, target: &mut Transform, value: f32) {
+        let start = *self.start.get_or_insert(target.rotation);
+        target.rotation = Quat::from_axis_angle(self.axis, self.angle * value) * start;
+    }
+
+    fn on_start(&mut self, target: &mut Transform) {
+        self.start = Some(target.rotation);
+    }
+}
+
+impl TweenHue {
+    fn hue_at(&self, value: f32) -> f32 {
+        self.path
+            .lerp(self.start, self.end, value, 360.0)
+            .rem_euclid(360.0)
+    }
+}
+
+impl TweenApplier<Sprite> for TweenHue {
+    fn apply(&mut self, target: &mut Sprite, value: f32) {
+        target.color.set_hue(self.hue_at(value));
+    }
+}
+
+impl TweenApplier<BackgroundColor> for TweenHue {
+    fn apply(&mut self, target: &mut BackgroundColor, value: f32) {
+        target.0.set_hue(self.hue_at(value));
+    }
+}
+
+impl TweenApplier<BackgroundColor> for TweenBackgroundColor {
+    fn apply(&mut self, target: &mut BackgroundColor, value: f32) {
+        target.0 = (self.start..self.end).at(value);
+    }
+}
+
+impl TweenApplier<ClearColor> for TweenClearColor {
+    fn apply(&mut self, target: &mut ClearColor, value: f32) {
+        target.0 = (self.start..self.end).at(value);
+    }
+}
+
+/// Please note this uses LCH color space and RGB
+impl TweenApplier<Sprite> for TweenSpriteColor {
+    fn apply(&mut self, target: &mut Sprite, value: f32) {
+        target.color = (self.start..self.end).at(value);
+    }
+}
+
+impl TweenApplier<Sprite> for TweenSpriteAlpha {
+    fn apply(&mut self, target: &mut Sprite, value: f32) {
+        target.color.set_alpha(self.start.lerp(self.end, value));
+    }
+}
+
+impl TweenApplier<GlobalTranslation> for TweenGlobalTranslation {
+    fn apply(&mut self, target: &mut GlobalTranslation, value: f32) {
+        target.0 = self.start.lerp(self.end, value);
+    }
+}
+
+impl TweenApplier<Sprite> for TweenAnchor {
+    fn apply(&mut self, target: &mut Sprite, value: f32) {
+        target.anchor = Anchor::Custom(self.start.lerp(self.end, value));
+    }
+}
+
+impl TweenApplier<Sprite> for TweenSpriteRect {
+    fn apply(&mut self, target: &mut Sprite, value: f32) {
+        target.rect = Some(Rect {
+            min: self.start.min.lerp(self.end.min, value),
+            max: self.start.max.lerp(self.end.max, value),
+        });
+    }
+}
+
+impl TweenApplier<Sprite> for TweenSpriteFlip {
+    fn apply(&mut self, target: &mut Sprite, value: f32) {
+        let reached = value >= self.at;
+        if let Some(flip_x) = self.flip_x {
+            target.flip_x = flip_x == reached;
+        }
+        if let Some(flip_y) = self.flip_y {
+            target.flip_y = flip_y == reached;
+        }
+    }
+}
+
+impl TweenApplier<Handle<Image>> for TweenImage {
+    fn apply(&mut self, target: &mut Handle<Image>, value: f32) {
+        if let Some(image) = self.frame(value).filter(|image| *image != target) {
+            *target = image.clone();
+        }
+    }
+}
+
+impl TweenApplier<UiImage> for TweenImage {
+    fn apply(&mut self, target: &mut UiImage, value: f32) {
+        self.apply(&mut target.texture, value);
+    }
+}
+
+impl TweenApplier<Camera> for TweenViewport {
+    fn apply(&mut self, target: &mut Camera, value: f32) {
+        let lerp = |start: UVec2, end: UVec2| start.as_vec2().lerp(end.as_vec2(), value).round();
+        let min = lerp(self.start.min, self.end.min).as_uvec2();
+        let max = lerp(self.start.max, self.end.max).as_uvec2();
+        let viewport = target.viewport.get_or_insert_with(Viewport::default);
+        viewport.physical_position = min;
+        viewport.physical_size = max.saturating_sub(min);
+    }
+}
+
+impl TweenApplier<Skybox> for TweenSkyboxBrightness {
+    fn apply(&mut self, target: &mut Skybox, value: f32) {
+        target.brightness = self.start.lerp(self.end, value);
+    }
+}
+
+impl TweenApplier<DepthOfFieldSettings> for TweenFocalDistance {
+    fn apply(&mut self, target: &mut DepthOfFieldSettings, value: f32) {
+        target.focal_distance = self.start.lerp(self.end, value);
+    }
+}
+
+impl TweenApplier<DepthOfFieldSettings> for TweenAperture {
+    fn apply(&mut self, target: &mut DepthOfFieldSettings, value: f32) {
+        target.aperture_f_stops = self.start.lerp(self.end, value);
+    }
+}
+
+impl TweenApplier<MorphWeights> for TweenMorphWeight {
+    fn apply(&mut self, target: &mut MorphWeights, value: f32) {
+        if let Some(weight) = target.weights_mut().get_mut(self.index) {
+            *weight = self.start.lerp(self.end, value);
+        }
+    }
+}
+
+impl TweenApplier<Transform> for TweenOffset {
+    fn apply(&mut self, target: &mut Transform, value: f32) {
+        let offset = self.offset * value;
+        target.translation += offset - self.applied;
+        self.applied = offset;
+    }
+
+    fn on_start(&mut self, _target: &mut Transform) {
+        self.applied = Vec3::ZERO;
+    }
+
+    fn on_loop(&mut self, _target: &mut Transform) {
+        self.applied = Vec3::ZERO;
+    }
+}
+
+impl TweenApplier<Transform> for TweenScale {
+    fn apply(&mut self, target: &mut Transform, value: f32) {
+        target.scale = self.start.lerp(self.end, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::world::CommandQueue;
+    use std::time::Duration;
+
+    #[derive(Event, Debug, Clone, PartialEq, Eq)]
+    struct TestEvent;
+
+    #[test]
+    fn test_transform_tween() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.insert_resource(Time::<Real>::default());
+        world.init_resource::<Events<NoEvent>>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let play_tween = PlayTween::new(Tween::new(
+            Duration::from_secs(2),
+            Lerp,
+            TweenTranslation {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            },
+        ));
+        let to_transform = world.spawn((Transform::default(), play_tween)).id();
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        let transform = world.get::<Transform>(to_transform).unwrap();
+        assert_eq!(transform.translation, Vec3::X * 0.5);
+    }
+
+    #[test]
+    fn test_pause_does_not_change_target() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        world.spawn((
+            Transform::default(),
+            PlayTween::new(Tween::<Transform, NoEvent>::pause(Duration::from_secs(2))),
+        ));
+        let moved = world
+            .spawn((
+                Transform::default(),
+                PlayTween::new(Tween::new(
+                    Duration::from_secs(2),
+                    Lerp,
+                    TweenTranslation {
+                        start: Vec3::ZERO,
+                        end: Vec3::X,
+                    },
+                )),
+            ))
+            .id();
+        world.clear_trackers();
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        let mut changed = world.query_filtered::<Entity, Changed<Transform>>();
+        assert_eq!(changed.iter(&world).collect::<Vec<_>>(), [moved]);
+    }
+
+    #[test]
+    fn test_tween_event() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(3));
+        world.insert_resource(time);
+        world.insert_resource(Time::<Real>::default());
+        world.init_resource::<Events<TestEvent>>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, TestEvent, ()>);
+        let play_tween = PlayTween::new(
+            Tween::<Transform, TestEvent>::pause(Duration::from_secs(2)).with_completed(TestEvent),
+        );
+        world.spawn((Transform::default(), play_tween));
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        let events = world.get_resource::<Events<TestEvent>>().unwrap();
+        let mut reader = events.get_reader();
+        assert_eq!(reader.read(events).count(), 1);
+    }
+
+    #[test]
+    fn test_parallel_tween_event() {
+        // GIVEN
+        bevy::tasks::ComputeTaskPool::get_or_init(default);
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(3));
+        world.insert_resource(time);
+        world.init_resource::<Events<TestEvent>>();
+        let play_tween_id =
+            world.register_system(play_tween_animation_par::<Transform, TestEvent, ()>);
+        for _ in 0..100 {
+            let play_tween = PlayTween::new(
+                Tween::<Transform, TestEvent>::pause(Duration::from_secs(2))
+                    .with_completed(TestEvent),
+            );
+            world.spawn((Transform::default(), play_tween.remove()));
+        }
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        let events = world.get_resource::<Events<TestEvent>>().unwrap();
+        let mut reader = events.get_reader();
+        assert_eq!(reader.read(events).count(), 100);
+        assert_eq!(
+            world
+                .query::<&PlayTween<Transform, TestEvent, ()>>()
+                .iter(&world)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_real_time() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(2));
+        world.insert_resource(time);
+        let mut time = Time::<Real>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.init_resource::<Events<TestEvent>>();
+        let play_tween_id_real =
+            world.register_system(play_tween_animation::<Transform, TestEvent, Real>);
+        let play_tween_id_virtual =
+            world.register_system(play_tween_animation::<Transform, TestEvent, ()>);
+        let play_tween = PlayTween::new_real_time(
+            Tween::<Transform, TestEvent>::pause(Duration::from_secs(2)).with_completed(TestEvent),
+        );
+        world.spawn((Transform::default(), play_tween));
+
+        // WHEN
+        world.run_system(play_tween_id_real).unwrap();
+        world.run_system(play_tween_id_virtual).unwrap();
+
+        // THEN
+        let events = world.get_resource::<Events<TestEvent>>().unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_tweening_tweens() {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(2));
+        world.insert_resource(time);
+        let mut time = Time::<Real>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let play_tween_id_real = world.register_system(
+            play_buffered_tween_animation::<Transform, TweenTranslation, NoEvent, Real>,
+        );
+        let play_tween_tween = world
+            .register_system(play_tween_animation::<TweenBuffer<TweenTranslation>, NoEvent, ()>);
+        let play_tween_tween_real = world
+            .register_system(play_tween_animation::<TweenBuffer<TweenTranslation>, NoEvent, Real>);
+
+        let real_time_tween = PlayTween::new_real_time(Tween::new(
+            Duration::from_secs(2),
+            Lerp,
+            Start(TweenTranslation {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            }),
+        ));
+        let virtual_time_tween = PlayTween::new(Tween::new(
+            Duration::from_secs(2),
+            Lerp,
+            End(TweenTranslation {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            }),
+        ));
+        let to_transform = world
+            .spawn((
+                Transform::default(),
+                PlayBufferedTweenBundle {
+                    play_tween: PlayTween::new_real_time(Tween::new(
+                        Duration::from_secs(2),
+                        Lerp,
+                        BufferApplier::new(),
+                    )),
+                    ..default()
+                },
+                real_time_tween,
+                virtual_time_tween,
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(play_tween_tween).unwrap();
+        world.run_system(play_tween_tween_real).unwrap();
+        world.run_system(play_tween_id_real).unwrap();
+
+        // THEN
+        let transform = world.get::<Transform>(to_transform).unwrap();
+        assert_eq!(transform.translation, Vec3::X * 0.75);
+        let tween_buffer = world
+            .get::<TweenBuffer<TweenTranslation>>(to_transform)
+            .unwrap();
+        assert_eq!(tween_buffer.tween.start, Vec3::X * 0.5);
+        assert_eq!(tween_buffer.tween.end, Vec3::X);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_buffered_pair_tweens() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let play_id = world.register_system(
+            play_buffered_tween_animation::<Transform, TweenTranslation, NoEvent, ()>,
+        );
+        let play_pair_id = world.register_system(
+            play_buffered_pair_tween_animation::<Transform, TweenTranslation, NoEvent, ()>,
+        );
+        let buffer = TweenTranslation {
+            start: Vec3::ZERO,
+            end: Vec3::X,
+        };
+        let buffered = world
+            .spawn((
+                Transform::default(),
+                PlayBufferedTweenBundle::new(
+                    PlayTween::new(Tween::new(
+                        Duration::from_secs(1),
+                        Lerp,
+                        BufferApplier::new(),
+                    )),
+                    buffer,
+                ),
+            ))
+            .id();
+        let pair = world
+            .spawn((
+                Transform::default(),
+                TweenBuffer::new(buffer),
+                PlayTween::<(Transform, TweenBuffer<TweenTranslation>), _, _>::new(Tween::new(
+                    Duration::from_secs(1),
+                    Lerp,
+                    BufferApplier::new(),
+                )),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(play_id).unwrap();
+        world.run_system(play_pair_id).unwrap();
+
+        // THEN
+        for entity in [buffered, pair] {
+            assert_eq!(
+                world.get::<Transform>(entity).unwrap().translation,
+                Vec3::X * 0.5
+            );
+        }
+    }
+
+    #[test]
+    fn test_global_translation() {
+        // GIVEN
+        let mut world = World::new();
+        let parent = world
+            .spawn(GlobalTransform::from(
+                Transform::from_xyz(10.0, 0.0, 0.0).with_scale(Vec3::splat(2.0)),
+            ))
+            .id();
+        let child = world
+            .spawn((Transform::default(), GlobalTranslation(Vec3::X * 14.0)))
+            .set_parent(parent)
+            .id();
+        let apply_id = world.register_system(apply_global_translation);
+
+        // WHEN
+        world.run_system(apply_id).unwrap();
+
+        // THEN
+        assert_eq!(
+            world.get::<Transform>(child).unwrap().translation,
+            Vec3::X * 2.0
+        );
+    }
+
+    #[test]
+    fn test_translation_per_axis() {
+        let mut tween = Tween::new(
+            Duration::from_secs(2),
+            Lerp,
+            TweenTranslationPerAxis {
+                start: Vec3::ZERO,
+                end: Vec3::ONE,
+                easing: AxisEasing::new(
+                    Easing::Linear,
+                    EaseFunction::QuadraticIn,
+                    Easing::QuadraticOut,
+                ),
+            },
+        );
+
+        let mut transform = Transform::default();
+        tween.advance(&mut transform, &mut NoEvent, Duration::from_secs(1));
+        assert_eq!(transform.translation, Vec3::new(0.5, 0.25, 0.75));
+    }
+
+    #[test]
+    fn test_tween_to() {
+        let mut tween = Tween::sequence([
+            Vec3::ZERO
+                .tween_to(Vec3::X)
+                .tween(Duration::from_secs(1), Lerp),
+            Quat::IDENTITY
+                .tween_to(Quat::from_rotation_z(1.0))
+                .tween(Duration::from_secs(1), Lerp),
+        ]);
+
+        let mut transform = Transform::default();
+        tween.advance(&mut transform, &mut NoEvent, Duration::from_millis(1500));
+        assert_eq!(transform.translation, Vec3::X);
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(0.5), 1e-6));
+    }
+
+    #[test]
+    fn test_arc_path() {
+        assert_eq!(ArcPath::Shortest.lerp(350.0, 10.0, 0.5, 360.0), 360.0);
+        assert_eq!(ArcPath::Longest.lerp(350.0, 10.0, 0.5, 360.0), 180.0);
+        assert_eq!(ArcPath::Shortest.lerp(10.0, 350.0, 0.25, 360.0), 5.0);
+
+        let mut sprite = Sprite {
+            color: Hsva::hsv(350.0, 1.0, 1.0).into(),
+            ..default()
+        };
+        let mut hue = TweenHue {
+            start: 350.0,
+            end: 30.0,
+            path: ArcPath::Shortest,
+        };
+        hue.apply(&mut sprite, 0.5);
+        let Color::Hsva(color) = sprite.color else {
+            panic!("Color space changed")
+        };
+        assert_eq!(color.hue, 10.0);
+
+        let mut transform = Transform::default();
+        TweenRotationZ {
+            start: 0.0,
+            end: std::f32::consts::FRAC_PI_2,
+            path: ArcPath::Longest,
+        }
+        .apply(&mut transform, 0.5);
+        assert!(transform.rotation.abs_diff_eq(
+            Quat::from_rotation_z(-std::f32::consts::FRAC_PI_4 * 3.0),
+            1e-6
+        ));
+    }
+
+    #[test]
+    fn test_rotate_by() {
+        let mut tween = Tween::<_, NoEvent>::repeat(
+            RepeatTimes::N(2),
+            Tween::new(
+                Duration::from_secs(1),
+                Lerp,
+                TweenRotateBy::new(Vec3::Z, std::f32::consts::TAU * 1.25),
+            ),
+        );
+
+        let mut transform = Transform::default();
+        tween.advance(&mut transform, &mut NoEvent, Duration::from_millis(500));
+        let halfway = transform.rotation;
+        tween.advance(&mut transform, &mut NoEvent, Duration::from_millis(1500));
+
+        assert!(halfway.abs_diff_eq(
+            Quat::from_rotation_z(std::f32::consts::FRAC_PI_4 * 5.0),
+            1e-5
+        ));
+        assert!(transform
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(std::f32::consts::PI), 1e-5));
+    }
+
+    #[test]
+    fn test_easing_preview() {
+        let image = easing_preview(&Lerp, 3, 3);
+
+        let alpha = |x: usize, y: usize| image.data[(y * 3 + x) * 4 + 3];
+        assert_eq!(alpha(0, 2), 255);
+        assert_eq!(alpha(1, 1), 255);
+        assert_eq!(alpha(2, 0), 255);
+        assert_eq!(alpha(0, 0), 0);
+    }
+
+    #[test]
+    fn test_tween_play_state() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(3));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let entity = world
+            .spawn((
+                Transform::default(),
+                TweenPlayState::<Transform>::default(),
+                PlayTween::new(Tween::<Transform, NoEvent>::sequence([
+                    Tween::pause(Duration::from_secs(2)),
+                    Tween::pause(Duration::from_secs(2)),
+                ])),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        let play_state = world.get::<TweenPlayState<Transform>>(entity).unwrap();
+        assert_eq!(play_state.progress, 0.75);
+        assert_eq!(play_state.remaining, Duration::from_secs(1));
+        assert_eq!(play_state.step, 1);
+    }
+
+    #[test]
+    fn test_play_tween_def() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let play_tween_id = world.register_system(play_tween_def::<Transform, NoEvent, ()>);
+        let def = TweenDef::new(Tween::new(
+            Duration::from_secs(2),
+            Lerp,
+            TweenTranslation {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            },
+        ));
+        let first = world
+            .spawn((Transform::default(), PlayTweenDef::new(&def).remove()))
+            .id();
+        world.run_system(play_tween_id).unwrap();
+        let second = world
+            .spawn((Transform::default(), PlayTweenDef::new(&def).despawn()))
+            .id();
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        let translation = |entity| world.get::<Transform>(entity).unwrap().translation;
+        assert_eq!(translation(first), Vec3::X);
+        assert_eq!(translation(second), Vec3::X * 0.5);
+        assert!(world
+            .get::<PlayTweenDef<Transform, NoEvent, ()>>(first)
+            .is_none());
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        assert!(world.get_entity(second).is_none());
+    }
+
+    #[test]
+    fn test_finish_tween() {
+        // GIVEN
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.init_resource::<Events<TestEvent>>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, TestEvent, ()>);
+        let mut play = PlayTween::new(Tween::new_with_event(
+            Duration::from_secs(1),
+            Lerp,
+            TweenTranslation {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            },
+            TestEvent,
+        ))
+        .remove();
+        play.pause();
+        play.finish();
+        let entity = world.spawn((Transform::default(), play)).id();
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+        world.flush();
+
+        // THEN
+        assert_eq!(world.get::<Transform>(entity).unwrap().translation, Vec3::X);
+        assert_eq!(world.resource::<Events<TestEvent>>().len(), 1);
+        assert!(world
+            .get::<PlayTween<Transform, TestEvent, ()>>(entity)
+            .is_none());
+    }
+
+    #[test]
+    fn test_stop_and_cancel() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let snapshot_id = world.register_system(snapshot_tween_targets::<Transform, NoEvent, ()>);
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let play = || {
+            PlayTween::new(Tween::new(
+                Duration::from_secs(1),
+                Lerp,
+                TweenTranslation {
+                    start: Vec3::ZERO,
+                    end: Vec3::X,
+                },
+            ))
+        };
+        let stopped = world.spawn((Transform::default(), play())).id();
+        let cancelled = world
+            .spawn((Transform::from_xyz(0.0, 1.0, 0.0), play()))
+            .id();
+        let restored = world
+            .spawn((
+                Transform::from_xyz(0.0, 1.0, 0.0),
+                Snapshot::<Transform>::new(),
+                play(),
+            ))
+            .id();
+        world.run_system(snapshot_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+
+        // WHEN
+        world
+            .get_mut::<PlayTween<Transform, NoEvent, ()>>(stopped)
+            .unwrap()
+            .stop();
+        for entity in [cancelled, restored] {
+            world
+                .get_mut::<PlayTween<Transform, NoEvent, ()>>(entity)
+                .unwrap()
+                .cancel();
+        }
+        world.run_system(snapshot_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+        world.flush();
+
+        // THEN
+        let translation = |entity| world.get::<Transform>(entity).unwrap().translation;
+        assert_eq!(translation(stopped), Vec3::X * 0.5);
+        assert_eq!(translation(cancelled), Vec3::ZERO);
+        assert_eq!(translation(restored), Vec3::Y);
+        assert!(world
+            .get::<PlayTween<Transform, NoEvent, ()>>(restored)
+            .is_none());
+        assert!(world
+            .get::<PlayTween<Transform, NoEvent, ()>>(stopped)
+            .is_none());
+        assert!(world
+            .get::<PlayTween<Transform, NoEvent, ()>>(cancelled)
+            .is_none());
+    }
+
+    #[test]
+    fn test_snapshot() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let snapshot_id = world.register_system(snapshot_tween_targets::<Transform, NoEvent, ()>);
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let start = Transform::from_xyz(1.0, 2.0, 3.0);
+        let entity = world
+            .spawn((
+                start,
+                Snapshot::<Transform>::new(),
+                PlayTween::new(Tween::new(
+                    Duration::from_secs(1),
+                    Lerp,
+                    TweenTranslation {
+                        start: Vec3::ZERO,
+                        end: Vec3::X,
+                    },
+                )),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(snapshot_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::X * 0.5
+        );
+        let snapshot = world.get::<Snapshot<Transform>>(entity).unwrap();
+        assert_eq!(snapshot.get(), Some(&start));
+
+        // WHEN
+        world
+            .get_mut::<PlayTween<Transform, NoEvent, ()>>(entity)
+            .unwrap()
+            .cancel();
+        world.run_system(snapshot_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        assert_eq!(*world.get::<Transform>(entity).unwrap(), start);
+    }
+
+    #[test]
+    fn test_replace_tween_keeps_progress() {
+        // GIVEN
+        let translation = |duration| {
+            Tween::new(
+                Duration::from_secs(duration),
+                Lerp,
+                TweenTranslation {
+                    start: Vec3::ZERO,
+                    end: Vec3::X,
+                },
+            )
+        };
+        let mut play = PlayTween::new(translation(2));
+        let mut transform = Transform::default();
+        play.play(&mut transform, &mut NoEvent, Duration::from_secs(1), ());
+
+        // WHEN
+        play.replace_tween(translation(4));
+        play.play(&mut transform, &mut NoEvent, Duration::from_secs(1), ());
+
+        // THEN
+        assert_eq!(transform.translation, Vec3::new(0.75, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_replace_tween_blended() {
+        // GIVEN
+        let translation = |start, end| {
+            Tween::new(
+                Duration::from_secs(4),
+                Lerp,
+                TweenTranslation { start, end },
+            )
+        };
+        let mut play = PlayTween::new(translation(Vec3::ZERO, Vec3::X * 4.0));
+        let mut transform = Transform::default();
+        play.play(&mut transform, &mut NoEvent, Duration::from_secs(1), ());
+
+        // WHEN
+        play.replace_tween_blended(translation(Vec3::Y, Vec3::Y), Duration::from_secs(2));
+        play.play(&mut transform, &mut NoEvent, Duration::from_secs(1), ());
+        let halfway = transform.translation;
+        play.play(&mut transform, &mut NoEvent, Duration::from_secs(1), ());
+
+        // THEN
+        assert_eq!(halfway, Vec3::new(1.0, 0.5, 0.0));
+        assert_eq!(transform.translation, Vec3::Y);
+    }
+
+    #[test]
+    fn test_substep() {
+        // GIVEN
+        #[derive(Clone)]
+        struct Accumulate;
+
+        impl TweenApplier<Transform> for Accumulate {
+            fn apply(&mut self, target: &mut Transform, value: f32) {
+                target.translation.x += value;
+            }
+        }
+
+        let tween = || Tween::new(Duration::from_secs(1), Lerp, Accumulate);
+        let mut play = PlayTween::new(tween()).with_substep(Duration::from_millis(250));
+        let mut transform = Transform::default();
+        let mut unstepped = Transform::default();
+
+        // WHEN
+        let played = play.play(
+            &mut transform,
+            &mut NoEvent,
+            Duration::from_millis(1500),
+            (),
+        );
+        PlayTween::new(tween()).play(
+            &mut unstepped,
+            &mut NoEvent,
+            Duration::from_millis(1500),
+            (),
+        );
+
+        // THEN
+        assert_eq!(transform.translation.x, 2.5);
+        assert_eq!(unstepped.translation.x, 1.0);
+        assert_eq!(
+            played.result,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(500)
+            }
+        );
+    }
+
+    #[test]
+    fn test_apply_error_policy() {
+        // GIVEN
+        #[derive(Clone)]
+        struct Fail;
+
+        impl TweenApplier<Transform> for Fail {
+            fn apply(&mut self, _target: &mut Transform, _value: f32) {}
+
+            fn try_apply(
+                &mut self,
+                _target: &mut Transform,
+                _value: f64,
+            ) -> Result<(), ApplyError> {
+                Err(ApplyError("missing asset".to_string()))
+            }
+        }
+
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(100));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let failing = || Tween::new(Duration::from_secs(1), Lerp, Fail);
+        let logged = world
+            .spawn((Transform::default(), PlayTween::new(failing())))
+            .id();
+        let stopped = world
+            .spawn((
+                Transform::default(),
+                PlayTween::new(failing()).with_error_policy(ApplyErrorPolicy::StopTween),
+            ))
+            .id();
+        let removed = world
+            .spawn((
+                Transform::default(),
+                PlayTween::new(failing()).with_error_policy(ApplyErrorPolicy::RemoveComponent),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        let play = |entity| world.get::<PlayTween<Transform, NoEvent, ()>>(entity);
+        assert!(!play(logged).unwrap().is_paused());
+        assert!(play(stopped).unwrap().is_paused());
+        assert!(play(removed).is_none());
+    }
+
+    #[test]
+    fn test_sprite_flip() {
+        let mut flip = TweenSpriteFlip {
+            flip_x: Some(true),
+            flip_y: None,
+            at: 0.5,
+        };
+        let mut sprite = Sprite {
+            flip_y: true,
+            ..default()
+        };
+
+        flip.apply(&mut sprite, 0.25);
+        assert!(!sprite.flip_x);
+        flip.apply(&mut sprite, 0.5);
+        assert!(sprite.flip_x);
+        assert!(sprite.flip_y);
+    }
+
+    #[test]
+    fn test_image() {
+        // GIVEN
+        let images: Vec<Handle<Image>> = (0..3).map(Handle::weak_from_u128).collect();
+        let mut tween = Tween::<UiImage, NoEvent>::new(
+            Duration::from_secs(3),
+            Lerp,
+            TweenImage::evenly(images.clone()),
+        );
+        let mut image = UiImage::default();
+        let mut shown = Vec::new();
+
+        // WHEN
+        for _ in 0..3 {
+            tween.advance(&mut image, &mut NoEvent, Duration::from_millis(1500));
+            shown.push(image.texture.clone());
+        }
+
+        // THEN
+        assert_eq!(
+            shown,
+            [images[1].clone(), images[2].clone(), images[2].clone()]
+        );
+    }
+
+    #[test]
+    fn test_viewport() {
+        let mut viewport = TweenViewport {
+            start: URect::new(0, 0, 800, 600),
+            end: URect::new(400, 300, 800, 600),
+        };
+        let mut camera = Camera::default();
+
+        viewport.apply(&mut camera, 0.5);
+
+        let viewport = camera.viewport.unwrap();
+        assert_eq!(viewport.physical_position, UVec2::new(200, 150));
+        assert_eq!(viewport.physical_size, UVec2::new(600, 450));
+    }
+
+    #[test]
+    fn test_max_delta() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(5));
+        world.insert_resource(time);
+        world.insert_resource(TweenMaxDelta(Duration::from_millis(100)));
+        world.init_resource::<Events<NoEvent>>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let translation = || {
+            Tween::new(
+                Duration::from_secs(1),
+                Lerp,
+                TweenTranslation {
+                    start: Vec3::ZERO,
+                    end: Vec3::X,
+                },
+            )
+        };
+        let clamped = world
+            .spawn((Transform::default(), PlayTween::new(translation())))
+            .id();
+        let overridden = world
+            .spawn((
+                Transform::default(),
+                PlayTween::new(translation()).with_max_delta(Duration::from_millis(500)),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        let translation = |entity| world.get::<Transform>(entity).unwrap().translation.x;
+        assert_eq!(translation(clamped), 0.1);
+        assert_eq!(translation(overridden), 0.5);
+    }
+
+    #[test]
+    fn test_exclusive_group() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let play_transform_id =
+            world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let play_sprite_id = world.register_system(play_tween_animation::<Sprite, NoEvent, ()>);
+        let entity = world
+            .spawn((
+                Transform::default(),
+                Sprite::default(),
+                PlayTween::new(Tween::new(
+                    Duration::from_secs(1),
+                    Lerp,
+                    TweenTranslation {
+                        start: Vec3::ZERO,
+                        end: Vec3::X,
+                    },
+                ))
+                .exclusive_group("movement"),
+            ))
+            .id();
+        world.run_system(play_transform_id).unwrap();
+
+        // WHEN
+        world.entity_mut(entity).insert(
+            PlayTween::new(Tween::new(
+                Duration::from_secs(1),
+                Lerp,
+                TweenSpriteColor {
+                    start: Color::WHITE,
+                    end: Color::BLACK,
+                },
+            ))
+            .exclusive_group("movement"),
+        );
+        world.run_system(play_sprite_id).unwrap();
+        world.run_system(play_transform_id).unwrap();
+        world.run_system(play_transform_id).unwrap();
+
+        // THEN
+        assert!(world
+            .get::<PlayTween<Transform, NoEvent, ()>>(entity)
+            .is_none());
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::X * 0.5
+        );
+        assert!(world
+            .get::<PlayTween<Sprite, NoEvent, ()>>(entity)
+            .is_some());
+    }
+
+    #[test]
+    fn test_conflicts() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        let mut time = Time::<Real>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        world.insert_resource(TweenConflicts::new(ConflictResolution::Priority));
+        let play_virtual_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let play_real_id = world.register_system(play_tween_animation::<Transform, NoEvent, Real>);
+        let translation = |end| {
+            Tween::new(
+                Duration::from_secs(1),
+                Lerp,
+                TweenTranslation {
+                    start: Vec3::ZERO,
+                    end,
+                },
+            )
+        };
+        let entity = world
+            .spawn((
+                Transform::default(),
+                PlayTween::new(translation(Vec3::X)).with_priority(1),
+                PlayTween::new_real_time(translation(Vec3::Y)),
+            ))
+            .id();
+
+        let mut paused = PlayTween::new(translation(Vec3::X)).with_priority(1);
+        paused.pause();
+        let yielding = world
+            .spawn((
+                Transform::default(),
+                paused,
+                PlayTween::new_real_time(translation(Vec3::Y)),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(play_virtual_id).unwrap();
+        world.run_system(play_real_id).unwrap();
+
+        // THEN
+        assert_eq!(world.get::<Transform>(entity).unwrap().translation, Vec3::X);
+        assert_eq!(
+            world.get::<Transform>(yielding).unwrap().translation,
+            Vec3::Y
+        );
+    }
+
+    #[test]
+    fn test_conflicts_reject() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        let mut time = Time::<Real>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        world.insert_resource(TweenConflicts::new(ConflictResolution::Reject));
+        let clear_id = world.register_system(clear_tween_conflicts);
+        let play_virtual_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let play_real_id = world.register_system(play_tween_animation::<Transform, NoEvent, Real>);
+        let translation = |end| {
+            Tween::new(
+                Duration::from_secs(1),
+                Lerp,
+                TweenTranslation {
+                    start: Vec3::ZERO,
+                    end,
+                },
+            )
+        };
+        let entity = world
+            .spawn((
+                Transform::default(),
+                PlayTween::new(translation(Vec3::X)),
+                PlayTween::new_real_time(translation(Vec3::Y)),
+            ))
+            .id();
+
+        // WHEN
+        for _ in 0..2 {
+            world.run_system(clear_id).unwrap();
+            world.run_system(play_virtual_id).unwrap();
+            world.run_system(play_real_id).unwrap();
+        }
+
+        // THEN
+        assert_eq!(world.get::<Transform>(entity).unwrap().translation, Vec3::X);
+        assert!(world
+            .get::<PlayTween<Transform, NoEvent, Real>>(entity)
+            .is_none());
+    }
+
+    #[test]
+    fn test_tween_all() {
+        // GIVEN
+        #[derive(Component)]
+        struct Marked;
+
+        let mut world = World::new();
+        let marked: Vec<_> = (0..3)
+            .map(|_| world.spawn((Transform::default(), Marked)).id())
+            .collect();
+        let unmarked = world.spawn(Transform::default()).id();
+        let mut queue = CommandQueue::default();
+
+        // WHEN
+        Commands::new(&mut queue, &world).tween_all::<Marked, _, _, _>(PlayTween::new(Tween::<
+            Transform,
+            NoEvent,
+        >::pause(
+            Duration::from_secs(1),
+        )));
+        queue.apply(&mut world);
+
+        // THEN
+        for entity in marked {
+            assert!(world
+                .get::<PlayTween<Transform, NoEvent, ()>>(entity)
+                .is_some());
+        }
+        assert!(world
+            .get::<PlayTween<Transform, NoEvent, ()>>(unmarked)
+            .is_none());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::time::Duration;
+    #[test]
+    fn test_tween_crowd() {
+        // GIVEN
+        #[derive(Component)]
+        struct Crowd;
 
-    #[derive(Event, Clone)]
-    struct TestEvent;
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        let tween = Tween::<Transform, NoEvent>::new(
+            Duration::from_secs(2),
+            Lerp,
+            TweenTranslation {
+                start: Vec3::ZERO,
+                end: Vec3::X * 2.0,
+            },
+        );
+        world.insert_resource(TweenCrowd::<Transform, Crowd>::new(
+            tween.bake(60.0).unwrap(),
+        ));
+        let play_crowd_id = world.register_system(play_tween_crowd::<Transform, Crowd, ()>);
+        let first = world.spawn((Transform::default(), Crowd)).id();
+        let shifted = world
+            .spawn((
+                Transform::default(),
+                Crowd,
+                TweenPhase(Duration::from_secs(1)),
+            ))
+            .id();
+        let outsider = world.spawn(Transform::default()).id();
+
+        // WHEN
+        world.run_system(play_crowd_id).unwrap();
+
+        // THEN
+        let translation = |entity| world.get::<Transform>(entity).unwrap().translation;
+        assert_eq!(translation(first), Vec3::X * 0.5);
+        assert_eq!(translation(shifted), Vec3::X * 1.5);
+        assert_eq!(translation(outsider), Vec3::ZERO);
+    }
 
     #[test]
-    fn test_transform_tween() {
+    fn test_step_on() {
         // GIVEN
+        #[derive(Event)]
+        struct Beat;
+
         let mut world = World::new();
         let mut time = Time::<()>::default();
         time.advance_by(Duration::from_secs(1));
         world.insert_resource(time);
-        world.insert_resource(Time::<Real>::default());
         world.init_resource::<Events<NoEvent>>();
+        world.init_resource::<Events<Beat>>();
+        let step_id = world.register_system(step_tweens_on::<Beat, Transform, NoEvent, ()>);
         let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
-        let play_tween = PlayTween::new(Tween::new(
+        let entity = world
+            .spawn((
+                Transform::default(),
+                PlayTween::new(Tween::new(
+                    Duration::from_secs(4),
+                    Lerp,
+                    TweenTranslation {
+                        start: Vec3::ZERO,
+                        end: Vec3::X * 4.0,
+                    },
+                ))
+                .step_on::<Beat>(Duration::from_millis(500)),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(step_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+        let without_beat = world.get::<Transform>(entity).unwrap().translation;
+        world.send_event_batch([Beat, Beat, Beat]);
+        world.run_system(step_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        assert_eq!(without_beat, Vec3::ZERO);
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::X * 1.5
+        );
+    }
+
+    #[test]
+    fn test_set_progress() {
+        // GIVEN
+        let mut play = PlayTween::new(Tween::new(
             Duration::from_secs(2),
             Lerp,
             TweenTranslation {
@@ -314,132 +3807,429 @@ mod tests {
                 end: Vec3::X,
             },
         ));
-        let to_transform = world.spawn((Transform::default(), play_tween)).id();
+        let mut transform = Transform::default();
+
+        // WHEN
+        play.set_progress(0.75);
+        play.play(&mut transform, &mut NoEvent, Duration::from_secs(1), ());
+        let driven = transform.translation;
+        play.set_progress(0.25);
+        play.play(&mut transform, &mut NoEvent, Duration::from_secs(1), ());
+        let rewound = transform.translation;
+        play.release_progress();
+        play.play(&mut transform, &mut NoEvent, Duration::from_secs(1), ());
+
+        // THEN
+        assert_eq!(driven, Vec3::X * 0.75);
+        assert_eq!(rewound, Vec3::X * 0.25);
+        assert_eq!(transform.translation, Vec3::X * 0.75);
+    }
+
+    #[test]
+    fn test_set_progress_completes() {
+        // GIVEN
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.init_resource::<Events<TestEvent>>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, TestEvent, ()>);
+        let mut play = PlayTween::new(Tween::new_with_event(
+            Duration::from_secs(2),
+            Lerp,
+            TweenTranslation {
+                start: Vec3::ZERO,
+                end: Vec3::X,
+            },
+            TestEvent,
+        ))
+        .remove();
+        play.set_progress(f32::NAN);
+        play.set_progress(0.5);
+        play.set_progress(f32::INFINITY);
+        let entity = world.spawn((Transform::default(), play)).id();
+        world.run_system(play_tween_id).unwrap();
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::X * 0.5
+        );
 
         // WHEN
+        world
+            .get_mut::<PlayTween<Transform, TestEvent, ()>>(entity)
+            .unwrap()
+            .set_progress(1.0);
         world.run_system(play_tween_id).unwrap();
+        world.flush();
 
         // THEN
-        let transform = world.get::<Transform>(to_transform).unwrap();
-        assert_eq!(transform.translation, Vec3::X * 0.5);
+        assert_eq!(world.get::<Transform>(entity).unwrap().translation, Vec3::X);
+        assert!(world
+            .get::<PlayTween<Transform, TestEvent, ()>>(entity)
+            .is_none());
+        assert_eq!(world.resource::<Events<TestEvent>>().len(), 1);
     }
 
     #[test]
-    fn test_tween_event() {
+    fn test_max_lifetime() {
         // GIVEN
         let mut world = World::new();
         let mut time = Time::<()>::default();
-        time.advance_by(Duration::from_secs(3));
+        time.advance_by(Duration::from_millis(100));
         world.insert_resource(time);
-        world.insert_resource(Time::<Real>::default());
         world.init_resource::<Events<TestEvent>>();
         let play_tween_id = world.register_system(play_tween_animation::<Transform, TestEvent, ()>);
-        let play_tween = PlayTween::new(
-            Tween::<Transform, TestEvent>::pause(Duration::from_secs(2)).with_completed(TestEvent),
-        );
-        world.spawn((Transform::default(), play_tween));
+        let step = TweenTranslation {
+            start: Vec3::ZERO,
+            end: Vec3::X,
+        };
+        let finite = world
+            .spawn((
+                Transform::default(),
+                PlayTween::new(Tween::once(
+                    Duration::from_secs(3600),
+                    Lerp,
+                    step,
+                    None::<TestEvent>,
+                ))
+                .with_max_lifetime(Duration::ZERO)
+                .remove(),
+            ))
+            .id();
+        let endless = |lifetime| {
+            PlayTween::new(
+                Tween::repeat(
+                    RepeatTimes::Infinite,
+                    Tween::once(Duration::from_secs(1), Lerp, step, None),
+                )
+                .with_completed(TestEvent),
+            )
+            .with_max_lifetime(lifetime)
+        };
+        let expired = world
+            .spawn((Transform::default(), endless(Duration::ZERO)))
+            .id();
+        let expiring = world
+            .spawn((
+                Transform::default(),
+                endless(Duration::from_millis(50)).remove(),
+            ))
+            .id();
 
         // WHEN
         world.run_system(play_tween_id).unwrap();
+        world.flush();
+        let events_before = world.resource::<Events<TestEvent>>().len();
+        std::thread::sleep(Duration::from_millis(60));
+        world.run_system(play_tween_id).unwrap();
+        world.flush();
 
         // THEN
-        let events = world.get_resource::<Events<TestEvent>>().unwrap();
-        let mut reader = events.get_reader();
-        assert_eq!(reader.read(events).count(), 1);
+        assert_eq!(world.get::<Transform>(finite).unwrap().translation, Vec3::X);
+        assert!(world
+            .get::<PlayTween<Transform, TestEvent, ()>>(finite)
+            .is_none());
+        assert!(world
+            .get::<PlayTween<Transform, TestEvent, ()>>(expired)
+            .unwrap()
+            .is_paused());
+        assert_eq!(
+            world.get::<Transform>(expired).unwrap().translation,
+            Vec3::ZERO
+        );
+        assert!(world
+            .get::<PlayTween<Transform, TestEvent, ()>>(expiring)
+            .is_none());
+        assert!(world
+            .get::<Transform>(expiring)
+            .unwrap()
+            .translation
+            .abs_diff_eq(Vec3::X * 0.1, 1e-6));
+        assert_eq!(events_before, 1);
+        assert_eq!(world.resource::<Events<TestEvent>>().len(), 2);
     }
 
     #[test]
-    fn test_real_time() {
+    fn test_additive() {
         // GIVEN
         let mut world = World::new();
         let mut time = Time::<()>::default();
-        time.advance_by(Duration::from_secs(2));
-        world.insert_resource(time);
-        let mut time = Time::<Real>::default();
         time.advance_by(Duration::from_secs(1));
         world.insert_resource(time);
-        world.init_resource::<Events<TestEvent>>();
-        let play_tween_id_real =
-            world.register_system(play_tween_animation::<Transform, TestEvent, Real>);
-        let play_tween_id_virtual =
-            world.register_system(play_tween_animation::<Transform, TestEvent, ()>);
-        let play_tween = PlayTween::new_real_time(
-            Tween::<Transform, TestEvent>::pause(Duration::from_secs(2)).with_completed(TestEvent),
-        );
-        world.spawn((Transform::default(), play_tween));
+        world.init_resource::<Events<NoEvent>>();
+        let play_tween_id = world.register_system(play_additive_tweens::<NoEvent, ()>);
+        let entity = world
+            .spawn((
+                Transform::from_xyz(0.0, 5.0, 0.0),
+                PlayTween::new(Tween::new(
+                    Duration::from_secs(1),
+                    Lerp,
+                    TweenTranslation {
+                        start: Vec3::ZERO,
+                        end: Vec3::X,
+                    },
+                ))
+                .additive(),
+            ))
+            .id();
 
         // WHEN
-        world.run_system(play_tween_id_real).unwrap();
-        world.run_system(play_tween_id_virtual).unwrap();
+        world.run_system(play_tween_id).unwrap();
+        let added = world.get::<Transform>(entity).unwrap().translation;
+        world.get_mut::<Transform>(entity).unwrap().translation = Vec3::Y * 7.0;
+        world.run_system(play_tween_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
 
         // THEN
-        let events = world.get_resource::<Events<TestEvent>>().unwrap();
-        assert!(events.is_empty());
+        assert_eq!(added, Vec3::new(1.0, 5.0, 0.0));
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::new(1.0, 7.0, 0.0)
+        );
     }
 
     #[test]
-    fn test_tweening_tweens() {
+    fn test_target_path() {
+        // GIVEN
         let mut world = World::new();
         let mut time = Time::<()>::default();
-        time.advance_by(Duration::from_secs(2));
+        time.advance_by(Duration::from_millis(500));
         world.insert_resource(time);
-        let mut time = Time::<Real>::default();
+        world.init_resource::<Events<NoEvent>>();
+        let play_tween_id = world.register_system(play_tween_on_path::<Transform, NoEvent, ()>);
+        let hand = world.spawn((Name::new("Hand"), Transform::default())).id();
+        let other_hand = world.spawn((Name::new("Hand"), Transform::default())).id();
+        let arm = world.spawn(Name::new("Arm")).push_children(&[hand]).id();
+        let other_arm = world
+            .spawn(Name::new("OtherArm"))
+            .push_children(&[other_hand])
+            .id();
+        world
+            .spawn((
+                Transform::default(),
+                PlayTween::new(Tween::new(
+                    Duration::from_secs(1),
+                    Lerp,
+                    TweenTranslation {
+                        start: Vec3::ZERO,
+                        end: Vec3::X,
+                    },
+                ))
+                .with_target_path(["Arm", "Hand"]),
+            ))
+            .push_children(&[other_arm, arm]);
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+        // Found once, the descendant doesn't need to keep its name
+        world.entity_mut(hand).remove::<Name>();
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        assert_eq!(world.get::<Transform>(hand).unwrap().translation, Vec3::X);
+        assert_eq!(
+            world.get::<Transform>(other_hand).unwrap().translation,
+            Vec3::ZERO
+        );
+    }
+
+    #[test]
+    fn test_batch_completions() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
         time.advance_by(Duration::from_secs(1));
         world.insert_resource(time);
-        world.insert_resource(time);
         world.init_resource::<Events<NoEvent>>();
-        let play_tween_id_real = world.register_system(
-            play_buffered_tween_animation::<Transform, TweenTranslation, NoEvent, Real>,
+        world.init_resource::<Events<TweenCompletedBatch>>();
+        world.init_resource::<TweenCompletedBatch>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let send_batch_id = world.register_system(send_tween_completed_batch);
+        let tween = |secs| {
+            PlayTween::new(Tween::new(
+                Duration::from_secs(secs),
+                Lerp,
+                TweenTranslation {
+                    start: Vec3::ZERO,
+                    end: Vec3::X,
+                },
+            ))
+        };
+        let first = world.spawn((Transform::default(), tween(1))).id();
+        let second = world.spawn((Transform::default(), tween(1))).id();
+        world.spawn((Transform::default(), tween(5)));
+
+        // WHEN
+        for _ in 0..2 {
+            world.run_system(play_tween_id).unwrap();
+            world.run_system(send_batch_id).unwrap();
+        }
+
+        // THEN
+        let events = world.resource::<Events<TweenCompletedBatch>>();
+        let batches: Vec<_> = events.get_reader().read(events).cloned().collect();
+        assert_eq!(batches.len(), 1);
+        let mut completed: Vec<_> = batches[0]
+            .completed
+            .iter()
+            .map(|(entity, _)| *entity)
+            .collect();
+        completed.sort();
+        assert_eq!(completed, [first, second]);
+    }
+
+    #[test]
+    fn test_relative_speed_overshoot() {
+        // GIVEN
+        let mut tween = Tween::<_, NoEvent>::new(
+            Duration::from_secs(1),
+            EaseFunction::BackOut,
+            TweenRelativeSpeed {
+                start: 1.0,
+                end: 0.0,
+            },
         );
-        let play_tween_tween = world
-            .register_system(play_tween_animation::<TweenBuffer<TweenTranslation>, NoEvent, ()>);
-        let play_tween_tween_real = world
-            .register_system(play_tween_animation::<TweenBuffer<TweenTranslation>, NoEvent, Real>);
+        let mut time = Time::<Virtual>::default();
 
-        let real_time_tween = PlayTween::new_real_time(Tween::new(
-            Duration::from_secs(2),
-            Lerp,
-            Start(TweenTranslation {
-                start: Vec3::ZERO,
-                end: Vec3::X,
-            }),
-        ));
-        let virtual_time_tween = PlayTween::new(Tween::new(
-            Duration::from_secs(2),
+        // WHEN
+        let mut slowest = f32::MAX;
+        for _ in 0..10 {
+            tween.advance(&mut time, &mut NoEvent, Duration::from_millis(100));
+            slowest = slowest.min(time.relative_speed());
+        }
+
+        // THEN
+        assert_eq!(slowest, 0.0);
+        assert_eq!(time.relative_speed(), 0.0);
+    }
+
+    #[test]
+    fn test_batch_completions_after_restart() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        world.init_resource::<TweenCompletedBatch>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let tween = PlayTween::new(Tween::new(
+            Duration::from_secs(1),
             Lerp,
-            End(TweenTranslation {
+            TweenTranslation {
                 start: Vec3::ZERO,
                 end: Vec3::X,
-            }),
+            },
         ));
-        let to_transform = world
+        let entity = world.spawn((Transform::default(), tween)).id();
+        world.run_system(play_tween_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+        let completions =
+            std::mem::take(&mut world.resource_mut::<TweenCompletedBatch>().completed);
+        assert_eq!(completions.len(), 1);
+
+        // WHEN
+        world
+            .get_mut::<PlayTween<Transform, NoEvent, ()>>(entity)
+            .unwrap()
+            .restart();
+        world.run_system(play_tween_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        let completions = &world.resource::<TweenCompletedBatch>().completed;
+        assert_eq!(completions.len(), 1);
+        assert_eq!(completions[0].0, entity);
+    }
+
+    #[test]
+    fn test_tween_started() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        world.init_resource::<Events<TweenStarted>>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let entity = world
             .spawn((
                 Transform::default(),
-                PlayBufferedTweenBundle {
-                    play_tween: PlayTween::new_real_time(Tween::new(
-                        Duration::from_secs(2),
+                PlayTween::new(Tween::sequence([
+                    Tween::pause(Duration::from_millis(1500)),
+                    Tween::new(
+                        Duration::from_secs(1),
                         Lerp,
-                        BufferApplier::new(),
-                    )),
-                    ..default()
-                },
-                real_time_tween,
-                virtual_time_tween,
+                        TweenTranslation {
+                            start: Vec3::ZERO,
+                            end: Vec3::X,
+                        },
+                    ),
+                ]))
+                .with_id(TweenId(7)),
             ))
             .id();
 
         // WHEN
-        world.run_system(play_tween_tween).unwrap();
-        world.run_system(play_tween_tween_real).unwrap();
-        world.run_system(play_tween_id_real).unwrap();
+        world.run_system(play_tween_id).unwrap();
+        let started_after_pause = world.resource::<Events<TweenStarted>>().len();
+        world.run_system(play_tween_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
 
         // THEN
-        let transform = world.get::<Transform>(to_transform).unwrap();
-        assert_eq!(transform.translation, Vec3::X * 0.75);
-        let tween_buffer = world
-            .get::<TweenBuffer<TweenTranslation>>(to_transform)
-            .unwrap();
-        assert_eq!(tween_buffer.tween.start, Vec3::X * 0.5);
-        assert_eq!(tween_buffer.tween.end, Vec3::X);
+        assert_eq!(started_after_pause, 0);
+        let mut reader = world.resource::<Events<TweenStarted>>().get_reader();
+        let started: Vec<_> = reader
+            .read(world.resource::<Events<TweenStarted>>())
+            .copied()
+            .collect();
+        assert_eq!(
+            started,
+            [TweenStarted {
+                entity,
+                id: TweenId(7)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_tween_event_ids() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        let mut time = Time::<Real>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.init_resource::<Events<TestEvent>>();
+        world.init_resource::<Events<TweenEvent<TestEvent>>>();
+        let play_virtual = world.register_system(play_tween_animation::<Transform, TestEvent, ()>);
+        let play_real = world.register_system(play_tween_animation::<Transform, TestEvent, Real>);
+        let completed =
+            || Tween::<Transform, TestEvent>::pause(Duration::ZERO).with_completed(TestEvent);
+        let virtual_tween = PlayTween::new(completed());
+        let real_tween = PlayTween::new_real_time(completed());
+        let ids = [virtual_tween.id(), real_tween.id()];
+        let entity = world
+            .spawn((Transform::default(), virtual_tween, real_tween))
+            .id();
+
+        // WHEN
+        world.run_system(play_virtual).unwrap();
+        world.run_system(play_real).unwrap();
+
+        // THEN
+        assert_ne!(ids[0], ids[1]);
+        let events = world.resource::<Events<TweenEvent<TestEvent>>>();
+        let mut reader = events.get_reader();
+        let events: Vec<_> = reader.read(events).cloned().collect();
+        assert_eq!(
+            events,
+            ids.map(|id| TweenEvent {
+                entity,
+                id,
+                event: TestEvent
+            })
+        );
+        assert_eq!(world.resource::<Events<TestEvent>>().len(), 2);
     }
 }
@@ -0,0 +1,197 @@
+#[cfg(feature = "bevy")]
+use crate::plugin::PlayTween;
+use crate::tween::Tween;
+#[cfg(feature = "bevy")]
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::hash::Hash;
+#[cfg(feature = "bevy")]
+use std::time::Duration;
+
+/// Recycles finished tweens, so frequently spawned effects (ie. hit flashes) don't have to build
+/// their tweens again each time. Tweens are kept per key, one for each kind of effect.
+///
+/// Put tweens back with [`TweenPool::recycle`], or mark their [`PlayTween`](crate::PlayTween)s
+/// with [`RecycleOnComplete`]. Only the tweens are reset, appliers keep their own state - so
+/// appliers relative to the target (like [`TweenOffset`](crate::TweenOffset)) have to capture it
+/// again in [`TweenApplier::on_start`](crate::TweenApplier::on_start).
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Resource))]
+pub struct TweenPool<T, E, K = &'static str> {
+    free: HashMap<K, Vec<Tween<T, E>>>,
+}
+
+impl<T, E, K> Default for TweenPool<T, E, K> {
+    fn default() -> Self {
+        Self {
+            free: HashMap::new(),
+        }
+    }
+}
+
+impl<T, E, K: Hash + Eq> TweenPool<T, E, K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A recycled tween for `key`, or a new one from `build` if there is none.
+    pub fn get(&mut self, key: K, build: impl FnOnce() -> Tween<T, E>) -> Tween<T, E> {
+        self.free
+            .get_mut(&key)
+            .and_then(Vec::pop)
+            .unwrap_or_else(build)
+    }
+
+    /// Resets the tween and keeps it for the next [`TweenPool::get`] with the same key. Nothing
+    /// calls this by itself, see [`RecycleOnComplete`] for doing so once a tween completed.
+    pub fn recycle(&mut self, key: K, mut tween: Tween<T, E>) {
+        tween.reset_deep();
+        self.free.entry(key).or_default().push(tween);
+    }
+
+    /// Number of tweens ready for reuse.
+    pub fn len(&self) -> usize {
+        self.free.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Puts the tween of a [`PlayTween`] into the [`TweenPool`] under `key` once it completed, see
+/// [`recycle_finished_tweens`]. The [`PlayTween`] is removed then, so don't remove or despawn
+/// it with [`PlayTween::remove`] or [`PlayTween::despawn`] - use [`RecycleOnComplete::despawn`]
+/// instead.
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Debug)]
+pub struct RecycleOnComplete<K = &'static str> {
+    pub key: K,
+    /// Despawn the entity after recycling the tween - instead of just removing the components.
+    pub despawn: bool,
+}
+
+#[cfg(feature = "bevy")]
+impl<K> RecycleOnComplete<K> {
+    pub fn new(key: K) -> Self {
+        Self {
+            key,
+            despawn: false,
+        }
+    }
+
+    pub fn despawn(self) -> Self {
+        Self {
+            despawn: true,
+            ..self
+        }
+    }
+}
+
+/// Recycles the tweens of completed [`PlayTween`]s marked with [`RecycleOnComplete`] into the
+/// [`TweenPool`], removing both components. Add it after the system playing the tweens.
+#[cfg(feature = "bevy")]
+pub fn recycle_finished_tweens<
+    T: Component,
+    E: Event,
+    I: Send + Sync + 'static,
+    K: Hash + Eq + Clone + Send + Sync + 'static,
+>(
+    mut finished: Query<(Entity, &mut PlayTween<T, E, I>, &RecycleOnComplete<K>)>,
+    mut pool: ResMut<TweenPool<T, E, K>>,
+    mut commands: Commands,
+) {
+    for (entity, mut play, recycle) in finished.iter_mut() {
+        if !play.is_finished() {
+            continue;
+        }
+        let tween = std::mem::replace(play.tween_mut(), Tween::pause(Duration::ZERO));
+        pool.recycle(recycle.key.clone(), tween);
+        if recycle.despawn {
+            commands.entity(entity).despawn();
+        } else {
+            commands
+                .entity(entity)
+                .remove::<(PlayTween<T, E, I>, RecycleOnComplete<K>)>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tween::{Lerp, NoEvent, SetValue};
+    use std::time::Duration;
+
+    #[test]
+    fn pool_recycles_tweens() {
+        let mut pool = TweenPool::<f32, NoEvent>::new();
+        let build = || {
+            Tween::sequence([
+                Tween::new(Duration::from_secs(1), Lerp, SetValue),
+                Tween::new(Duration::from_secs(1), Lerp, SetValue),
+            ])
+        };
+        let mut tween = pool.get("flash", build);
+        tween.advance(&mut 0.0, &mut NoEvent, Duration::from_secs(2));
+
+        pool.recycle("flash", tween);
+        assert_eq!(pool.len(), 1);
+
+        let tween = pool.get("flash", || unreachable!());
+        assert_eq!(tween.elapsed(), Some(Duration::ZERO));
+        assert!(pool.is_empty());
+    }
+
+    #[cfg(feature = "bevy")]
+    #[test]
+    fn test_recycle_on_complete() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        world.init_resource::<TweenPool<Transform, NoEvent>>();
+        let play_id =
+            world.register_system(crate::plugin::play_tween_animation::<Transform, NoEvent, ()>);
+        let recycle_id =
+            world.register_system(recycle_finished_tweens::<Transform, NoEvent, (), &'static str>);
+        let start = Transform::from_xyz(1.0, 2.0, 3.0);
+        let spawn = |world: &mut World| {
+            let tween = world
+                .resource_mut::<TweenPool<Transform, NoEvent>>()
+                .get("slide", || {
+                    crate::presets::slide_in_from(Vec3::X, Duration::from_secs(1))
+                });
+            world
+                .spawn((
+                    start,
+                    PlayTween::new(tween),
+                    RecycleOnComplete::new("slide"),
+                ))
+                .id()
+        };
+        let first = spawn(&mut world);
+
+        // WHEN
+        world.run_system(play_id).unwrap();
+        world.run_system(recycle_id).unwrap();
+        let second = spawn(&mut world);
+        world.run_system(play_id).unwrap();
+        world.run_system(recycle_id).unwrap();
+
+        // THEN
+        for entity in [first, second] {
+            assert!(world
+                .get::<PlayTween<Transform, NoEvent, ()>>(entity)
+                .is_none());
+            assert!(world
+                .get::<Transform>(entity)
+                .unwrap()
+                .translation
+                .abs_diff_eq(start.translation, 1e-6));
+        }
+        // The second effect reused the tween of the first one
+        assert_eq!(world.resource::<TweenPool<Transform, NoEvent>>().len(), 1);
+    }
+}
@@ -0,0 +1,298 @@
+//! Ready-made tweens for common effects.
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use be_tween::{presets, NoEvent, PlayTween};
+//! # use std::time::Duration;
+//! # fn system(mut commands: Commands) {
+//! commands.spawn((
+//!     SpriteBundle::default(),
+//!     PlayTween::new(presets::fade_in::<NoEvent>(Duration::from_millis(300))),
+//!     PlayTween::new(presets::slide_in_from::<NoEvent>(-Vec3::X * 100.0, Duration::from_millis(300))),
+//! ));
+//! # }
+//! ```
+use crate::easing::Easing;
+use crate::plugin::{
+    PlayTween, TweenClearColor, TweenOffset, TweenRelativeSpeed, TweenScale, TweenSpriteAlpha,
+};
+use crate::random::{value_noise, TweenRng};
+use crate::tween::{RepeatTimes, Tween, TweenApplier};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Shakes per second of [`shake`].
+const SHAKE_FREQUENCY: f32 = 20.0;
+
+/// Fades a sprite from transparent to opaque.
+pub fn fade_in<E>(duration: Duration) -> Tween<Sprite, E> {
+    Tween::once(
+        duration,
+        Easing::QuadraticOut,
+        TweenSpriteAlpha {
+            start: 0.0,
+            end: 1.0,
+        },
+        None,
+    )
+}
+
+/// Fades a sprite from opaque to transparent.
+pub fn fade_out<E>(duration: Duration) -> Tween<Sprite, E> {
+    Tween::once(
+        duration,
+        Easing::QuadraticIn,
+        TweenSpriteAlpha {
+            start: 1.0,
+            end: 0.0,
+        },
+        None,
+    )
+}
+
+/// Grows to `scale` and shrinks back to a scale of one.
+pub fn pulse<E>(scale: f32, duration: Duration) -> Tween<Transform, E> {
+    let half = duration / 2;
+    let pulsed = Vec3::splat(scale);
+    Tween::sequence([
+        Tween::once(
+            half,
+            Easing::SineInOut,
+            TweenScale {
+                start: Vec3::ONE,
+                end: pulsed,
+            },
+            None,
+        ),
+        Tween::once(
+            half,
+            Easing::SineInOut,
+            TweenScale {
+                start: pulsed,
+                end: Vec3::ONE,
+            },
+            None,
+        ),
+    ])
+}
+
+/// Jitters the translation by up to `strength`, calming down towards the end and returning to
+/// the original position.
+pub fn shake<E>(strength: f32, duration: Duration) -> Tween<Transform, E> {
+    Tween::once(
+        duration,
+        Easing::Linear,
+        Shake {
+            strength,
+            frequency: SHAKE_FREQUENCY * duration.as_secs_f32(),
+            seed: 0,
+            offset: TweenOffset::default(),
+        },
+        None,
+    )
+}
+
+/// Moves in from `offset` relative to the current translation.
+pub fn slide_in_from<E>(offset: Vec3, duration: Duration) -> Tween<Transform, E> {
+    Tween::sequence([
+        Tween::set(TweenOffset::new(offset)),
+        Tween::once(duration, Easing::CubicOut, TweenOffset::new(-offset), None),
+    ])
+}
+
+/// Jumps up by `height` and bounces back down.
+pub fn bounce<E>(height: f32, duration: Duration) -> Tween<Transform, E> {
+    let half = duration / 2;
+    Tween::sequence([
+        Tween::once(
+            half,
+            Easing::QuadraticOut,
+            TweenOffset::new(Vec3::Y * height),
+            None,
+        ),
+        Tween::once(
+            half,
+            Easing::BounceOut,
+            TweenOffset::new(-Vec3::Y * height),
+            None,
+        ),
+    ])
+}
+
+/// Slows the virtual clock down from normal speed to `to` (ie. `0.2` for a fifth of the speed).
+/// Insert it as a resource, it's played in [`Real`] time and removed once done.
+pub fn slow_motion<E>(to: f32, over: Duration) -> PlayTween<Time<Virtual>, E, Real> {
+    PlayTween::new_real_time(Tween::once(
+        over,
+        Easing::QuadraticOut,
+        TweenRelativeSpeed {
+            start: 1.0,
+            end: to,
+        },
+        None,
+    ))
+    .remove()
+}
+
+/// Sky colors of [`day_night`], starting at midnight.
+const DAY_NIGHT_COLORS: [Color; 4] = [
+    Color::srgb(0.02, 0.02, 0.08),
+    Color::srgb(0.9, 0.5, 0.3),
+    Color::srgb(0.5, 0.75, 1.0),
+    Color::srgb(0.8, 0.35, 0.25),
+];
+
+/// Cycles the [`ClearColor`] through night, dawn, day and dusk forever, starting at midnight.
+/// Insert it as a resource.
+///
+/// Only the sky is animated: lights and fog are part of `bevy_pbr`, which this crate doesn't
+/// depend on. Tween them with your own appliers, using the same day length to keep them in sync.
+pub fn day_night<E>(day_length: Duration) -> PlayTween<ClearColor, E, ()> {
+    let phases = DAY_NIGHT_COLORS.len();
+    PlayTween::new(Tween::repeat(
+        RepeatTimes::Infinite,
+        Tween::sequence(
+            (0..phases)
+                .map(|i| {
+                    Tween::once(
+                        day_length / phases as u32,
+                        Easing::SineInOut,
+                        TweenClearColor {
+                            start: DAY_NIGHT_COLORS[i],
+                            end: DAY_NIGHT_COLORS[(i + 1) % phases],
+                        },
+                        None,
+                    )
+                })
+                .collect::<Vec<_>>(),
+        ),
+    ))
+}
+
+#[derive(Clone)]
+struct Shake {
+    strength: f32,
+    frequency: f32,
+    seed: u64,
+    offset: TweenOffset,
+}
+
+impl TweenApplier<Transform> for Shake {
+    fn apply(&mut self, target: &mut Transform, value: f32) {
+        let position = value * self.frequency;
+        self.offset.offset = Vec3::new(
+            value_noise(self.seed, position),
+            value_noise(self.seed.wrapping_add(1), position),
+            0.0,
+        ) * self.strength;
+        self.offset.apply(target, 1.0 - value);
+    }
+
+    fn randomize(&mut self, rng: &mut TweenRng) {
+        self.seed = rng.next_u64();
+    }
+
+    fn on_start(&mut self, target: &mut Transform) {
+        self.offset.on_start(target);
+    }
+
+    fn on_loop(&mut self, target: &mut Transform) {
+        self.offset.on_loop(target);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::play_resource_tween;
+    use crate::tween::NoEvent;
+
+    fn play(mut tween: Tween<Transform, NoEvent>, target: &mut Transform, duration: Duration) {
+        tween.advance(target, &mut Vec::new(), duration);
+    }
+
+    #[test]
+    fn presets_return_to_rest() {
+        let second = Duration::from_secs(1);
+        let start = Transform::from_xyz(1.0, 2.0, 3.0);
+
+        let mut transform = start;
+        play(slide_in_from(Vec3::X, second), &mut transform, second);
+        assert_eq!(transform, start);
+
+        let mut transform = start;
+        play(bounce(1.0, second), &mut transform, second);
+        assert!(transform.translation.abs_diff_eq(start.translation, 1e-6));
+
+        let mut transform = start;
+        let mut tween = shake::<NoEvent>(1.0, second);
+        tween.advance(&mut transform, &mut Vec::new(), second / 3);
+        assert_ne!(transform, start);
+        tween.advance(&mut transform, &mut Vec::new(), second);
+        assert!(transform.translation.abs_diff_eq(start.translation, 1e-6));
+
+        let mut sprite = Sprite::default();
+        fade_out::<NoEvent>(second).advance(&mut sprite, &mut Vec::new(), second);
+        assert_eq!(sprite.color.alpha(), 0.0);
+    }
+
+    #[test]
+    fn repeated_presets_return_to_rest() {
+        let second = Duration::from_secs(1);
+        let start = Transform::from_xyz(1.0, 2.0, 3.0);
+
+        let mut transform = start;
+        let mut tween = Tween::repeat(RepeatTimes::N(3), bounce::<NoEvent>(1.0, second));
+        for _ in 0..3 {
+            tween.advance(&mut transform, &mut NoEvent, second);
+            assert!(transform.translation.abs_diff_eq(start.translation, 1e-6));
+        }
+
+        let mut transform = start;
+        let mut tween = slide_in_from::<NoEvent>(Vec3::X, second);
+        tween.advance(&mut transform, &mut NoEvent, second);
+        tween.reset();
+        tween.advance(&mut transform, &mut NoEvent, second);
+        assert!(transform.translation.abs_diff_eq(start.translation, 1e-6));
+    }
+
+    #[test]
+    fn test_slow_motion() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<Real>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.insert_resource(Time::<Virtual>::default());
+        world.init_resource::<Events<NoEvent>>();
+        world.insert_resource(slow_motion::<NoEvent>(0.5, Duration::from_secs(1)));
+        let play_tween_id =
+            world.register_system(play_resource_tween::<Time<Virtual>, NoEvent, Real>);
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        assert_eq!(world.resource::<Time<Virtual>>().relative_speed(), 0.5);
+        assert!(!world.contains_resource::<PlayTween<Time<Virtual>, NoEvent, Real>>());
+    }
+
+    #[test]
+    fn test_day_night() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(6));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        world.init_resource::<ClearColor>();
+        world.insert_resource(day_night::<NoEvent>(Duration::from_secs(24)));
+        let play_tween_id = world.register_system(play_resource_tween::<ClearColor, NoEvent, ()>);
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        assert_eq!(world.resource::<ClearColor>().0, DAY_NIGHT_COLORS[1]);
+    }
+}
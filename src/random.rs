@@ -0,0 +1,253 @@
+use crate::tween::{ApplyError, TweenApplier};
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::time::Duration;
+
+/// Small, seedable random number generator (SplitMix64) used by randomized tweens.
+///
+/// Cloning a generator copies its state, so a clone makes the same choices as the original.
+/// Seed cloned tweens differently (see [`Tween::seed`](crate::Tween::seed)) to vary them.
+#[derive(Debug, Clone)]
+pub struct TweenRng {
+    state: u64,
+}
+
+impl TweenRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn from_entropy() -> Self {
+        Self::new(RandomState::new().hash_one(0_u64))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed in `0.0..1.0`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1_u64 << 24) as f32
+    }
+
+    /// Picks an index with a probability proportional to its weight. Returns `None` if there are
+    /// no positive weights.
+    pub fn pick_weighted(&mut self, weights: &[f32]) -> Option<usize> {
+        let total: f32 = weights.iter().filter(|w| **w > 0.0).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut roll = self.next_f32() * total;
+        let mut last = None;
+        for (i, weight) in weights.iter().enumerate().filter(|(_, w)| **w > 0.0) {
+            if roll < *weight {
+                return Some(i);
+            }
+            roll -= weight;
+            last = Some(i);
+        }
+        // Rounding errors
+        last
+    }
+}
+
+impl Default for TweenRng {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+/// Smooth 1D value noise in `-1.0..=1.0`, with a new random value at each integer position.
+pub fn value_noise(seed: u64, position: f32) -> f32 {
+    let cell = position.floor();
+    let t = position - cell;
+    let lattice = |cell: f32| {
+        TweenRng::new(seed ^ (cell as i64 as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)).next_f32()
+            * 2.0
+            - 1.0
+    };
+    let smooth = t * t * (3.0 - 2.0 * t);
+    lattice(cell) + (lattice(cell + 1.0) - lattice(cell)) * smooth
+}
+
+/// Values which can be picked at random from a range.
+pub trait RandomValue: Clone {
+    fn random_between(min: &Self, max: &Self, rng: &mut TweenRng) -> Self;
+}
+
+/// A randomized parameter, which is rolled from `min..max` each time the tween using it starts.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Rand<V> {
+    pub min: V,
+    pub max: V,
+}
+
+/// An applier with randomized endpoints - the actual applier is rolled each time the tween
+/// starts.
+#[derive(Clone, Debug)]
+pub struct Randomized<A> {
+    pub range: Rand<A>,
+    applier: A,
+}
+
+impl<V: RandomValue> Rand<V> {
+    pub fn new(min: V, max: V) -> Self {
+        Self { min, max }
+    }
+
+    pub fn sample(&self, rng: &mut TweenRng) -> V {
+        V::random_between(&self.min, &self.max, rng)
+    }
+}
+
+impl<A: RandomValue> Randomized<A> {
+    pub fn new(min: A, max: A) -> Self {
+        Self {
+            applier: min.clone(),
+            range: Rand::new(min, max),
+        }
+    }
+}
+
+/// Perturbs the progress passed to the wrapped applier with smooth noise, ie. for a hand-held
+/// camera feel or flickering lights. A new noise pattern is rolled each time the tween starts.
+#[derive(Clone, Debug)]
+pub struct Wobble<A> {
+    pub applier: A,
+    /// Noise cycles over the whole progress of the tween.
+    pub frequency: f32,
+    /// Maximum deviation of the progress.
+    pub amplitude: f32,
+    seed: u64,
+}
+
+impl<A> Wobble<A> {
+    pub fn new(applier: A, frequency: f32, amplitude: f32) -> Self {
+        Self {
+            applier,
+            frequency,
+            amplitude,
+            seed: 0,
+        }
+    }
+}
+
+impl RandomValue for f32 {
+    fn random_between(min: &Self, max: &Self, rng: &mut TweenRng) -> Self {
+        min + (max - min) * rng.next_f32()
+    }
+}
+
+impl RandomValue for Duration {
+    fn random_between(min: &Self, max: &Self, rng: &mut TweenRng) -> Self {
+        Duration::from_secs_f32(f32::random_between(
+            &min.as_secs_f32(),
+            &max.as_secs_f32(),
+            rng,
+        ))
+    }
+}
+
+impl<T, A: TweenApplier<T> + RandomValue> TweenApplier<T> for Randomized<A> {
+    fn apply(&mut self, target: &mut T, value: f32) {
+        self.applier.apply(target, value);
+    }
+
+    fn apply_f64(&mut self, target: &mut T, value: f64) {
+        self.applier.apply_f64(target, value);
+    }
+
+    fn try_apply(&mut self, target: &mut T, value: f64) -> Result<(), ApplyError> {
+        self.applier.try_apply(target, value)
+    }
+
+    fn randomize(&mut self, rng: &mut TweenRng) {
+        self.applier = self.range.sample(rng);
+    }
+
+    fn on_start(&mut self, target: &mut T) {
+        self.applier.on_start(target);
+    }
+
+    fn on_complete(&mut self, target: &mut T) {
+        self.applier.on_complete(target);
+    }
+
+    fn on_loop(&mut self, target: &mut T) {
+        self.applier.on_loop(target);
+    }
+}
+
+impl<T, A: TweenApplier<T> + Clone> TweenApplier<T> for Wobble<A> {
+    fn apply(&mut self, target: &mut T, value: f32) {
+        self.apply_f64(target, value as f64);
+    }
+
+    fn apply_f64(&mut self, target: &mut T, value: f64) {
+        let noise = value_noise(self.seed, value as f32 * self.frequency);
+        self.applier
+            .apply_f64(target, value + (noise * self.amplitude) as f64);
+    }
+
+    fn try_apply(&mut self, target: &mut T, value: f64) -> Result<(), ApplyError> {
+        let noise = value_noise(self.seed, value as f32 * self.frequency);
+        self.applier
+            .try_apply(target, value + (noise * self.amplitude) as f64)
+    }
+
+    fn randomize(&mut self, rng: &mut TweenRng) {
+        self.seed = rng.next_u64();
+        self.applier.randomize(rng);
+    }
+
+    fn on_start(&mut self, target: &mut T) {
+        self.applier.on_start(target);
+    }
+
+    fn on_complete(&mut self, target: &mut T) {
+        self.applier.on_complete(target);
+    }
+
+    fn on_loop(&mut self, target: &mut T) {
+        self.applier.on_loop(target);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tween::SetValue;
+
+    #[test]
+    fn cloned_rng_repeats_choices() {
+        let mut rng = TweenRng::new(3);
+        rng.next_u64();
+        let mut clone = rng.clone();
+        assert_eq!(rng.next_u64(), clone.next_u64());
+
+        let range = Rand::new(1.0, 2.0);
+        for _ in 0..100 {
+            let value = range.sample(&mut rng);
+            assert!((1.0..2.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn wobble_stays_within_amplitude() {
+        let mut wobble = Wobble::new(SetValue, 8.0, 0.1);
+        wobble.randomize(&mut TweenRng::new(7));
+        let mut changed = false;
+        for i in 0..=100 {
+            let value = i as f32 / 100.0;
+            let mut target = 0.0;
+            wobble.apply(&mut target, value);
+            assert!((target - value).abs() <= 0.1);
+            changed |= target != value;
+        }
+        assert!(changed);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::tween::{Lerp, Tween, TweenApplier};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Values applied by a [`PlayTween`](crate::PlayTween) along with the time played when they were
+/// applied, see [`PlayTween::record`](crate::PlayTween::record). Ie. to compare runs when hunting
+/// nondeterminism, or for visual regression tests. Clones share the recording.
+pub struct TweenRecording<T> {
+    frames: Arc<Mutex<Vec<(Duration, T)>>>,
+}
+
+impl<T> Clone for TweenRecording<T> {
+    fn clone(&self) -> Self {
+        Self {
+            frames: self.frames.clone(),
+        }
+    }
+}
+
+impl<T> Default for TweenRecording<T> {
+    fn default() -> Self {
+        Self {
+            frames: Default::default(),
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> TweenRecording<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn frames(&self) -> Vec<(Duration, T)> {
+        self.frames.lock().unwrap().clone()
+    }
+
+    pub fn clear(&self) {
+        self.frames.lock().unwrap().clear();
+    }
+
+    /// A tween setting the recorded values again, at the times they were recorded.
+    pub fn replay<E>(&self) -> Tween<T, E> {
+        let frames: Arc<[(Duration, T)]> = self.frames().into();
+        let duration = frames.last().map_or(Duration::ZERO, |(at, _)| *at);
+        Tween::once(duration, Lerp, Replay { frames, duration }, None)
+    }
+}
+
+impl<T: Clone> TweenRecording<T> {
+    pub(crate) fn push(&self, at: Duration, value: &T) {
+        self.frames.lock().unwrap().push((at, value.clone()));
+    }
+}
+
+/// Applies the frames of a [`TweenRecording`].
+#[derive(Clone)]
+struct Replay<T> {
+    frames: Arc<[(Duration, T)]>,
+    duration: Duration,
+}
+
+impl<T: Clone + Send + Sync + 'static> TweenApplier<T> for Replay<T> {
+    fn apply(&mut self, target: &mut T, value: f32) {
+        let position = self.duration.mul_f32(value.clamp(0.0, 1.0));
+        let played = self.frames.partition_point(|(at, _)| *at <= position);
+        if let Some((_, frame)) = played.checked_sub(1).map(|index| &self.frames[index]) {
+            *target = frame.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::{play_tween_animation, PlayTween, TweenTranslation};
+    use crate::tween::NoEvent;
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_record_and_replay() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, ()>);
+        let recording = TweenRecording::new();
+        world.spawn((
+            Transform::default(),
+            PlayTween::new(Tween::new(
+                Duration::from_secs(1),
+                Lerp,
+                TweenTranslation {
+                    start: Vec3::ZERO,
+                    end: Vec3::X,
+                },
+            ))
+            .record(recording.clone()),
+        ));
+
+        // WHEN
+        world.run_system(play_tween_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+        let mut replay = recording.replay::<NoEvent>();
+        let mut transform = Transform::default();
+        replay.advance(&mut transform, &mut NoEvent, Duration::from_millis(600));
+
+        // THEN
+        let translations: Vec<_> = recording
+            .frames()
+            .into_iter()
+            .map(|(at, transform)| (at.as_millis(), transform.translation))
+            .collect();
+        assert_eq!(translations, [(500, Vec3::X * 0.5), (1000, Vec3::X)]);
+        assert_eq!(transform.translation, Vec3::X * 0.5);
+    }
+}
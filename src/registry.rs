@@ -0,0 +1,76 @@
+use crate::tween::Tween;
+use std::collections::HashMap;
+
+type TweenFactory<T, E> = Box<dyn Fn() -> Tween<T, E> + Send + Sync>;
+
+/// Named tween factories, to look up tweens at runtime - ie. from console commands or data driven
+/// triggers spawning `"boss_intro"`.
+#[cfg_attr(feature = "bevy", derive(bevy::prelude::Resource))]
+pub struct TweenRegistry<T, E> {
+    factories: HashMap<String, TweenFactory<T, E>>,
+}
+
+impl<T, E> Default for TweenRegistry<T, E> {
+    fn default() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+}
+
+impl<T, E> TweenRegistry<T, E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `factory` under `name`, replacing any factory registered before.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl Fn() -> Tween<T, E> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.factories.insert(name.into(), Box::new(factory));
+        self
+    }
+
+    pub fn unregister(&mut self, name: &str) -> bool {
+        self.factories.remove(name).is_some()
+    }
+
+    /// A new tween built by the factory registered under `name`.
+    pub fn get(&self, name: &str) -> Option<Tween<T, E>> {
+        self.factories.get(name).map(|factory| factory())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Names of all registered factories, in no particular order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.factories.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tween::NoEvent;
+    use std::time::Duration;
+
+    #[test]
+    fn registry_builds_tweens_by_name() {
+        let mut registry = TweenRegistry::<f32, NoEvent>::new();
+        registry
+            .register("short", || Tween::pause(Duration::from_secs(1)))
+            .register("long", || Tween::pause(Duration::from_secs(5)));
+
+        assert_eq!(
+            registry.get("long").and_then(|tween| tween.duration()),
+            Some(Duration::from_secs(5))
+        );
+        assert!(registry.get("boss_intro").is_none());
+        assert!(registry.unregister("short"));
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["long"]);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::plugin::PlayTween;
+use crate::tween::*;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Compact description of a tween, which can be sent over the network instead of the animated
+/// values.
+pub trait TweenDescriptor<T, E>: Send + Sync + 'static {
+    fn build(&self) -> Tween<T, E>;
+}
+
+/// The clock all peers agree on, which [`TweenReplica::start`] refers to - ie. the server time
+/// estimated by your networking crate. Local clocks like [`Time`] start at different moments on
+/// each peer, so keep this one up to date before [`play_replicated_tweens`] runs.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct ReplicationClock {
+    pub now: Duration,
+}
+
+/// A tween started at `start` (on the [`ReplicationClock`]), meant to be replicated (ie.
+/// registered with `bevy_replicon`). [`play_replicated_tweens`] builds the tween on each peer and
+/// catches up with the time passed since `start`, so only this component has to be sent - not
+/// each animated frame.
+#[derive(Component, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TweenReplica<D> {
+    pub descriptor: D,
+    pub start: Duration,
+}
+
+impl<D> TweenReplica<D> {
+    pub fn new(descriptor: D, start: Duration) -> Self {
+        Self { descriptor, start }
+    }
+}
+
+/// The [`TweenReplica`] a peer is playing, to tell real changes from repeated updates.
+#[derive(Component)]
+pub struct PlayedReplica<D>(TweenReplica<D>);
+
+/// Starts a [`PlayTween`] for each new [`TweenReplica`], and again whenever its descriptor or
+/// start changes. The time passed since the start is applied immediately, events for it are not
+/// sent (they were sent where the tween started).
+pub fn play_replicated_tweens<
+    D: TweenDescriptor<T, E> + Clone + PartialEq,
+    T: Component,
+    E: Send + Sync + 'static,
+    I: Default + Send + Sync + 'static,
+>(
+    clock: Res<ReplicationClock>,
+    mut replicas: Query<
+        (Entity, &TweenReplica<D>, Option<&PlayedReplica<D>>, &mut T),
+        Changed<TweenReplica<D>>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, replica, played, mut target) in replicas.iter_mut() {
+        if played.is_some_and(|played| played.0 == *replica) {
+            continue;
+        }
+        let mut tween = replica.descriptor.build();
+        let behind = clock.now.saturating_sub(replica.start);
+        tween.advance(&mut target, &mut NoEvent, behind);
+        commands.entity(entity).insert((
+            PlayTween::<T, E, I>::new_with_time(tween),
+            PlayedReplica(replica.clone()),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::TweenTranslation;
+
+    #[derive(Clone, PartialEq, Serialize, Deserialize)]
+    struct MoveTo(Vec3);
+
+    impl TweenDescriptor<Transform, NoEvent> for MoveTo {
+        fn build(&self) -> Tween<Transform, NoEvent> {
+            Tween::new(
+                Duration::from_secs(2),
+                Lerp,
+                TweenTranslation {
+                    start: Vec3::ZERO,
+                    end: self.0,
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn test_replicated_tween_catches_up() {
+        // GIVEN
+        let mut world = World::new();
+        world.insert_resource(ReplicationClock {
+            now: Duration::from_secs(3),
+        });
+        let play_replicas_id =
+            world.register_system(play_replicated_tweens::<MoveTo, Transform, NoEvent, ()>);
+        let entity = world
+            .spawn((
+                Transform::default(),
+                TweenReplica::new(MoveTo(Vec3::X), Duration::from_secs(2)),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(play_replicas_id).unwrap();
+
+        // THEN
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::X * 0.5
+        );
+        assert!(world
+            .get::<PlayTween<Transform, NoEvent, ()>>(entity)
+            .is_some());
+    }
+
+    #[test]
+    fn test_replicated_tween_rebuilt_on_change() {
+        // GIVEN
+        let mut world = World::new();
+        world.insert_resource(ReplicationClock {
+            now: Duration::from_secs(3),
+        });
+        let play_replicas_id =
+            world.register_system(play_replicated_tweens::<MoveTo, Transform, NoEvent, ()>);
+        let entity = world
+            .spawn((
+                Transform::default(),
+                TweenReplica::new(MoveTo(Vec3::X), Duration::from_secs(2)),
+            ))
+            .id();
+        world.run_system(play_replicas_id).unwrap();
+        world.resource_mut::<ReplicationClock>().now = Duration::from_millis(3500);
+
+        // WHEN the same replica is received again
+        world
+            .get_mut::<TweenReplica<MoveTo>>(entity)
+            .unwrap()
+            .set_changed();
+        world.run_system(play_replicas_id).unwrap();
+
+        // THEN
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::X * 0.5
+        );
+
+        // WHEN the descriptor changed
+        world
+            .get_mut::<TweenReplica<MoveTo>>(entity)
+            .unwrap()
+            .descriptor = MoveTo(Vec3::Y);
+        world.run_system(play_replicas_id).unwrap();
+
+        // THEN
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            Vec3::Y * 0.75
+        );
+    }
+}
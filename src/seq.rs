@@ -0,0 +1,127 @@
+use crate::tween::{EventSender, Tween, TweenProgress};
+use std::time::Duration;
+
+/// A tween which can be advanced without knowing its concrete type, ie. the children of a
+/// [`Seq`].
+pub trait StaticTween<T, E> {
+    fn advance<ES: EventSender<E>>(
+        &mut self,
+        target: &mut T,
+        event_sender: &mut ES,
+        duration: Duration,
+    ) -> TweenProgress;
+
+    fn duration(&self) -> Option<Duration>;
+}
+
+impl<T, E> StaticTween<T, E> for Tween<T, E> {
+    fn advance<ES: EventSender<E>>(
+        &mut self,
+        target: &mut T,
+        event_sender: &mut ES,
+        duration: Duration,
+    ) -> TweenProgress {
+        Tween::advance(self, target, event_sender, duration)
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        Tween::duration(self)
+    }
+}
+
+/// A sequence of a tuple of tweens, see [`Tween::seq`]. Its children are stored inline, for hot
+/// paths where the shape of the sequence is known at compile time.
+#[derive(Clone, Debug)]
+pub struct Seq<S> {
+    tweens: S,
+    index: usize,
+}
+
+impl<T, E> Tween<T, E> {
+    /// Plays a tuple of tweens (up to 8) one after the other, without allocating.
+    pub fn seq<S>(tweens: S) -> Seq<S>
+    where
+        Seq<S>: StaticTween<T, E>,
+    {
+        Seq { tweens, index: 0 }
+    }
+}
+
+macro_rules! impl_seq {
+    ($($index:tt $tween:ident),+) => {
+        impl<T, E, $($tween: StaticTween<T, E>),+> StaticTween<T, E> for Seq<($($tween,)+)> {
+            fn advance<ES: EventSender<E>>(
+                &mut self,
+                target: &mut T,
+                event_sender: &mut ES,
+                mut duration: Duration,
+            ) -> TweenProgress {
+                loop {
+                    let result = match self.index {
+                        $($index => self.tweens.$index.advance(target, event_sender, duration),)+
+                        _ => return TweenProgress::Done { surplus: duration },
+                    };
+                    let TweenProgress::Done { surplus } = result else {
+                        return result;
+                    };
+                    self.index += 1;
+                    duration = surplus;
+                }
+            }
+
+            fn duration(&self) -> Option<Duration> {
+                Some(Duration::ZERO $(+ self.tweens.$index.duration()?)+)
+            }
+        }
+    };
+}
+
+impl_seq!(0 A);
+impl_seq!(0 A, 1 B);
+impl_seq!(0 A, 1 B, 2 C);
+impl_seq!(0 A, 1 B, 2 C, 3 D);
+impl_seq!(0 A, 1 B, 2 C, 3 D, 4 F);
+impl_seq!(0 A, 1 B, 2 C, 3 D, 4 F, 5 G);
+impl_seq!(0 A, 1 B, 2 C, 3 D, 4 F, 5 G, 6 H);
+impl_seq!(0 A, 1 B, 2 C, 3 D, 4 F, 5 G, 6 H, 7 J);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tween::{Lerp, NoEvent, TweenApplier};
+
+    #[derive(Clone)]
+    struct Add(f32);
+
+    impl TweenApplier<f32> for Add {
+        fn apply(&mut self, target: &mut f32, value: f32) {
+            *target = self.0 + value;
+        }
+    }
+
+    #[test]
+    fn tuple_sequence() {
+        let second = Duration::from_secs(1);
+        let mut seq = Tween::seq((
+            Tween::new(second, Lerp, Add(0.0)),
+            Tween::seq((Tween::pause(second), Tween::new(second, Lerp, Add(1.0)))),
+        ));
+        assert_eq!(
+            StaticTween::<f32, NoEvent>::duration(&seq),
+            Some(second * 3)
+        );
+
+        let mut value = 0.0;
+        let result = seq.advance(&mut value, &mut NoEvent, second * 5 / 2);
+        assert_eq!(result, TweenProgress::Running);
+        assert_eq!(value, 1.5);
+        let result = seq.advance(&mut value, &mut NoEvent, second);
+        assert_eq!(
+            result,
+            TweenProgress::Done {
+                surplus: second / 2
+            }
+        );
+        assert_eq!(value, 2.0);
+    }
+}
@@ -0,0 +1,189 @@
+//! Helps testing tweens with Bevy, without setting up an `App`: [`TweenTestClock`] builds a
+//! minimal [`World`] and plays the tweens with controlled time steps.
+use crate::plugin::{play_tween_animation, TweenEvent, TweenStarted};
+use crate::tween::NoEvent;
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// A [`World`] with [`Time`] resources advanced by hand. Tweens are played by the systems added
+/// with [`TweenTestClock::play`], in the order they were added.
+pub struct TweenTestClock<E: Event = NoEvent> {
+    world: World,
+    systems: Vec<SystemId>,
+    events: Vec<E>,
+    started: Vec<TweenStarted>,
+    tween_events: Vec<TweenEvent<E>>,
+}
+
+impl<E: Event + Clone> Default for TweenTestClock<E> {
+    fn default() -> Self {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Time<Real>>();
+        world.init_resource::<Events<E>>();
+        world.init_resource::<Events<TweenStarted>>();
+        world.init_resource::<Events<TweenEvent<E>>>();
+        Self {
+            world,
+            systems: Vec::new(),
+            events: Vec::new(),
+            started: Vec::new(),
+            tween_events: Vec::new(),
+        }
+    }
+}
+
+impl<E: Event + Clone> TweenTestClock<E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Plays tweens on `T` components, in virtual and real time.
+    pub fn play<T: Component>(mut self) -> Self {
+        self.systems.extend([
+            self.world.register_system(play_tween_animation::<T, E, ()>),
+            self.world
+                .register_system(play_tween_animation::<T, E, Real>),
+        ]);
+        self
+    }
+
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    pub fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        self.world.spawn(bundle).id()
+    }
+
+    /// Time advanced so far.
+    pub fn elapsed(&self) -> Duration {
+        self.world.resource::<Time>().elapsed()
+    }
+
+    /// Advances time by `delta` and plays the tweens once.
+    pub fn advance(&mut self, delta: Duration) -> &mut Self {
+        self.world.resource_mut::<Time>().advance_by(delta);
+        self.world
+            .resource_mut::<Time<Real>>()
+            .update_with_duration(delta);
+        for &system in &self.systems {
+            self.world
+                .run_system(system)
+                .expect("Play systems are registered");
+        }
+        let world = &mut self.world;
+        self.events
+            .extend(world.resource_mut::<Events<E>>().drain());
+        self.started
+            .extend(world.resource_mut::<Events<TweenStarted>>().drain());
+        self.tween_events
+            .extend(world.resource_mut::<Events<TweenEvent<E>>>().drain());
+        self
+    }
+
+    /// Advances time to `elapsed` in one step, see [`TweenTestClock::advance`].
+    pub fn advance_to(&mut self, elapsed: Duration) -> &mut Self {
+        let delta = elapsed.saturating_sub(self.elapsed());
+        self.advance(delta)
+    }
+
+    /// The component `C` of `entity`, panics if there is none.
+    pub fn get<C: Component>(&self, entity: Entity) -> &C {
+        self.world
+            .get::<C>(entity)
+            .unwrap_or_else(|| panic!("{entity:?} has no {}", std::any::type_name::<C>()))
+    }
+
+    /// Advances time to `elapsed` and asserts the value of the component `C` of `entity`.
+    #[track_caller]
+    pub fn assert_at<C: Component + PartialEq + Debug>(
+        &mut self,
+        elapsed: Duration,
+        entity: Entity,
+        expected: C,
+    ) -> &mut Self {
+        self.advance_to(elapsed);
+        assert_eq!(
+            self.get::<C>(entity),
+            &expected,
+            "{entity:?} at {elapsed:?}"
+        );
+        self
+    }
+
+    /// Takes the events sent by the tweens so far.
+    pub fn take_events(&mut self) -> Vec<E> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Takes the [`TweenStarted`] events sent so far.
+    pub fn take_started(&mut self) -> Vec<TweenStarted> {
+        std::mem::take(&mut self.started)
+    }
+
+    /// Takes the events sent so far along with the entities they came from, see [`TweenEvent`].
+    pub fn take_tween_events(&mut self) -> Vec<TweenEvent<E>> {
+        std::mem::take(&mut self.tween_events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::{PlayTween, TweenTranslation};
+    use crate::tween::{Lerp, Tween};
+
+    #[derive(Event, Debug, Clone, PartialEq)]
+    struct Done;
+
+    #[test]
+    fn clock_plays_tweens() {
+        let mut clock = TweenTestClock::<Done>::new().play::<Transform>();
+        let entity = clock.spawn((
+            Transform::default(),
+            PlayTween::new(Tween::new_with_event(
+                Duration::from_secs(2),
+                Lerp,
+                TweenTranslation {
+                    start: Vec3::ZERO,
+                    end: Vec3::X,
+                },
+                Done,
+            )),
+        ));
+
+        clock
+            .assert_at(
+                Duration::from_secs(1),
+                entity,
+                Transform::from_xyz(0.5, 0.0, 0.0),
+            )
+            .assert_at(
+                Duration::from_secs(3),
+                entity,
+                Transform::from_xyz(1.0, 0.0, 0.0),
+            );
+        assert_eq!(clock.take_events(), [Done]);
+        let started = clock.take_started();
+        assert_eq!(started.len(), 1);
+        assert_eq!(started[0].entity, entity);
+        let tween_events = clock.take_tween_events();
+        assert_eq!(tween_events.len(), 1);
+        assert_eq!(tween_events[0].entity, entity);
+        assert_eq!(tween_events[0].event, Done);
+
+        clock.advance(Duration::from_secs(1));
+        assert!(clock.world().resource::<Events<TweenStarted>>().is_empty());
+        assert!(clock
+            .world()
+            .resource::<Events<TweenEvent<Done>>>()
+            .is_empty());
+    }
+}
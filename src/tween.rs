@@ -1,18 +1,101 @@
+use crate::easing::Easing;
+use crate::random::{Rand, TweenRng};
 use dyn_clone::DynClone;
-use interpolation::Ease;
+use interpolation::Ease as _;
 pub use interpolation::EaseFunction;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 pub trait TweenApplier<T>: Send + Sync + DynClone {
     fn apply(&mut self, target: &mut T, value: f32);
+
+    /// Like [`TweenApplier::apply`], but in full precision - ie. for large-world coordinates.
+    /// Rounds to `f32` by default.
+    fn apply_f64(&mut self, target: &mut T, value: f64) {
+        self.apply(target, value as f32);
+    }
+
+    /// Like [`TweenApplier::apply_f64`], but may fail - ie. if an asset or entity to apply to is
+    /// missing. Tweens always call this one, which never fails by default. Tweening goes on after
+    /// errors, [`PlayTween`](crate::PlayTween)s handle them according to their
+    /// [`ApplyErrorPolicy`](crate::ApplyErrorPolicy).
+    fn try_apply(&mut self, target: &mut T, value: f64) -> Result<(), ApplyError> {
+        self.apply_f64(target, value);
+        Ok(())
+    }
+
+    /// Called whenever the tween using this applier starts, to roll randomized parameters.
+    fn randomize(&mut self, _rng: &mut TweenRng) {}
+
+    /// Called when the tween using this applier starts, before the first value is applied - ie. to
+    /// capture the initial state of the target. Like the other hooks, only called when values
+    /// are applied (not when skipping).
+    fn on_start(&mut self, _target: &mut T) {}
+
+    /// Called when the tween using this applier completed, after the last value was applied.
+    fn on_complete(&mut self, _target: &mut T) {}
+
+    /// Called when a [`Tween::repeat`] containing this applier starts its next iteration.
+    fn on_loop(&mut self, _target: &mut T) {}
 }
 
 pub trait Interpolator: Send + Sync + 'static + DynClone {
     fn interpolate(&self, position: f32) -> f32;
+
+    /// Like [`Interpolator::interpolate`], but in full precision. Tweens always call this one,
+    /// which rounds to `f32` by default.
+    fn interpolate_f64(&self, position: f64) -> f64 {
+        self.interpolate(position as f32) as f64
+    }
+
+    /// Samples `n` evenly spaced positions from `0.0` to `1.0`, ie. to preview the curve.
+    fn sample_n(&self, n: usize) -> Vec<f32> {
+        let last = n.saturating_sub(1).max(1) as f32;
+        (0..n).map(|i| self.interpolate(i as f32 / last)).collect()
+    }
+
+    /// Converts into the storage used by tweens, only custom interpolators have to be boxed.
+    fn into_ease(self) -> Ease
+    where
+        Self: Sized,
+    {
+        Ease::Custom(Box::new(self))
+    }
+}
+
+/// Creates the applier tweening from `self` to another value, to be turned into a tween with
+/// [`TweenApplierExt::tween`]: `Vec3::ZERO.tween_to(Vec3::X).tween(duration, Lerp)`.
+pub trait TweenTo: Sized {
+    type Applier;
+
+    fn tween_to(self, end: Self) -> Self::Applier;
+}
+
+pub trait TweenApplierExt<T>: TweenApplier<T> + Sized + 'static {
+    /// A tween applying this applier once.
+    fn tween(self, duration: Duration, function: impl Interpolator + 'static) -> Tween<T, NoEvent> {
+        Tween::new(duration, function, self)
+    }
+}
+
+impl<T, A: TweenApplier<T> + 'static> TweenApplierExt<T> for A {}
+
+/// Decides which way a [`Tween::branch`] goes, by inspecting the target when the branch is reached.
+pub trait TweenPredicate<T>: Send + Sync + DynClone {
+    fn test(&self, target: &T) -> bool;
+}
+
+/// Produces the steps of a [`Tween::generate`], one at a time.
+pub trait TweenGenerator<T, E>: Send + Sync + DynClone {
+    /// The step with the given index, `None` ends the generated sequence.
+    fn generate(&mut self, index: usize) -> Option<Tween<T, E>>;
 }
 
 dyn_clone::clone_trait_object!(<T> TweenApplier<T>);
 dyn_clone::clone_trait_object!(Interpolator);
+dyn_clone::clone_trait_object!(<T> TweenPredicate<T>);
+dyn_clone::clone_trait_object!(<T, E> TweenGenerator<T, E>);
 
 pub trait EventSender<E> {
     fn send(&mut self, event: &E);
@@ -25,19 +108,67 @@ pub struct NoEvent;
 #[derive(Copy, Clone)]
 pub struct Lerp;
 
+/// Rounds the values passed to the wrapped applier to multiples of `1 / steps`, so tiny floating
+/// point differences (ie. of easing functions on other platforms) don't change the results. Useful
+/// for lockstep or networked games.
+#[derive(Clone, Debug)]
+pub struct Quantized<A> {
+    pub applier: A,
+    pub steps: u32,
+}
+
+impl<A> Quantized<A> {
+    pub fn new(applier: A, steps: u32) -> Self {
+        Self { applier, steps }
+    }
+}
+
+/// Animates an `f64` in full precision, ie. a long running counter.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TweenF64 {
+    pub start: f64,
+    pub end: f64,
+}
+
+impl TweenApplier<f64> for TweenF64 {
+    fn apply(&mut self, target: &mut f64, value: f32) {
+        self.apply_f64(target, value as f64);
+    }
+
+    fn apply_f64(&mut self, target: &mut f64, value: f64) {
+        *target = self.start + (self.end - self.start) * value;
+    }
+}
+
+/// The interpolator of a [`Tween`], stored inline for the built-in ones.
+#[derive(Clone)]
+pub enum Ease {
+    Lerp,
+    Function(EaseFunction),
+    Easing(Easing),
+    Custom(Box<dyn Interpolator>),
+}
+
 #[derive(Clone)]
 pub enum Tween<T, E> {
     Once {
         duration: Duration,
         elapsed: Duration,
-        function: Box<dyn Interpolator>,
+        function: Ease,
         applier: Box<dyn TweenApplier<T> + 'static>,
+        /// Whether the final value was applied.
+        finished: bool,
+        random_duration: Option<Box<Rand<Duration>>>,
+        rng: TweenRng,
         completed_event: Option<E>,
+        /// Events sent when reaching the given times, sorted by time.
+        markers: Vec<(Duration, E)>,
     },
     Repeat {
         tween: Box<Tween<T, E>>,
         times: RepeatTimes,
         count: usize,
+        decay: RepeatDecay,
         completed_event: Option<E>,
     },
     Sequence {
@@ -47,22 +178,76 @@ pub enum Tween<T, E> {
     },
     Parallel {
         tweens: Vec<Tween<T, E>>,
+        policy: ParallelPolicy,
+        elapsed: Duration,
         completed_event: Option<E>,
     },
     Pause {
         duration: Duration,
         elapsed: Duration,
+        random_duration: Option<Box<Rand<Duration>>>,
+        rng: TweenRng,
+        completed_event: Option<E>,
+    },
+    Branch {
+        predicate: Box<dyn TweenPredicate<T>>,
+        if_true: Box<Tween<T, E>>,
+        if_false: Box<Tween<T, E>>,
+        chosen: Option<bool>,
+        completed_event: Option<E>,
+    },
+    RepeatWhile {
+        predicate: Box<dyn TweenPredicate<T>>,
+        tween: Box<Tween<T, E>>,
+        /// Whether an iteration is currently running - the predicate is only checked between them.
+        iterating: bool,
+        count: usize,
+        completed_event: Option<E>,
+    },
+    Choose {
+        tweens: Vec<Tween<T, E>>,
+        weights: Vec<f32>,
+        chosen: Option<usize>,
+        rng: TweenRng,
+        completed_event: Option<E>,
+    },
+    Speed {
+        factor: TweenSpeed,
+        tween: Box<Tween<T, E>>,
+        completed_event: Option<E>,
+    },
+    Offset {
+        offset: Duration,
+        tween: Box<Tween<T, E>>,
+        /// Whether the offset was already skipped.
+        started: bool,
+        elapsed: Duration,
+        completed_event: Option<E>,
+    },
+    Reversed {
+        tween: Box<Tween<T, E>>,
+        /// Whether `tween` was already moved to its end.
+        started: bool,
+        completed_event: Option<E>,
+    },
+    Generate {
+        generator: Box<dyn TweenGenerator<T, E>>,
+        index: usize,
+        current: Option<Box<Tween<T, E>>>,
+        completed_event: Option<E>,
+    },
+    Alternate {
+        tweens: Vec<Tween<T, E>>,
+        times: RepeatTimes,
+        /// Completed iterations, the current one plays `tweens[count % tweens.len()]`.
+        count: usize,
         completed_event: Option<E>,
     },
 }
 
 impl<T, E> Default for Tween<T, E> {
     fn default() -> Self {
-        Self::Pause {
-            duration: Duration::ZERO,
-            elapsed: Duration::ZERO,
-            completed_event: None,
-        }
+        Self::pause(Duration::ZERO)
     }
 }
 
@@ -84,507 +269,4361 @@ impl Default for RepeatTimes {
     }
 }
 
-impl Interpolator for Lerp {
-    fn interpolate(&self, position: f32) -> f32 {
-        position
+/// When a [`Tween::parallel`] completes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParallelPolicy {
+    /// Once all tweens completed.
+    #[default]
+    WhenAll,
+    /// Once the first tween completed, the other tweens are stopped at that moment.
+    WhenAny,
+}
+
+/// Scales the duration of each iteration of a [`Tween::repeat`] relative to the previous one, ie.
+/// to speed up a heartbeat.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RepeatDecay {
+    /// Factor for the duration of the repeated tween.
+    pub duration: f32,
+}
+
+impl Default for RepeatDecay {
+    fn default() -> Self {
+        Self { duration: 1.0 }
     }
 }
 
-impl Interpolator for EaseFunction {
-    fn interpolate(&self, position: f32) -> f32 {
-        position.calc(*self)
+impl RepeatDecay {
+    /// Duration factor of the given iteration.
+    fn time_scale(&self, iteration: usize) -> f64 {
+        let iteration = iteration.min(i32::MAX as usize) as i32;
+        (self.duration as f64).powi(iteration)
+    }
+
+    /// Summed duration factors of the first `iterations` iterations.
+    fn iterations(&self, iterations: usize) -> f64 {
+        if self.duration == 1.0 {
+            iterations as f64
+        } else {
+            let factor = self.duration as f64;
+            (1.0 - factor.powi(iterations.min(i32::MAX as usize) as i32)) / (1.0 - factor)
+        }
     }
 }
 
-impl<E> EventSender<E> for NoEvent {
-    fn send(&mut self, _: &E) {}
+/// Time scale of a [`Tween::speed`]. Clones share the factor, so keeping a clone allows changing
+/// the speed of a running tween (ie. for slow motion).
+#[derive(Debug, Clone)]
+pub struct TweenSpeed(Arc<AtomicU32>);
+
+impl TweenSpeed {
+    pub fn new(factor: f32) -> Self {
+        Self(Arc::new(AtomicU32::new(factor.to_bits())))
+    }
+
+    pub fn get(&self) -> f32 {
+        f32::from_bits(self.0.load(Ordering::Relaxed))
+    }
+
+    pub fn set(&self, factor: f32) {
+        self.0.store(factor.to_bits(), Ordering::Relaxed);
+    }
 }
 
-impl<T> Tween<T, NoEvent> {
-    pub fn new(
+impl From<f32> for TweenSpeed {
+    fn from(factor: f32) -> Self {
+        Self::new(factor)
+    }
+}
+
+/// Runtime state of a [`Tween`] - elapsed times, positions in sequences, repeat counts and
+/// decisions - without its definition. Taken with [`Tween::state`] and restored onto an identically
+/// built tween with [`Tween::restore_state`], ie. to resume animations from a save game.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum TweenState {
+    Once {
         duration: Duration,
-        function: impl Interpolator + 'static,
-        applier: impl TweenApplier<T> + 'static,
-    ) -> Self {
-        Self::Once {
-            duration,
+        elapsed: Duration,
+        finished: bool,
+    },
+    Repeat {
+        count: usize,
+        tween: Box<TweenState>,
+    },
+    Sequence {
+        index: usize,
+        tweens: Vec<TweenState>,
+    },
+    Parallel {
+        elapsed: Duration,
+        tweens: Vec<TweenState>,
+    },
+    Pause {
+        duration: Duration,
+        elapsed: Duration,
+    },
+    Branch {
+        chosen: Option<bool>,
+        if_true: Box<TweenState>,
+        if_false: Box<TweenState>,
+    },
+    RepeatWhile {
+        iterating: bool,
+        count: usize,
+        tween: Box<TweenState>,
+    },
+    Choose {
+        chosen: Option<usize>,
+        tweens: Vec<TweenState>,
+    },
+    Speed {
+        factor: f32,
+        tween: Box<TweenState>,
+    },
+    Offset {
+        started: bool,
+        elapsed: Duration,
+        tween: Box<TweenState>,
+    },
+    Reversed {
+        started: bool,
+        tween: Box<TweenState>,
+    },
+    Generate {
+        index: usize,
+        current: Option<Box<TweenState>>,
+    },
+    Alternate {
+        count: usize,
+        tweens: Vec<TweenState>,
+    },
+}
+
+impl TweenState {
+    /// Cheap stand-in for the state of a child, until it is written.
+    fn placeholder() -> Self {
+        TweenState::Pause {
+            duration: Duration::ZERO,
             elapsed: Duration::ZERO,
-            function: Box::new(function),
-            applier: Box::new(applier),
-            completed_event: None,
         }
     }
+
+    /// The states of the direct children, in the order of [`Tween::children`].
+    fn children(&self) -> impl DoubleEndedIterator<Item = &TweenState> {
+        let (first, second, all): (Option<&TweenState>, Option<&TweenState>, &[TweenState]) =
+            match self {
+                TweenState::Once { .. }
+                | TweenState::Pause { .. }
+                | TweenState::Generate { current: None, .. } => (None, None, &[]),
+                TweenState::Repeat { tween, .. }
+                | TweenState::RepeatWhile { tween, .. }
+                | TweenState::Speed { tween, .. }
+                | TweenState::Offset { tween, .. }
+                | TweenState::Reversed { tween, .. }
+                | TweenState::Generate {
+                    current: Some(tween),
+                    ..
+                } => (Some(tween.as_ref()), None, &[]),
+                TweenState::Sequence { tweens, .. }
+                | TweenState::Parallel { tweens, .. }
+                | TweenState::Choose { tweens, .. }
+                | TweenState::Alternate { tweens, .. } => (None, None, tweens),
+                TweenState::Branch {
+                    if_true, if_false, ..
+                } => (Some(if_true.as_ref()), Some(if_false.as_ref()), &[]),
+            };
+        first.into_iter().chain(second).chain(all)
+    }
+
+    fn children_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut TweenState> {
+        let (first, second, all): (
+            Option<&mut TweenState>,
+            Option<&mut TweenState>,
+            &mut [TweenState],
+        ) = match self {
+            TweenState::Once { .. }
+            | TweenState::Pause { .. }
+            | TweenState::Generate { current: None, .. } => (None, None, &mut []),
+            TweenState::Repeat { tween, .. }
+            | TweenState::RepeatWhile { tween, .. }
+            | TweenState::Speed { tween, .. }
+            | TweenState::Offset { tween, .. }
+            | TweenState::Reversed { tween, .. }
+            | TweenState::Generate {
+                current: Some(tween),
+                ..
+            } => (Some(tween.as_mut()), None, &mut []),
+            TweenState::Sequence { tweens, .. }
+            | TweenState::Parallel { tweens, .. }
+            | TweenState::Choose { tweens, .. }
+            | TweenState::Alternate { tweens, .. } => (None, None, tweens),
+            TweenState::Branch {
+                if_true, if_false, ..
+            } => (Some(if_true.as_mut()), Some(if_false.as_mut()), &mut []),
+        };
+        first.into_iter().chain(second).chain(all)
+    }
 }
 
-impl<T, E> Tween<T, E> {
-    pub fn new_with_event(
-        duration: Duration,
-        function: impl Interpolator + 'static,
-        applier: impl TweenApplier<T> + 'static,
-        completed_event: E,
-    ) -> Self {
-        Self::Once {
-            duration,
-            elapsed: Duration::ZERO,
-            function: Box::new(function),
-            applier: Box::new(applier),
-            completed_event: Some(completed_event),
+/// A [`TweenState`] doesn't fit the structure of the tween it is restored onto.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TweenStateMismatch;
+
+impl std::fmt::Display for TweenStateMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("tween state doesn't match the tween")
+    }
+}
+
+impl std::error::Error for TweenStateMismatch {}
+
+/// A value could not be applied, see [`TweenApplier::try_apply`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyError(pub String);
+
+impl std::fmt::Display for ApplyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ApplyError {}
+
+/// Consecutive zero duration steps a [`Tween::generate`] may produce in one advance, before it is
+/// considered to be stuck in an infinite loop.
+const MAX_ZERO_DURATION_STEPS: usize = 1000;
+
+/// Which values a stepped tween applies to its target.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ApplyMode {
+    /// The values of all steps, see [`Tween::advance`].
+    All,
+    /// Only the final values of completed steps, see [`Tween::skip_applying`].
+    Completed,
+}
+
+/// The target of a stepped tween, remembering whether any value was applied to it.
+struct TargetRef<'a, T> {
+    target: &'a mut T,
+    mode: ApplyMode,
+    applied: bool,
+    /// The first error applying a value.
+    error: Option<ApplyError>,
+}
+
+impl<'a, T> TargetRef<'a, T> {
+    fn new(target: &'a mut T) -> Self {
+        Self::with_mode(target, ApplyMode::All)
+    }
+
+    fn with_mode(target: &'a mut T, mode: ApplyMode) -> Self {
+        Self {
+            target,
+            mode,
+            applied: false,
+            error: None,
         }
     }
 
-    pub fn pause(duration: Duration) -> Self {
-        Self::Pause {
-            duration,
-            elapsed: Duration::ZERO,
-            completed_event: None,
+    fn apply(&mut self, applier: &mut dyn TweenApplier<T>, value: f64) {
+        self.applied = true;
+        if let Err(error) = applier.try_apply(self.target, value) {
+            self.error.get_or_insert(error);
         }
     }
+}
 
-    pub fn repeat(times: RepeatTimes, tween: Tween<T, E>) -> Self {
-        Self::Repeat {
-            times,
-            count: 0,
-            tween: Box::new(tween),
-            completed_event: None,
+/// A tween moved out of its parent, while it is being stepped.
+struct StepFrame<T, E> {
+    tween: Tween<T, E>,
+    state: StepState,
+    duration: Duration,
+    silent: bool,
+    mode: StepMode,
+}
+
+/// Reusable buffer for stepping nested tweens, see [`Tween::advance_with`]. Keeping one next to a
+/// tween which is advanced every frame saves allocating it each time. Clones start out empty.
+pub struct TweenStack<T, E> {
+    frames: Vec<StepFrame<T, E>>,
+    /// Emptied surplus lists of finished races, see [`StepState::Race`].
+    surpluses: Vec<Vec<Option<Duration>>>,
+}
+
+impl<T, E> Default for TweenStack<T, E> {
+    fn default() -> Self {
+        Self {
+            frames: Vec::new(),
+            surpluses: Vec::new(),
         }
     }
+}
 
-    pub fn sequence(tweens: impl Into<Vec<Tween<T, E>>>) -> Self {
-        Self::Sequence {
-            index: 0,
-            tweens: tweens.into(),
-            completed_event: None,
+impl<T, E> Clone for TweenStack<T, E> {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+/// The tweens of a step in progress. If an applier panics, dropping it moves the children which
+/// are being stepped back into their parents, so the tree stays intact.
+struct StepStack<'a, T, E> {
+    root: &'a mut Tween<T, E>,
+    root_state: StepState,
+    frames: &'a mut Vec<StepFrame<T, E>>,
+}
+
+impl<T, E> StepStack<'_, T, E> {
+    /// Moves a stepped child back into the tween on top of the stack.
+    fn put_back(&mut self, tween: Tween<T, E>) {
+        let (parent, state) = match self.frames.last_mut() {
+            Some(parent) => (&mut parent.tween, &parent.state),
+            None => (&mut *self.root, &self.root_state),
+        };
+        *parent.step_child(state) = tween;
+    }
+}
+
+impl<T, E> Drop for StepStack<'_, T, E> {
+    fn drop(&mut self) {
+        while let Some(frame) = self.frames.pop() {
+            self.put_back(frame.tween);
         }
     }
+}
 
-    pub fn parallel(tweens: impl Into<Vec<Tween<T, E>>>) -> Self {
-        Self::Parallel {
-            tweens: tweens.into(),
-            completed_event: None,
+/// How a tween is stepped.
+#[derive(Clone, Copy)]
+enum StepMode {
+    /// Forward, see [`Tween::resume_step`].
+    Forward,
+    /// Backward, see [`Tween::resume_step_back`].
+    Back,
+    /// To the end, see [`Tween::resume_seek_end`].
+    End,
+}
+
+/// Where a tween continues, once the child it is waiting for was stepped.
+#[derive(Default)]
+enum StepState {
+    #[default]
+    Start,
+    Repeat {
+        time_scale: f64,
+    },
+    Parallel {
+        child: usize,
+        surplus: Option<Duration>,
+    },
+    Race {
+        child: usize,
+        surpluses: Vec<Option<Duration>>,
+    },
+    /// Moving the children of a race back to the moment the first one completed.
+    RaceBack {
+        child: usize,
+        surplus: Duration,
+        surpluses: Vec<Option<Duration>>,
+    },
+    /// Rewinding the children of a parallel tween. Once it reached its start, the children are
+    /// `settling` at theirs and `surplus` is returned.
+    ParallelBack {
+        child: usize,
+        rewound: Duration,
+        settling: Option<Duration>,
+    },
+    /// Moving the children of a parallel tween to their end, which is `None` once a child never
+    /// ends.
+    ParallelEnd {
+        child: usize,
+        end: Option<Duration>,
+    },
+    /// Moving the children of a race to the moment the first one completes.
+    RaceEnd {
+        child: usize,
+        end: Duration,
+    },
+    Speed {
+        factor: f64,
+    },
+    Offset {
+        skipping: bool,
+    },
+    /// Moving the child to its end, before stepping it backwards.
+    Seeking,
+    Generate {
+        zero_duration_steps: usize,
+    },
+    /// Iterations in a row which took no time.
+    Alternate {
+        zero_duration_steps: usize,
+    },
+}
+
+enum StepAction {
+    /// Step the child selected by [`Tween::step_child`] in the given mode, events are not sent if
+    /// `silent`.
+    Descend {
+        duration: Duration,
+        silent: bool,
+        mode: StepMode,
+    },
+    Return(TweenProgress),
+}
+
+impl StepAction {
+    /// Steps the child backwards by `duration`.
+    fn back(duration: Duration) -> Self {
+        Self::Descend {
+            duration,
+            silent: true,
+            mode: StepMode::Back,
         }
     }
 
-    pub fn with_completed(mut self, event: E) -> Self {
-        match &mut self {
-            Tween::Once {
-                completed_event, ..
-            }
-            | Tween::Repeat {
-                completed_event, ..
-            }
-            | Tween::Sequence {
-                completed_event, ..
-            }
-            | Tween::Parallel {
-                completed_event, ..
-            }
-            | Tween::Pause {
-                completed_event, ..
-            } => *completed_event = Some(event),
+    /// Moves the child to its end.
+    fn end() -> Self {
+        Self::Descend {
+            duration: Duration::ZERO,
+            silent: true,
+            mode: StepMode::End,
         }
-        self
     }
 
-    pub fn skip(&mut self, mut duration: Duration) -> TweenProgress {
-        match self {
-            Tween::Once {
-                duration: tween_duration,
-                elapsed,
-                ..
-            } => {
-                *elapsed += duration;
-                if elapsed >= tween_duration {
-                    let surplus = *elapsed - *tween_duration;
-                    *elapsed = *tween_duration;
-                    TweenProgress::Done { surplus }
+    /// Result of [`Tween::resume_seek_end`]: whether the tween ended.
+    fn ended(ended: bool) -> Self {
+        Self::Return(if ended {
+            TweenProgress::Done {
+                surplus: Duration::ZERO,
+            }
+        } else {
+            TweenProgress::Running
+        })
+    }
+}
+
+/// Timing of a tween, summarized from its children by [`Tween::timing`].
+#[derive(Clone, Copy)]
+struct Timing {
+    duration: Option<Duration>,
+    elapsed: Option<Duration>,
+    finished: bool,
+}
+
+/// Duration of the first `iterations` iterations of a [`Tween::alternate`], given the timing of
+/// its tweens.
+fn alternate_duration(tweens: &[Timing], iterations: usize) -> Option<Duration> {
+    if tweens.is_empty() {
+        return Some(Duration::ZERO);
+    }
+    let sum = |tweens: &[Timing]| {
+        tweens.iter().try_fold(Duration::ZERO, |acc, tween| {
+            acc.checked_add(tween.duration?)
+        })
+    };
+    let cycles = u32::try_from(iterations / tweens.len()).ok()?;
+    sum(tweens)?
+        .checked_mul(cycles)?
+        .checked_add(sum(&tweens[..iterations % tweens.len()])?)
+}
+
+/// Relative progress of a tween, a zero duration tween is always complete. Elapsed time is kept in
+/// whole nanoseconds, so replaying identical deltas yields bit-identical progress.
+fn progress(elapsed: Duration, duration: Duration) -> f64 {
+    if duration.is_zero() {
+        1.0
+    } else {
+        elapsed.as_nanos() as f64 / duration.as_nanos() as f64
+    }
+}
+
+/// Moves `elapsed` back by `duration`, stopping at zero.
+fn rewind_elapsed(elapsed: &mut Duration, duration: Duration) -> TweenProgress {
+    if *elapsed > duration {
+        *elapsed -= duration;
+        TweenProgress::Running
+    } else {
+        let surplus = duration - *elapsed;
+        *elapsed = Duration::ZERO;
+        TweenProgress::Done { surplus }
+    }
+}
+
+/// Scales a duration, saturating instead of panicking.
+pub(crate) fn scale_duration(duration: Duration, factor: f64) -> Duration {
+    if factor == 1.0 {
+        duration
+    } else {
+        Duration::try_from_secs_f64(duration.as_secs_f64() * factor).unwrap_or(if factor > 0.0 {
+            Duration::MAX
+        } else {
+            Duration::ZERO
+        })
+    }
+}
+
+impl Interpolator for Lerp {
+    fn interpolate(&self, position: f32) -> f32 {
+        position
+    }
+
+    fn interpolate_f64(&self, position: f64) -> f64 {
+        position
+    }
+
+    fn into_ease(self) -> Ease {
+        Ease::Lerp
+    }
+}
+
+impl Interpolator for EaseFunction {
+    fn interpolate(&self, position: f32) -> f32 {
+        position.calc(*self)
+    }
+
+    fn into_ease(self) -> Ease {
+        Ease::Function(self)
+    }
+}
+
+impl Interpolator for Ease {
+    fn interpolate(&self, position: f32) -> f32 {
+        match self {
+            Ease::Lerp => position,
+            Ease::Function(function) => function.interpolate(position),
+            Ease::Easing(easing) => easing.ease(position),
+            Ease::Custom(interpolator) => interpolator.interpolate(position),
+        }
+    }
+
+    fn interpolate_f64(&self, position: f64) -> f64 {
+        match self {
+            Ease::Lerp => position,
+            Ease::Easing(easing) => easing.ease_f64(position),
+            Ease::Custom(interpolator) => interpolator.interpolate_f64(position),
+            Ease::Function(function) => function.interpolate(position as f32) as f64,
+        }
+    }
+
+    fn into_ease(self) -> Ease {
+        self
+    }
+}
+
+impl<T, A: TweenApplier<T> + Clone> TweenApplier<T> for Quantized<A> {
+    fn apply(&mut self, target: &mut T, value: f32) {
+        self.apply_f64(target, value as f64);
+    }
+
+    fn apply_f64(&mut self, target: &mut T, value: f64) {
+        let steps = self.steps.max(1) as f64;
+        self.applier
+            .apply_f64(target, (value * steps).round() / steps);
+    }
+
+    fn try_apply(&mut self, target: &mut T, value: f64) -> Result<(), ApplyError> {
+        let steps = self.steps.max(1) as f64;
+        self.applier
+            .try_apply(target, (value * steps).round() / steps)
+    }
+
+    fn randomize(&mut self, rng: &mut TweenRng) {
+        self.applier.randomize(rng);
+    }
+
+    fn on_start(&mut self, target: &mut T) {
+        self.applier.on_start(target);
+    }
+
+    fn on_complete(&mut self, target: &mut T) {
+        self.applier.on_complete(target);
+    }
+
+    fn on_loop(&mut self, target: &mut T) {
+        self.applier.on_loop(target);
+    }
+}
+
+impl<T, F: Fn(&T) -> bool + Send + Sync + Clone> TweenPredicate<T> for F {
+    fn test(&self, target: &T) -> bool {
+        self(target)
+    }
+}
+
+impl<T, E, F: FnMut(usize) -> Option<Tween<T, E>> + Send + Sync + Clone> TweenGenerator<T, E>
+    for F
+{
+    fn generate(&mut self, index: usize) -> Option<Tween<T, E>> {
+        self(index)
+    }
+}
+
+impl<E> EventSender<E> for NoEvent {
+    fn send(&mut self, _: &E) {}
+}
+
+impl<E: Clone> EventSender<E> for Vec<E> {
+    fn send(&mut self, event: &E) {
+        self.push(event.clone());
+    }
+}
+
+impl<T> Tween<T, NoEvent> {
+    pub fn new(
+        duration: Duration,
+        function: impl Interpolator + 'static,
+        applier: impl TweenApplier<T> + 'static,
+    ) -> Self {
+        Self::once(duration, function, applier, None)
+    }
+}
+
+impl<T, E> Tween<T, E> {
+    pub fn new_with_event(
+        duration: Duration,
+        function: impl Interpolator + 'static,
+        applier: impl TweenApplier<T> + 'static,
+        completed_event: E,
+    ) -> Self {
+        Self::once(duration, function, applier, Some(completed_event))
+    }
+
+    pub(crate) fn once(
+        duration: Duration,
+        function: impl Interpolator + 'static,
+        applier: impl TweenApplier<T> + 'static,
+        completed_event: Option<E>,
+    ) -> Self {
+        let mut rng = TweenRng::default();
+        let mut applier = Box::new(applier);
+        applier.randomize(&mut rng);
+        Self::Once {
+            duration,
+            elapsed: Duration::ZERO,
+            function: function.into_ease(),
+            applier,
+            finished: false,
+            random_duration: None,
+            rng,
+            completed_event,
+            markers: Vec::new(),
+        }
+    }
+
+    /// Decelerates from the initial velocity `v0` (units per second) at a constant `friction`
+    /// (units per second²) until coming to rest, ie. for kinetic scrolling or thrown objects.
+    /// `applier` is created from the signed distance travelled until then.
+    pub fn from_velocity<A: TweenApplier<T> + 'static>(
+        v0: f32,
+        friction: f32,
+        applier: impl FnOnce(f32) -> A,
+    ) -> Self {
+        let friction = friction.abs().max(f32::EPSILON);
+        let duration = Duration::try_from_secs_f32(v0.abs() / friction).unwrap_or(Duration::MAX);
+        let distance = v0 * v0.abs() / (2.0 * friction);
+        Self::once(duration, Easing::QuadraticOut, applier(distance), None)
+    }
+
+    /// Applies the final value of `applier` instantly.
+    pub fn set(applier: impl TweenApplier<T> + 'static) -> Self {
+        Self::once(Duration::ZERO, Lerp, applier, None)
+    }
+
+    pub fn pause(duration: Duration) -> Self {
+        Self::Pause {
+            duration,
+            elapsed: Duration::ZERO,
+            random_duration: None,
+            rng: TweenRng::default(),
+            completed_event: None,
+        }
+    }
+
+    pub fn repeat(times: RepeatTimes, tween: Tween<T, E>) -> Self {
+        Self::Repeat {
+            times,
+            count: 0,
+            tween: Box::new(tween),
+            decay: RepeatDecay::default(),
+            completed_event: None,
+        }
+    }
+
+    pub fn sequence(tweens: impl Into<Vec<Tween<T, E>>>) -> Self {
+        Self::Sequence {
+            index: 0,
+            tweens: tweens.into(),
+            completed_event: None,
+        }
+    }
+
+    pub fn parallel(tweens: impl Into<Vec<Tween<T, E>>>) -> Self {
+        Self::Parallel {
+            tweens: tweens.into(),
+            policy: ParallelPolicy::WhenAll,
+            elapsed: Duration::ZERO,
+            completed_event: None,
+        }
+    }
+
+    /// Like [`Tween::parallel`], but shorter tweens are followed by a pause, so all of them end at
+    /// the same time. Tweens with unknown duration are left as they are.
+    pub fn parallel_padded(tweens: impl Into<Vec<Tween<T, E>>>) -> Self {
+        let tweens = tweens.into();
+        let Some(longest) = tweens.iter().filter_map(Tween::duration).max() else {
+            return Self::parallel(tweens);
+        };
+        Self::parallel(
+            tweens
+                .into_iter()
+                .map(|tween| match tween.duration() {
+                    Some(duration) if duration < longest => {
+                        Tween::sequence([tween, Tween::pause(longest - duration)])
+                    }
+                    _ => tween,
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Like [`Tween::parallel`], but the durations of shorter tweens are stretched, so all of them
+    /// end together with the longest one. Tweens with unknown or zero duration are left as they
+    /// are.
+    pub fn parallel_synced(tweens: impl Into<Vec<Tween<T, E>>>) -> Self {
+        let mut tweens = tweens.into();
+        if let Some(longest) = tweens.iter().filter_map(Tween::duration).max() {
+            for tween in &mut tweens {
+                match tween.duration() {
+                    Some(duration) if !duration.is_zero() && duration < longest => {
+                        tween.scale_time(longest.as_secs_f64() / duration.as_secs_f64());
+                    }
+                    _ => (),
+                }
+            }
+        }
+        Self::parallel(tweens)
+    }
+
+    /// Plays all tweens in parallel, until the first one completes.
+    pub fn race(tweens: impl Into<Vec<Tween<T, E>>>) -> Self {
+        Self::parallel(tweens).with_policy(ParallelPolicy::WhenAny)
+    }
+
+    /// Sets when a [`Tween::parallel`] completes. Has no effect on other tweens.
+    pub fn with_policy(mut self, parallel_policy: ParallelPolicy) -> Self {
+        if let Tween::Parallel { policy, .. } = &mut self {
+            *policy = parallel_policy;
+        }
+        self
+    }
+
+    /// Plays `if_true` or `if_false`, depending on the `predicate` at the moment the branch is
+    /// reached. The decision sticks until the branch is reset (ie. by a [`Tween::repeat`]).
+    pub fn branch(
+        predicate: impl TweenPredicate<T> + 'static,
+        if_true: Tween<T, E>,
+        if_false: Tween<T, E>,
+    ) -> Self {
+        Self::Branch {
+            predicate: Box::new(predicate),
+            if_true: Box::new(if_true),
+            if_false: Box::new(if_false),
+            chosen: None,
+            completed_event: None,
+        }
+    }
+
+    /// Repeats `tween` as long as the `predicate` holds. The predicate is checked before each
+    /// iteration, so a running iteration is always completed.
+    pub fn repeat_while(predicate: impl TweenPredicate<T> + 'static, tween: Tween<T, E>) -> Self {
+        Self::RepeatWhile {
+            predicate: Box::new(predicate),
+            tween: Box::new(tween),
+            iterating: false,
+            count: 0,
+            completed_event: None,
+        }
+    }
+
+    /// Plays one of the given tweens, picked at random each time this tween is entered.
+    pub fn choose(tweens: impl Into<Vec<Tween<T, E>>>) -> Self {
+        let tweens = tweens.into();
+        Self::Choose {
+            weights: vec![1.0; tweens.len()],
+            tweens,
+            chosen: None,
+            rng: TweenRng::default(),
+            completed_event: None,
+        }
+    }
+
+    /// Like [`Tween::choose`], but the chance of a tween being picked is proportional to its
+    /// weight.
+    pub fn choose_weighted(weighted_tweens: impl IntoIterator<Item = (f32, Tween<T, E>)>) -> Self {
+        let (weights, tweens) = weighted_tweens.into_iter().unzip();
+        Self::Choose {
+            tweens,
+            weights,
+            chosen: None,
+            rng: TweenRng::default(),
+            completed_event: None,
+        }
+    }
+
+    /// Plays `tween` faster (`factor > 1`) or slower (`factor < 1`). Negative factors are treated
+    /// as 0, which halts the tween.
+    pub fn speed(factor: impl Into<TweenSpeed>, tween: Tween<T, E>) -> Self {
+        Self::Speed {
+            factor: factor.into(),
+            tween: Box::new(tween),
+            completed_event: None,
+        }
+    }
+
+    /// Starts `tween` as if `offset` had already elapsed, ie. to phase shift identical looping
+    /// tweens. No events are sent for the skipped part.
+    pub fn offset(offset: Duration, tween: Tween<T, E>) -> Self {
+        Self::Offset {
+            offset,
+            tween: Box::new(tween),
+            started: false,
+            elapsed: Duration::ZERO,
+            completed_event: None,
+        }
+    }
+
+    /// Plays `tween` backwards, from its end to its start. Events of `tween` itself are not sent.
+    /// The reversed tween must end, a reversed infinite tween completes immediately.
+    pub fn reversed(tween: Tween<T, E>) -> Self {
+        Self::Reversed {
+            tween: Box::new(tween),
+            started: false,
+            completed_event: None,
+        }
+    }
+
+    /// A sequence of tweens, which are generated one at a time, when the previous one completed.
+    /// Allows endless sequences, ie. random patrol routes. A generated sequence can only be
+    /// rewound to the start of its current step.
+    pub fn generate(generator: impl TweenGenerator<T, E> + 'static) -> Self {
+        Self::Generate {
+            generator: Box::new(generator),
+            index: 0,
+            current: None,
+            completed_event: None,
+        }
+    }
+
+    /// Plays one of `tweens` per iteration, cycling through them - ie. for blink patterns or
+    /// alternating left and right steps. Ends after `times` iterations, each tween is reset in place
+    /// when its turn comes again.
+    pub fn alternate(times: RepeatTimes, tweens: impl Into<Vec<Tween<T, E>>>) -> Self {
+        Self::Alternate {
+            tweens: tweens.into(),
+            times,
+            count: 0,
+            completed_event: None,
+        }
+    }
+
+    /// Sends `event` when a [`Tween::new`] reaches `at`, exactly once - even if a single advance
+    /// skips over it. Markers at the same time are sent in the order they were added, all before
+    /// the completion event. Has no effect on other tweens.
+    pub fn with_marker(mut self, at: Duration, event: E) -> Self {
+        if let Tween::Once { markers, .. } = &mut self {
+            let index = markers.partition_point(|(time, _)| *time <= at);
+            markers.insert(index, (at, event));
+        }
+        self
+    }
+
+    pub fn with_completed(mut self, event: E) -> Self {
+        *self.completed_event_mut() = Some(event);
+        self
+    }
+
+    /// Sends the completion event of this tween (but not of its children), ie. when it's stopped
+    /// for good before reaching its end.
+    #[cfg(feature = "bevy")]
+    pub(crate) fn send_completed<ES: EventSender<E>>(&mut self, event_sender: &mut ES) {
+        if let Some(e) = self.completed_event_mut() {
+            event_sender.send(e);
+        }
+    }
+
+    fn completed_event_mut(&mut self) -> &mut Option<E> {
+        match self {
+            Tween::Once {
+                completed_event, ..
+            }
+            | Tween::Repeat {
+                completed_event, ..
+            }
+            | Tween::Sequence {
+                completed_event, ..
+            }
+            | Tween::Parallel {
+                completed_event, ..
+            }
+            | Tween::Pause {
+                completed_event, ..
+            }
+            | Tween::Branch {
+                completed_event, ..
+            }
+            | Tween::RepeatWhile {
+                completed_event, ..
+            }
+            | Tween::Choose {
+                completed_event, ..
+            }
+            | Tween::Speed {
+                completed_event, ..
+            }
+            | Tween::Offset {
+                completed_event, ..
+            }
+            | Tween::Reversed {
+                completed_event, ..
+            }
+            | Tween::Generate {
+                completed_event, ..
+            }
+            | Tween::Alternate {
+                completed_event, ..
+            } => completed_event,
+        }
+    }
+
+    /// Scales the duration of each iteration of a [`Tween::repeat`] by `duration` relative to the
+    /// previous one. Has no effect on other tweens. To shrink the values of each iteration (ie. to
+    /// let a bouncing ball settle), create the iterations with [`Tween::generate`] instead.
+    pub fn with_decay(mut self, duration: f32) -> Self {
+        if let Tween::Repeat { decay, .. } = &mut self {
+            *decay = RepeatDecay { duration };
+        }
+        self
+    }
+
+    /// Appends a tween to this sequence, which might already be playing (or even completed). Any
+    /// other tween is turned into a sequence first.
+    ///
+    /// A completed sequence is reopened: it completes again after the appended tween, sending its
+    /// completion event once more - so whoever waits for it learns about the new end.
+    pub fn push(&mut self, tween: Tween<T, E>) {
+        if !matches!(self, Tween::Sequence { .. }) {
+            *self = Tween::sequence([std::mem::take(self)]);
+        }
+        if let Tween::Sequence { tweens, .. } = self {
+            tweens.push(tween);
+        }
+    }
+
+    /// Rolls the duration of a [`Tween::new`] or [`Tween::pause`] from the given range, each time
+    /// it starts. Has no effect on other tweens.
+    pub fn with_random_duration(mut self, range: Rand<Duration>) -> Self {
+        if let Tween::Once {
+            random_duration, ..
+        }
+        | Tween::Pause {
+            random_duration, ..
+        } = &mut self
+        {
+            *random_duration = Some(Box::new(range));
+        }
+        self.reroll();
+        self
+    }
+
+    /// Reseeds all random generators of this tween, to get reproducible results. Randomized
+    /// parameters are rolled again, so this should be done before playing the tween.
+    pub fn seed(&mut self, seed: u64) {
+        self.seed_from(&mut TweenRng::new(seed));
+    }
+
+    fn seed_from(&mut self, seeds: &mut TweenRng) {
+        self.for_each_mut(|tween| match tween {
+            Tween::Once { rng, .. } | Tween::Pause { rng, .. } => {
+                *rng = TweenRng::new(seeds.next_u64());
+                tween.reroll();
+            }
+            Tween::Choose { rng, .. } => *rng = TweenRng::new(seeds.next_u64()),
+            _ => (),
+        });
+    }
+
+    /// Calls `f` for the appliers of this tween and all its children.
+    fn for_each_applier(&mut self, f: &mut impl FnMut(&mut dyn TweenApplier<T>)) {
+        self.for_each_mut(|tween| {
+            if let Tween::Once { applier, .. } = tween {
+                f(applier.as_mut());
+            }
+        });
+    }
+
+    /// Rolls randomized parameters of this tween (but not of its children).
+    fn reroll(&mut self) {
+        match self {
+            Tween::Once {
+                duration,
+                applier,
+                random_duration,
+                rng,
+                ..
+            } => {
+                if let Some(random_duration) = random_duration {
+                    *duration = random_duration.sample(rng);
+                }
+                applier.randomize(rng);
+            }
+            Tween::Pause {
+                duration,
+                random_duration: Some(random_duration),
+                rng,
+                ..
+            } => *duration = random_duration.sample(rng),
+            _ => (),
+        }
+    }
+
+    /// The runtime state of this tween, see [`TweenState`].
+    pub fn state(&self) -> TweenState {
+        let mut state = self.shallow_state();
+        self.state_into(&mut state);
+        state
+    }
+
+    /// Like [`Tween::state`], but writes into `state` - reusing its allocations if it was taken
+    /// from an identically built tween.
+    pub fn state_into(&self, state: &mut TweenState) {
+        let mut pending = vec![(self, state)];
+        while let Some((tween, state)) = pending.pop() {
+            tween.node_state_into(state);
+            pending.extend(tween.children().zip(state.children_mut()));
+        }
+    }
+
+    /// The state of this tween, with placeholders for the states of its children.
+    fn shallow_state(&self) -> TweenState {
+        let boxed = |_: &Tween<T, E>| Box::new(TweenState::placeholder());
+        let all =
+            |tweens: &[Tween<T, E>]| tweens.iter().map(|_| TweenState::placeholder()).collect();
+        match self {
+            Tween::Once {
+                duration,
+                elapsed,
+                finished,
+                ..
+            } => TweenState::Once {
+                duration: *duration,
+                elapsed: *elapsed,
+                finished: *finished,
+            },
+            Tween::Repeat { tween, count, .. } => TweenState::Repeat {
+                count: *count,
+                tween: boxed(tween),
+            },
+            Tween::Sequence { index, tweens, .. } => TweenState::Sequence {
+                index: *index,
+                tweens: all(tweens),
+            },
+            Tween::Parallel {
+                tweens, elapsed, ..
+            } => TweenState::Parallel {
+                elapsed: *elapsed,
+                tweens: all(tweens),
+            },
+            Tween::Pause {
+                duration, elapsed, ..
+            } => TweenState::Pause {
+                duration: *duration,
+                elapsed: *elapsed,
+            },
+            Tween::Branch {
+                if_true,
+                if_false,
+                chosen,
+                ..
+            } => TweenState::Branch {
+                chosen: *chosen,
+                if_true: boxed(if_true),
+                if_false: boxed(if_false),
+            },
+            Tween::RepeatWhile {
+                tween,
+                iterating,
+                count,
+                ..
+            } => TweenState::RepeatWhile {
+                iterating: *iterating,
+                count: *count,
+                tween: boxed(tween),
+            },
+            Tween::Choose { tweens, chosen, .. } => TweenState::Choose {
+                chosen: *chosen,
+                tweens: all(tweens),
+            },
+            Tween::Speed { factor, tween, .. } => TweenState::Speed {
+                factor: factor.get(),
+                tween: boxed(tween),
+            },
+            Tween::Offset {
+                tween,
+                started,
+                elapsed,
+                ..
+            } => TweenState::Offset {
+                started: *started,
+                elapsed: *elapsed,
+                tween: boxed(tween),
+            },
+            Tween::Reversed { tween, started, .. } => TweenState::Reversed {
+                started: *started,
+                tween: boxed(tween),
+            },
+            Tween::Generate { index, current, .. } => TweenState::Generate {
+                index: *index,
+                current: current.as_deref().map(boxed),
+            },
+            Tween::Alternate { tweens, count, .. } => TweenState::Alternate {
+                count: *count,
+                tweens: all(tweens),
+            },
+        }
+    }
+
+    /// Writes the state of this tween (but not of its children) into `state`, which is replaced
+    /// if it doesn't have the same shape.
+    fn node_state_into(&self, state: &mut TweenState) {
+        if self.children().count() != state.children().count() {
+            *state = self.shallow_state();
+            return;
+        }
+        match (self, state) {
+            (
+                Tween::Once {
+                    duration,
+                    elapsed,
+                    finished,
+                    ..
+                },
+                TweenState::Once {
+                    duration: state_duration,
+                    elapsed: state_elapsed,
+                    finished: state_finished,
+                },
+            ) => {
+                *state_duration = *duration;
+                *state_elapsed = *elapsed;
+                *state_finished = *finished;
+            }
+            (
+                Tween::Repeat { count, .. },
+                TweenState::Repeat {
+                    count: state_count, ..
+                },
+            ) => {
+                *state_count = *count;
+            }
+            (
+                Tween::Sequence { index, .. },
+                TweenState::Sequence {
+                    index: state_index, ..
+                },
+            ) => {
+                *state_index = *index;
+            }
+            (
+                Tween::Parallel { elapsed, .. },
+                TweenState::Parallel {
+                    elapsed: state_elapsed,
+                    ..
+                },
+            ) => {
+                *state_elapsed = *elapsed;
+            }
+            (
+                Tween::Pause {
+                    duration, elapsed, ..
+                },
+                TweenState::Pause {
+                    duration: state_duration,
+                    elapsed: state_elapsed,
+                },
+            ) => {
+                *state_duration = *duration;
+                *state_elapsed = *elapsed;
+            }
+            (
+                Tween::Branch { chosen, .. },
+                TweenState::Branch {
+                    chosen: state_chosen,
+                    ..
+                },
+            ) => {
+                *state_chosen = *chosen;
+            }
+            (
+                Tween::RepeatWhile {
+                    iterating, count, ..
+                },
+                TweenState::RepeatWhile {
+                    iterating: state_iterating,
+                    count: state_count,
+                    ..
+                },
+            ) => {
+                *state_iterating = *iterating;
+                *state_count = *count;
+            }
+            (
+                Tween::Choose { chosen, .. },
+                TweenState::Choose {
+                    chosen: state_chosen,
+                    ..
+                },
+            ) => {
+                *state_chosen = *chosen;
+            }
+            (
+                Tween::Speed { factor, .. },
+                TweenState::Speed {
+                    factor: state_factor,
+                    ..
+                },
+            ) => {
+                *state_factor = factor.get();
+            }
+            (
+                Tween::Offset {
+                    started, elapsed, ..
+                },
+                TweenState::Offset {
+                    started: state_started,
+                    elapsed: state_elapsed,
+                    ..
+                },
+            ) => {
+                *state_started = *started;
+                *state_elapsed = *elapsed;
+            }
+            (
+                Tween::Reversed { started, .. },
+                TweenState::Reversed {
+                    started: state_started,
+                    ..
+                },
+            ) => {
+                *state_started = *started;
+            }
+            (
+                Tween::Generate { index, .. },
+                TweenState::Generate {
+                    index: state_index, ..
+                },
+            ) => *state_index = *index,
+            (
+                Tween::Alternate { count, .. },
+                TweenState::Alternate {
+                    count: state_count, ..
+                },
+            ) => *state_count = *count,
+            (tween, state) => *state = tween.shallow_state(),
+        }
+    }
+
+    /// Restores a state taken with [`Tween::state`] from an identically built tween. Values are
+    /// not applied until the tween is advanced, randomized appliers are rolled anew. On a mismatch
+    /// the tween may be partially restored.
+    pub fn restore_state(&mut self, state: &TweenState) -> Result<(), TweenStateMismatch> {
+        let mut pending = vec![(self, state)];
+        while let Some((tween, state)) = pending.pop() {
+            tween.restore_node_state(state)?;
+            if tween.children().count() != state.children().count() {
+                return Err(TweenStateMismatch);
+            }
+            pending.extend(tween.children_mut().zip(state.children()));
+        }
+        Ok(())
+    }
+
+    /// Copies the random generators of an identically built tween, so both roll the same values
+    /// from now on.
+    #[cfg(feature = "bevy")]
+    pub(crate) fn copy_rngs_from(&mut self, other: &Tween<T, E>) {
+        let mut pending = vec![(self, other)];
+        while let Some((tween, other)) = pending.pop() {
+            match (&mut *tween, other) {
+                (Tween::Once { rng, .. }, Tween::Once { rng: other_rng, .. })
+                | (Tween::Pause { rng, .. }, Tween::Pause { rng: other_rng, .. })
+                | (Tween::Choose { rng, .. }, Tween::Choose { rng: other_rng, .. }) => {
+                    rng.clone_from(other_rng)
+                }
+                _ => (),
+            }
+            pending.extend(tween.children_mut().zip(other.children()));
+        }
+    }
+
+    /// Restores the state of this tween (but not of its children) from `state`. A generated tween
+    /// is generated again.
+    fn restore_node_state(&mut self, state: &TweenState) -> Result<(), TweenStateMismatch> {
+        match (self, state) {
+            (
+                Tween::Once {
+                    duration,
+                    elapsed,
+                    finished,
+                    ..
+                },
+                TweenState::Once {
+                    duration: state_duration,
+                    elapsed: state_elapsed,
+                    finished: state_finished,
+                },
+            ) => {
+                *duration = *state_duration;
+                *elapsed = *state_elapsed;
+                *finished = *state_finished;
+            }
+            (
+                Tween::Repeat { count, .. },
+                TweenState::Repeat {
+                    count: state_count, ..
+                },
+            ) => {
+                *count = *state_count;
+            }
+            (
+                Tween::Sequence { index, .. },
+                TweenState::Sequence {
+                    index: state_index, ..
+                },
+            ) => {
+                *index = *state_index;
+            }
+            (
+                Tween::Parallel { elapsed, .. },
+                TweenState::Parallel {
+                    elapsed: state_elapsed,
+                    ..
+                },
+            ) => {
+                *elapsed = *state_elapsed;
+            }
+            (
+                Tween::Pause {
+                    duration, elapsed, ..
+                },
+                TweenState::Pause {
+                    duration: state_duration,
+                    elapsed: state_elapsed,
+                },
+            ) => {
+                *duration = *state_duration;
+                *elapsed = *state_elapsed;
+            }
+            (
+                Tween::Branch { chosen, .. },
+                TweenState::Branch {
+                    chosen: state_chosen,
+                    ..
+                },
+            ) => {
+                *chosen = *state_chosen;
+            }
+            (
+                Tween::RepeatWhile {
+                    iterating, count, ..
+                },
+                TweenState::RepeatWhile {
+                    iterating: state_iterating,
+                    count: state_count,
+                    ..
+                },
+            ) => {
+                *iterating = *state_iterating;
+                *count = *state_count;
+            }
+            (
+                Tween::Choose { chosen, .. },
+                TweenState::Choose {
+                    chosen: state_chosen,
+                    ..
+                },
+            ) => {
+                *chosen = *state_chosen;
+            }
+            (
+                Tween::Speed { factor, .. },
+                TweenState::Speed {
+                    factor: state_factor,
+                    ..
+                },
+            ) => {
+                factor.set(*state_factor);
+            }
+            (
+                Tween::Offset {
+                    started, elapsed, ..
+                },
+                TweenState::Offset {
+                    started: state_started,
+                    elapsed: state_elapsed,
+                    ..
+                },
+            ) => {
+                *started = *state_started;
+                *elapsed = *state_elapsed;
+            }
+            (
+                Tween::Reversed { started, .. },
+                TweenState::Reversed {
+                    started: state_started,
+                    ..
+                },
+            ) => {
+                *started = *state_started;
+            }
+            (
+                Tween::Generate {
+                    generator,
+                    index,
+                    current,
+                    ..
+                },
+                TweenState::Generate {
+                    index: state_index,
+                    current: state_current,
+                },
+            ) => {
+                *index = *state_index;
+                *current = match state_current {
+                    Some(_) => Some(Box::new(
+                        generator.generate(*index).ok_or(TweenStateMismatch)?,
+                    )),
+                    None => None,
+                };
+            }
+            (
+                Tween::Alternate { count, .. },
+                TweenState::Alternate {
+                    count: state_count, ..
+                },
+            ) => *count = *state_count,
+            _ => return Err(TweenStateMismatch),
+        }
+        Ok(())
+    }
+
+    pub fn skip(&mut self, duration: Duration) -> TweenProgress {
+        self.step(None, &mut NoEvent, duration)
+    }
+
+    /// Fast-forwards by `duration` like [`Tween::skip`], but still applies the final value of each
+    /// skipped step and sends the events on the way, each exactly once - ie. so discrete steps
+    /// (like swapping a sprite) and loop events aren't lost when catching up. Unlike
+    /// [`Tween::advance`], the step still in progress isn't applied.
+    pub fn skip_applying<ES: EventSender<E>>(
+        &mut self,
+        target: &mut T,
+        event_sender: &mut ES,
+        duration: Duration,
+    ) -> TweenProgress {
+        let mut target = TargetRef::with_mode(target, ApplyMode::Completed);
+        self.step(Some(&mut target), event_sender, duration)
+    }
+
+    pub fn advance<'a, ES: EventSender<E>>(
+        &'a mut self,
+        target: &'a mut T,
+        event_sender: &'a mut ES,
+        duration: Duration,
+    ) -> TweenProgress {
+        self.advance_changed(target, event_sender, duration).0
+    }
+
+    /// Estimates how fast the value `read` from the target changes right now (per second), by
+    /// playing a copy of this tween a little further. Used to hand off the motion to another
+    /// tween without a hitch, ie. one created by [`Tween::from_velocity`].
+    pub fn output_velocity(&self, target: &T, read: impl Fn(&T) -> f32) -> f32
+    where
+        T: Clone,
+        E: Clone,
+    {
+        const STEP: Duration = Duration::from_millis(1);
+        let mut tween = self.clone();
+        let mut before = target.clone();
+        tween.advance(&mut before, &mut NoEvent, Duration::ZERO);
+        let mut after = before.clone();
+        tween.advance(&mut after, &mut NoEvent, STEP);
+        (read(&after) - read(&before)) / STEP.as_secs_f32()
+    }
+
+    /// Jumps to the end, applying the final values and sending the events on the way - ie. to skip
+    /// a cutscene. Returns `false` if the tween never ends (or its end isn't known in advance).
+    pub fn finish<ES: EventSender<E>>(&mut self, target: &mut T, event_sender: &mut ES) -> bool {
+        self.finish_checked(target, event_sender).0
+    }
+
+    /// Like [`Tween::finish`], but also returns whether anything was applied to the target, and
+    /// the first error applying a value.
+    pub(crate) fn finish_checked<ES: EventSender<E>>(
+        &mut self,
+        target: &mut T,
+        event_sender: &mut ES,
+    ) -> (bool, bool, Option<ApplyError>) {
+        let (mut changed, mut first_error) = (false, None);
+        let mut stack = TweenStack::default();
+        // Randomized durations are rolled again while playing, so the end might move
+        for _ in 0..MAX_ZERO_DURATION_STEPS {
+            let Timing {
+                duration: Some(duration),
+                elapsed: Some(elapsed),
+                ..
+            } = self.timing()
+            else {
+                break;
+            };
+            let (result, applied, error) = self.advance_checked_with(
+                &mut stack,
+                target,
+                event_sender,
+                duration.saturating_sub(elapsed),
+            );
+            changed |= applied;
+            first_error = first_error.or(error);
+            if matches!(result, TweenProgress::Done { .. }) {
+                return (true, changed, first_error);
+            }
+        }
+        (false, changed, first_error)
+    }
+
+    /// Moves this tween backwards by `duration`, applying the values on the way. Sequences and
+    /// repeats walk back into their previous steps, `Done` is returned once the start is reached.
+    /// No events are sent.
+    pub fn rewind(&mut self, target: &mut T, duration: Duration) -> TweenProgress {
+        self.step_back(Some(&mut TargetRef::new(target)), duration)
+    }
+
+    /// Moves this tween forward or backward to `position`, applying the values on the way, ie. to
+    /// scrub through it in tools. No events are sent. Returns `false` if the time played so far is
+    /// unknown (see [`Tween::elapsed`]).
+    pub fn seek(&mut self, target: &mut T, position: Duration) -> bool {
+        let Some(elapsed) = self.elapsed() else {
+            return false;
+        };
+        if position < elapsed {
+            self.rewind(target, elapsed - position);
+        } else {
+            self.advance(target, &mut NoEvent, position - elapsed);
+        }
+        true
+    }
+
+    /// Like [`Tween::advance`], but also returns whether any value was applied to the target. Ie.
+    /// pauses and completed tweens don't apply values.
+    pub fn advance_changed<ES: EventSender<E>>(
+        &mut self,
+        target: &mut T,
+        event_sender: &mut ES,
+        duration: Duration,
+    ) -> (TweenProgress, bool) {
+        let (result, changed, _) = self.advance_checked(target, event_sender, duration);
+        (result, changed)
+    }
+
+    /// Like [`Tween::advance_changed`], but also returns the first error applying a value.
+    pub(crate) fn advance_checked<ES: EventSender<E>>(
+        &mut self,
+        target: &mut T,
+        event_sender: &mut ES,
+        duration: Duration,
+    ) -> (TweenProgress, bool, Option<ApplyError>) {
+        self.advance_checked_with(&mut TweenStack::default(), target, event_sender, duration)
+    }
+
+    /// Like [`Tween::advance`], but steps nested tweens in `stack` instead of allocating a new one
+    /// each time.
+    pub fn advance_with<ES: EventSender<E>>(
+        &mut self,
+        stack: &mut TweenStack<T, E>,
+        target: &mut T,
+        event_sender: &mut ES,
+        duration: Duration,
+    ) -> TweenProgress {
+        self.advance_checked_with(stack, target, event_sender, duration)
+            .0
+    }
+
+    /// Like [`Tween::advance_checked`], reusing `stack`.
+    pub(crate) fn advance_checked_with<ES: EventSender<E>>(
+        &mut self,
+        stack: &mut TweenStack<T, E>,
+        target: &mut T,
+        event_sender: &mut ES,
+        duration: Duration,
+    ) -> (TweenProgress, bool, Option<ApplyError>) {
+        let mut target = TargetRef::new(target);
+        let result = self.drive(
+            stack,
+            StepMode::Forward,
+            Some(&mut target),
+            event_sender,
+            duration,
+        );
+        (result, target.applied, target.error)
+    }
+
+    /// Shared implementation of [`Tween::skip`] and [`Tween::advance`]. Without a target, no
+    /// values are applied.
+    fn step<ES: EventSender<E>>(
+        &mut self,
+        target: Option<&mut TargetRef<'_, T>>,
+        event_sender: &mut ES,
+        duration: Duration,
+    ) -> TweenProgress {
+        self.drive(
+            &mut TweenStack::default(),
+            StepMode::Forward,
+            target,
+            event_sender,
+            duration,
+        )
+    }
+
+    /// Moves this tween backwards, towards its start. `Done` is returned once the start is reached.
+    /// No events are sent.
+    fn step_back(
+        &mut self,
+        target: Option<&mut TargetRef<'_, T>>,
+        duration: Duration,
+    ) -> TweenProgress {
+        self.drive(
+            &mut TweenStack::default(),
+            StepMode::Back,
+            target,
+            &mut NoEvent,
+            duration,
+        )
+    }
+
+    /// Steps this tween in the given mode, until it returns.
+    ///
+    /// Nested tweens are stepped with an explicit stack (kept in `stack`) instead of recursion,
+    /// so deeply nested trees can't overflow the call stack. A child is moved out of its parent
+    /// while it is stepped, and moved back even if an applier panics.
+    fn drive<ES: EventSender<E>>(
+        &mut self,
+        stack: &mut TweenStack<T, E>,
+        mode: StepMode,
+        mut target: Option<&mut TargetRef<'_, T>>,
+        event_sender: &mut ES,
+        duration: Duration,
+    ) -> TweenProgress {
+        let TweenStack { frames, surpluses } = stack;
+        let mut stack = StepStack {
+            root: self,
+            root_state: StepState::Start,
+            frames,
+        };
+        let mut root_duration = duration;
+        let mut child_result = None;
+        loop {
+            let (tween, state, duration, silent, mode) = match stack.frames.last_mut() {
+                Some(frame) => (
+                    &mut frame.tween,
+                    &mut frame.state,
+                    &mut frame.duration,
+                    frame.silent,
+                    frame.mode,
+                ),
+                None => (
+                    &mut *stack.root,
+                    &mut stack.root_state,
+                    &mut root_duration,
+                    false,
+                    mode,
+                ),
+            };
+            let target = target.as_deref_mut();
+            let action = match mode {
+                StepMode::Forward if silent => tween.resume_step(
+                    state,
+                    duration,
+                    target,
+                    &mut NoEvent,
+                    surpluses,
+                    child_result,
+                ),
+                StepMode::Forward => tween.resume_step(
+                    state,
+                    duration,
+                    target,
+                    event_sender,
+                    surpluses,
+                    child_result,
+                ),
+                StepMode::Back => tween.resume_step_back(state, duration, target, child_result),
+                StepMode::End => tween.resume_seek_end(state, target, child_result),
+            };
+            match action {
+                StepAction::Descend {
+                    duration,
+                    silent: silent_child,
+                    mode,
+                } => {
+                    let child = std::mem::replace(tween.step_child(state), Self::placeholder());
+                    stack.frames.push(StepFrame {
+                        tween: child,
+                        state: StepState::Start,
+                        duration,
+                        silent: silent || silent_child,
+                        mode,
+                    });
+                    child_result = None;
+                }
+                StepAction::Return(progress) => {
+                    let Some(frame) = stack.frames.pop() else {
+                        return progress;
+                    };
+                    stack.put_back(frame.tween);
+                    child_result = Some(progress);
+                }
+            }
+        }
+    }
+
+    /// Steps this tween by `duration` (which is updated to the time left over), until one of its
+    /// children has to be stepped or it is finished. `child` is the result of stepping the child
+    /// requested by the previous call. Races take their surplus lists from `spare_surpluses` and
+    /// put them back once done.
+    fn resume_step<ES: EventSender<E>>(
+        &mut self,
+        state: &mut StepState,
+        duration: &mut Duration,
+        mut target: Option<&mut TargetRef<'_, T>>,
+        event_sender: &mut ES,
+        spare_surpluses: &mut Vec<Vec<Option<Duration>>>,
+        child: Option<TweenProgress>,
+    ) -> StepAction {
+        let descend = |duration: Duration| StepAction::Descend {
+            duration,
+            silent: false,
+            mode: StepMode::Forward,
+        };
+        match self {
+            Tween::Once {
+                duration: tween_duration,
+                elapsed,
+                function,
+                applier,
+                finished,
+                completed_event,
+                markers,
+                ..
+            } => {
+                let already_finished = *finished;
+                let starting = !already_finished
+                    && elapsed.is_zero()
+                    && (!duration.is_zero() || tween_duration.is_zero());
+                if !already_finished {
+                    let reached = (*elapsed + *duration).min(*tween_duration);
+                    for (at, e) in markers.iter() {
+                        if (*at > *elapsed || (at.is_zero() && starting)) && *at <= reached {
+                            event_sender.send(e);
+                        }
+                    }
+                }
+                *elapsed += *duration;
+                let result = if elapsed >= tween_duration {
+                    let surplus = *elapsed - *tween_duration;
+                    *elapsed = *tween_duration;
+                    *finished = true;
+                    if let Some(e) = completed_event {
+                        event_sender.send(e);
+                    }
+                    TweenProgress::Done { surplus }
+                } else {
+                    TweenProgress::Running
+                };
+                if let Some(target) = target.filter(|_| !already_finished) {
+                    if starting {
+                        applier.on_start(target.target);
+                    }
+                    if *finished || target.mode == ApplyMode::All {
+                        let v = function.interpolate_f64(progress(*elapsed, *tween_duration));
+                        target.apply(applier.as_mut(), v);
+                    }
+                    if *finished {
+                        applier.on_complete(target.target);
+                    }
+                }
+                StepAction::Return(result)
+            }
+            Tween::Repeat {
+                tween,
+                times,
+                count,
+                decay,
+                completed_event,
+            } => {
+                if let (Some(result), StepState::Repeat { time_scale }) = (child, &*state) {
+                    match result {
+                        TweenProgress::Done { surplus } => {
+                            let surplus = scale_duration(surplus, *time_scale).min(*duration);
+                            *count += 1;
+                            if *duration <= surplus && *times == RepeatTimes::Infinite {
+                                #[cfg(feature = "bevy")]
+                                bevy::log::error!("Found infinite repeating tween with zero duration child (infinite loop)");
+                                return StepAction::Return(TweenProgress::Running);
+                            }
+                            *duration = surplus;
+                            tween.reset();
+                            if let Some(target) = target.as_deref_mut() {
+                                if *times != RepeatTimes::N(*count) {
+                                    tween.for_each_applier(&mut |applier| {
+                                        applier.on_loop(target.target)
+                                    });
+                                }
+                            }
+                        }
+                        TweenProgress::Running => {
+                            return StepAction::Return(TweenProgress::Running);
+                        }
+                    }
+                }
+                let done = match times {
+                    RepeatTimes::N(amount) => count >= amount,
+                    RepeatTimes::Infinite => false,
+                };
+                if done {
+                    if let Some(e) = completed_event {
+                        event_sender.send(e);
+                    }
+                    return StepAction::Return(TweenProgress::Done { surplus: *duration });
+                }
+                let time_scale = decay.time_scale(*count);
+                *state = StepState::Repeat { time_scale };
+                descend(scale_duration(*duration, 1.0 / time_scale))
+            }
+            Tween::Sequence {
+                index,
+                tweens,
+                completed_event,
+            } => {
+                match child {
+                    Some(TweenProgress::Done { surplus }) => {
+                        *index += 1;
+                        *duration = surplus;
+                    }
+                    Some(TweenProgress::Running) => {
+                        return StepAction::Return(TweenProgress::Running);
+                    }
+                    None => (),
+                }
+                if *index < tweens.len() {
+                    return descend(*duration);
+                }
+                if let Some(e) = completed_event {
+                    event_sender.send(e);
+                }
+                StepAction::Return(TweenProgress::Done { surplus: *duration })
+            }
+            Tween::Parallel {
+                tweens,
+                policy: ParallelPolicy::WhenAll,
+                elapsed,
+                completed_event,
+            } => {
+                // `surplus` is the smallest surplus so far, or `None` if a tween is still running
+                let (next, surplus) = match (&*state, child) {
+                    (StepState::Parallel { child, surplus }, Some(result)) => (
+                        child + 1,
+                        match result {
+                            TweenProgress::Done { surplus: delegate } => {
+                                surplus.map(|surplus| surplus.min(delegate))
+                            }
+                            TweenProgress::Running => None,
+                        },
+                    ),
+                    _ => {
+                        *elapsed += *duration;
+                        (0, Some(*duration))
+                    }
+                };
+                if next < tweens.len() {
+                    *state = StepState::Parallel {
+                        child: next,
+                        surplus,
+                    };
+                    return descend(*duration);
+                }
+                let Some(surplus) = surplus else {
+                    return StepAction::Return(TweenProgress::Running);
+                };
+                *elapsed -= surplus;
+                if let Some(e) = completed_event {
+                    event_sender.send(e);
+                }
+                StepAction::Return(TweenProgress::Done { surplus })
+            }
+            Tween::Parallel {
+                tweens,
+                policy: ParallelPolicy::WhenAny,
+                elapsed,
+                completed_event,
+            } => {
+                let (mut next, surplus, mut surpluses) = match (std::mem::take(state), child) {
+                    (
+                        StepState::RaceBack {
+                            child,
+                            surplus,
+                            surpluses,
+                        },
+                        Some(_),
+                    ) => (child + 1, surplus, surpluses),
+                    (race, child) => {
+                        let (next, mut surpluses) = match (race, child) {
+                            (
+                                StepState::Race {
+                                    child,
+                                    mut surpluses,
+                                },
+                                Some(result),
+                            ) => {
+                                surpluses.push(match result {
+                                    TweenProgress::Done { surplus } => Some(surplus),
+                                    TweenProgress::Running => None,
+                                });
+                                (child + 1, surpluses)
+                            }
+                            _ => {
+                                *elapsed += *duration;
+                                (0, spare_surpluses.pop().unwrap_or_default())
+                            }
+                        };
+                        if next < tweens.len() {
+                            *state = StepState::Race {
+                                child: next,
+                                surpluses,
+                            };
+                            return descend(*duration);
+                        }
+                        let surplus = match surpluses.iter().flatten().max() {
+                            Some(surplus) => *surplus,
+                            // Nothing to wait for
+                            None if tweens.is_empty() => *duration,
+                            None => {
+                                surpluses.clear();
+                                spare_surpluses.push(surpluses);
+                                return StepAction::Return(TweenProgress::Running);
+                            }
+                        };
+                        (0, surplus, surpluses)
+                    }
+                };
+                // Move the other tweens back to the moment the first one completed
+                while let Some(&delegate_surplus) = surpluses.get(next) {
+                    let overshoot = surplus - delegate_surplus.unwrap_or_default();
+                    if !overshoot.is_zero() {
+                        *state = StepState::RaceBack {
+                            child: next,
+                            surplus,
+                            surpluses,
+                        };
+                        return StepAction::back(overshoot);
+                    }
+                    next += 1;
+                }
+                surpluses.clear();
+                spare_surpluses.push(surpluses);
+                *elapsed -= surplus;
+                if let Some(e) = completed_event {
+                    event_sender.send(e);
+                }
+                StepAction::Return(TweenProgress::Done { surplus })
+            }
+            Tween::Pause {
+                duration: tween_duration,
+                elapsed,
+                completed_event,
+                ..
+            } => {
+                *elapsed += *duration;
+                StepAction::Return(if elapsed >= tween_duration {
+                    let surplus = *elapsed - *tween_duration;
+                    *elapsed = *tween_duration;
+                    if let Some(e) = completed_event {
+                        event_sender.send(e);
+                    }
+                    TweenProgress::Done { surplus }
+                } else {
+                    TweenProgress::Running
+                })
+            }
+            Tween::Branch {
+                predicate,
+                chosen,
+                completed_event,
+                ..
+            } => {
+                if let Some(result) = child {
+                    if matches!(result, TweenProgress::Done { .. }) {
+                        if let Some(e) = completed_event {
+                            event_sender.send(e);
+                        }
+                    }
+                    return StepAction::Return(result);
+                }
+                // Without a target (skipping) there is nothing to test, so `if_false` is taken
+                chosen.get_or_insert_with(|| {
+                    target
+                        .as_deref()
+                        .is_some_and(|target| predicate.test(target.target))
+                });
+                descend(*duration)
+            }
+            Tween::RepeatWhile {
+                predicate,
+                tween,
+                iterating,
+                count,
+                completed_event,
+            } => {
+                match child {
+                    Some(TweenProgress::Done { surplus }) => {
+                        if *duration <= surplus {
+                            #[cfg(feature = "bevy")]
+                            bevy::log::error!(
+                                "Found repeat while tween with zero duration child (infinite loop)"
+                            );
+                            return StepAction::Return(TweenProgress::Running);
+                        }
+                        *duration = surplus;
+                        *iterating = false;
+                        *count += 1;
+                        tween.reset();
+                    }
+                    Some(TweenProgress::Running) => {
+                        return StepAction::Return(TweenProgress::Running);
+                    }
+                    None => (),
+                }
+                // Without a target (skipping) there is nothing to test, so the loop ends
+                if !*iterating
+                    && !target
+                        .as_deref()
+                        .is_some_and(|target| predicate.test(target.target))
+                {
+                    if let Some(e) = completed_event {
+                        event_sender.send(e);
+                    }
+                    return StepAction::Return(TweenProgress::Done { surplus: *duration });
+                }
+                *iterating = true;
+                descend(*duration)
+            }
+            Tween::Choose {
+                tweens,
+                weights,
+                chosen,
+                rng,
+                completed_event,
+            } => {
+                let result = match child {
+                    Some(result) => result,
+                    None => {
+                        if chosen.is_none() {
+                            *chosen = rng.pick_weighted(weights);
+                        }
+                        if chosen.is_some_and(|index| index < tweens.len()) {
+                            return descend(*duration);
+                        }
+                        TweenProgress::Done { surplus: *duration }
+                    }
+                };
+                if matches!(result, TweenProgress::Done { .. }) {
+                    if let Some(e) = completed_event {
+                        event_sender.send(e);
+                    }
+                }
+                StepAction::Return(result)
+            }
+            Tween::Speed {
+                factor,
+                completed_event,
+                ..
+            } => match (child, &*state) {
+                (Some(TweenProgress::Done { surplus }), StepState::Speed { factor }) => {
+                    if let Some(e) = completed_event {
+                        event_sender.send(e);
+                    }
+                    StepAction::Return(TweenProgress::Done {
+                        surplus: scale_duration(surplus, 1.0 / factor).min(*duration),
+                    })
+                }
+                (Some(result), _) => StepAction::Return(result),
+                (None, _) => {
+                    let factor = factor.get().max(0.0) as f64;
+                    *state = StepState::Speed { factor };
+                    descend(scale_duration(*duration, factor))
+                }
+            },
+            Tween::Offset {
+                offset,
+                started,
+                elapsed,
+                completed_event,
+                ..
+            } => {
+                let result = match (child, &*state) {
+                    (None, _) if *started => {
+                        *state = StepState::Offset { skipping: false };
+                        return descend(*duration);
+                    }
+                    (None, _) => {
+                        *started = true;
+                        *state = StepState::Offset { skipping: true };
+                        return StepAction::Descend {
+                            duration: *offset,
+                            silent: true,
+                            mode: StepMode::Forward,
+                        };
+                    }
+                    (Some(TweenProgress::Running), StepState::Offset { skipping: true }) => {
+                        *state = StepState::Offset { skipping: false };
+                        return descend(*duration);
+                    }
+                    (Some(_), StepState::Offset { skipping: true }) => {
+                        TweenProgress::Done { surplus: *duration }
+                    }
+                    (Some(result), _) => result,
+                };
+                *elapsed += *duration;
+                if let TweenProgress::Done { surplus } = result {
+                    *elapsed -= surplus;
+                    if let Some(e) = completed_event {
+                        event_sender.send(e);
+                    }
+                }
+                StepAction::Return(result)
+            }
+            Tween::Reversed {
+                started,
+                completed_event,
+                ..
+            } => {
+                let result = match (child, &*state) {
+                    (None, _) if !*started => {
+                        *started = true;
+                        *state = StepState::Seeking;
+                        return StepAction::end();
+                    }
+                    (Some(ended), StepState::Seeking) => {
+                        if !matches!(ended, TweenProgress::Done { .. }) {
+                            #[cfg(feature = "bevy")]
+                            bevy::log::error!("Found reversed tween which never ends");
+                        }
+                        *state = StepState::Start;
+                        return StepAction::back(*duration);
+                    }
+                    (None, _) => return StepAction::back(*duration),
+                    (Some(result), _) => result,
+                };
+                if matches!(result, TweenProgress::Done { .. }) {
+                    if let Some(e) = completed_event {
+                        event_sender.send(e);
+                    }
+                }
+                StepAction::Return(result)
+            }
+            Tween::Generate {
+                generator,
+                index,
+                current,
+                completed_event,
+            } => {
+                let mut zero_duration_steps = match &*state {
+                    StepState::Generate {
+                        zero_duration_steps,
+                    } => *zero_duration_steps,
+                    _ => 0,
+                };
+                match child {
+                    Some(TweenProgress::Done { surplus }) => {
+                        *index += 1;
+                        *current = None;
+                        if *duration <= surplus {
+                            zero_duration_steps += 1;
+                            if zero_duration_steps > MAX_ZERO_DURATION_STEPS {
+                                #[cfg(feature = "bevy")]
+                                bevy::log::error!(
+                                    "Found generated tweens with zero duration (infinite loop?)"
+                                );
+                                return StepAction::Return(TweenProgress::Running);
+                            }
+                        }
+                        *duration = surplus;
+                    }
+                    Some(TweenProgress::Running) => {
+                        return StepAction::Return(TweenProgress::Running);
+                    }
+                    None => (),
+                }
+                if current.is_none() {
+                    *current = generator.generate(*index).map(Box::new);
+                }
+                if current.is_none() {
+                    if let Some(e) = completed_event {
+                        event_sender.send(e);
+                    }
+                    return StepAction::Return(TweenProgress::Done { surplus: *duration });
+                }
+                *state = StepState::Generate {
+                    zero_duration_steps,
+                };
+                descend(*duration)
+            }
+            Tween::Alternate {
+                tweens,
+                times,
+                count,
+                completed_event,
+            } => {
+                let mut zero_duration_steps = match &*state {
+                    StepState::Alternate {
+                        zero_duration_steps,
+                    } => *zero_duration_steps,
+                    _ => 0,
+                };
+                match child {
+                    Some(TweenProgress::Done { surplus }) => {
+                        *count += 1;
+                        if *duration > surplus {
+                            zero_duration_steps = 0;
+                        } else if *times == RepeatTimes::Infinite {
+                            zero_duration_steps += 1;
+                            // A whole cycle without time passing never ends
+                            if zero_duration_steps > tweens.len() {
+                                #[cfg(feature = "bevy")]
+                                bevy::log::error!("Found infinite alternating tweens with zero duration (infinite loop)");
+                                return StepAction::Return(TweenProgress::Running);
+                            }
+                        }
+                        *duration = surplus;
+                    }
+                    Some(TweenProgress::Running) => {
+                        return StepAction::Return(TweenProgress::Running);
+                    }
+                    None => (),
+                }
+                let done =
+                    tweens.is_empty() || matches!(times, RepeatTimes::N(times) if *count >= *times);
+                if done {
+                    if let Some(e) = completed_event {
+                        event_sender.send(e);
+                    }
+                    return StepAction::Return(TweenProgress::Done { surplus: *duration });
+                }
+                if child.is_some() {
+                    // The next tween might still be at the end of the previous cycle
+                    let len = tweens.len();
+                    tweens[*count % len].reset();
+                }
+                *state = StepState::Alternate {
+                    zero_duration_steps,
+                };
+                descend(*duration)
+            }
+        }
+    }
+
+    /// The child which [`Tween::resume_step`] (or one of its siblings) asked to step.
+    fn step_child(&mut self, state: &StepState) -> &mut Tween<T, E> {
+        match (self, state) {
+            (Tween::Repeat { tween, .. }, _)
+            | (Tween::RepeatWhile { tween, .. }, _)
+            | (Tween::Speed { tween, .. }, _)
+            | (Tween::Offset { tween, .. }, _)
+            | (Tween::Reversed { tween, .. }, _) => tween,
+            (Tween::Sequence { index, tweens, .. }, _) => &mut tweens[*index],
+            (Tween::Alternate { tweens, count, .. }, _) => {
+                let len = tweens.len();
+                &mut tweens[*count % len]
+            }
+            (
+                Tween::Parallel { tweens, .. },
+                StepState::Parallel { child, .. }
+                | StepState::Race { child, .. }
+                | StepState::RaceBack { child, .. }
+                | StepState::ParallelBack { child, .. }
+                | StepState::ParallelEnd { child, .. }
+                | StepState::RaceEnd { child, .. },
+            ) => &mut tweens[*child],
+            (
+                Tween::Branch {
+                    if_true,
+                    if_false,
+                    chosen,
+                    ..
+                },
+                _,
+            ) => {
+                if *chosen == Some(true) {
+                    if_true
                 } else {
-                    TweenProgress::Running
+                    if_false
+                }
+            }
+            (
+                Tween::Choose {
+                    tweens,
+                    chosen: Some(index),
+                    ..
+                },
+                _,
+            ) => &mut tweens[*index],
+            (
+                Tween::Generate {
+                    current: Some(tween),
+                    ..
+                },
+                _,
+            ) => tween,
+            _ => unreachable!("tween has no child to step"),
+        }
+    }
+
+    /// Cheap stand-in for a tween which was moved out.
+    fn placeholder() -> Self {
+        Self::Pause {
+            duration: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            random_duration: None,
+            rng: TweenRng::new(0),
+            completed_event: None,
+        }
+    }
+
+    /// The direct children of this tween, in order.
+    fn children(&self) -> impl DoubleEndedIterator<Item = &Tween<T, E>> {
+        let (first, second, all): (Option<&Tween<T, E>>, Option<&Tween<T, E>>, &[Tween<T, E>]) =
+            match self {
+                Tween::Once { .. }
+                | Tween::Pause { .. }
+                | Tween::Generate { current: None, .. } => (None, None, &[]),
+                Tween::Repeat { tween, .. }
+                | Tween::RepeatWhile { tween, .. }
+                | Tween::Speed { tween, .. }
+                | Tween::Offset { tween, .. }
+                | Tween::Reversed { tween, .. }
+                | Tween::Generate {
+                    current: Some(tween),
+                    ..
+                } => (Some(tween.as_ref()), None, &[]),
+                Tween::Sequence { tweens, .. }
+                | Tween::Parallel { tweens, .. }
+                | Tween::Choose { tweens, .. }
+                | Tween::Alternate { tweens, .. } => (None, None, tweens),
+                Tween::Branch {
+                    if_true, if_false, ..
+                } => (Some(if_true.as_ref()), Some(if_false.as_ref()), &[]),
+            };
+        first.into_iter().chain(second).chain(all)
+    }
+
+    fn children_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut Tween<T, E>> {
+        let (first, second, all): (
+            Option<&mut Tween<T, E>>,
+            Option<&mut Tween<T, E>>,
+            &mut [Tween<T, E>],
+        ) = match self {
+            Tween::Once { .. } | Tween::Pause { .. } | Tween::Generate { current: None, .. } => {
+                (None, None, &mut [])
+            }
+            Tween::Repeat { tween, .. }
+            | Tween::RepeatWhile { tween, .. }
+            | Tween::Speed { tween, .. }
+            | Tween::Offset { tween, .. }
+            | Tween::Reversed { tween, .. }
+            | Tween::Generate {
+                current: Some(tween),
+                ..
+            } => (Some(tween.as_mut()), None, &mut []),
+            Tween::Sequence { tweens, .. }
+            | Tween::Parallel { tweens, .. }
+            | Tween::Choose { tweens, .. }
+            | Tween::Alternate { tweens, .. } => (None, None, tweens),
+            Tween::Branch {
+                if_true, if_false, ..
+            } => (Some(if_true.as_mut()), Some(if_false.as_mut()), &mut []),
+        };
+        first.into_iter().chain(second).chain(all)
+    }
+
+    /// Calls `visit` for this tween and all its children, parents first. Uses an explicit stack
+    /// instead of recursion, like all traversals of the tree.
+    fn for_each_mut(&mut self, mut visit: impl FnMut(&mut Tween<T, E>)) {
+        let mut pending = vec![self];
+        while let Some(tween) = pending.pop() {
+            visit(tween);
+            pending.extend(tween.children_mut().rev());
+        }
+    }
+
+    /// Summarizes the timing of this tween from the bottom up.
+    fn timing(&self) -> Timing {
+        if self.children().next().is_none() {
+            return self.node_timing(&[]);
+        }
+        // Children are summarized before their parent, which finds them on top of `results`
+        let mut pending = vec![(self, false)];
+        let mut results = Vec::new();
+        while let Some((tween, expanded)) = pending.pop() {
+            let children = tween.children().count();
+            if expanded || children == 0 {
+                let first = results.len() - children;
+                let timing = tween.node_timing(&results[first..]);
+                results.truncate(first);
+                results.push(timing);
+            } else {
+                pending.push((tween, true));
+                pending.extend(tween.children().rev().map(|child| (child, false)));
+            }
+        }
+        results[0]
+    }
+
+    /// Timing of this tween, given the timing of its children (see [`Tween::children`]).
+    fn node_timing(&self, children: &[Timing]) -> Timing {
+        let zero = Some(Duration::ZERO);
+        match self {
+            Tween::Once {
+                duration,
+                elapsed,
+                finished,
+                ..
+            } => Timing {
+                duration: Some(*duration),
+                elapsed: Some(*elapsed),
+                finished: *finished,
+            },
+            Tween::Pause {
+                duration, elapsed, ..
+            } => Timing {
+                duration: Some(*duration),
+                elapsed: Some(*elapsed),
+                finished: elapsed >= duration,
+            },
+            Tween::Repeat {
+                times,
+                count,
+                decay,
+                ..
+            } => {
+                let tween = children[0];
+                let duration = match *times {
+                    RepeatTimes::N(times) => tween
+                        .duration
+                        .map(|duration| scale_duration(duration, decay.iterations(times))),
+                    RepeatTimes::Infinite => None,
+                };
+                Timing {
+                    duration,
+                    elapsed: tween
+                        .duration
+                        .zip(tween.elapsed)
+                        .map(|(duration, elapsed)| {
+                            scale_duration(duration, decay.iterations(*count))
+                                + scale_duration(elapsed, decay.time_scale(*count))
+                        }),
+                    finished: matches!(times, RepeatTimes::N(times) if count >= times),
+                }
+            }
+            Tween::RepeatWhile { count, .. } => {
+                let tween = children[0];
+                Timing {
+                    duration: None,
+                    elapsed: tween
+                        .duration
+                        .zip(tween.elapsed)
+                        .map(|(duration, elapsed)| duration * *count as u32 + elapsed),
+                    finished: false,
+                }
+            }
+            Tween::Sequence { index, tweens, .. } => Timing {
+                duration: children
+                    .iter()
+                    .try_fold(Duration::ZERO, |acc, tween| Some(acc + tween.duration?)),
+                elapsed: children
+                    .iter()
+                    .take(*index + 1)
+                    .try_fold(Duration::ZERO, |acc, tween| Some(acc + tween.elapsed?)),
+                finished: *index >= tweens.len(),
+            },
+            Tween::Parallel {
+                policy: ParallelPolicy::WhenAll,
+                elapsed,
+                ..
+            } => Timing {
+                duration: children
+                    .iter()
+                    .try_fold(Duration::ZERO, |acc, tween| Some(acc.max(tween.duration?))),
+                elapsed: Some(*elapsed),
+                finished: children.iter().all(|tween| tween.finished),
+            },
+            Tween::Parallel {
+                policy: ParallelPolicy::WhenAny,
+                elapsed,
+                ..
+            } => Timing {
+                duration: if children.is_empty() {
+                    Some(Duration::ZERO)
+                } else {
+                    children.iter().filter_map(|tween| tween.duration).min()
+                },
+                elapsed: Some(*elapsed),
+                finished: children.is_empty() || children.iter().any(|tween| tween.finished),
+            },
+            Tween::Branch { chosen, .. } => match chosen {
+                Some(true) => children[0],
+                Some(false) => children[1],
+                None => Timing {
+                    duration: children[0]
+                        .duration
+                        .filter(|d| Some(*d) == children[1].duration),
+                    elapsed: zero,
+                    finished: false,
+                },
+            },
+            Tween::Choose { chosen, .. } => match chosen {
+                Some(index) => children.get(*index).copied().unwrap_or(Timing {
+                    duration: zero,
+                    elapsed: None,
+                    finished: true,
+                }),
+                None => Timing {
+                    duration: children
+                        .first()
+                        .map_or(zero, |tween| tween.duration)
+                        .filter(|d| children.iter().all(|tween| tween.duration == Some(*d))),
+                    elapsed: zero,
+                    finished: false,
+                },
+            },
+            Tween::Speed { factor, .. } => {
+                let factor = factor.get();
+                let scale = |duration: Option<Duration>| {
+                    duration
+                        .filter(|_| factor > 0.0)
+                        .map(|duration| scale_duration(duration, 1.0 / factor as f64))
+                };
+                Timing {
+                    duration: scale(children[0].duration),
+                    elapsed: scale(children[0].elapsed),
+                    finished: children[0].finished,
+                }
+            }
+            Tween::Offset {
+                offset, elapsed, ..
+            } => Timing {
+                duration: children[0].duration.map(|d| d.saturating_sub(*offset)),
+                elapsed: Some(*elapsed),
+                finished: children[0].finished,
+            },
+            Tween::Reversed { started, .. } => {
+                let tween = children[0];
+                Timing {
+                    duration: tween.duration,
+                    elapsed: if *started {
+                        tween
+                            .duration
+                            .zip(tween.elapsed)
+                            .and_then(|(duration, elapsed)| duration.checked_sub(elapsed))
+                    } else {
+                        zero
+                    },
+                    finished: *started && tween.elapsed == zero,
+                }
+            }
+            Tween::Generate { index, current, .. } => Timing {
+                duration: None,
+                elapsed: None,
+                finished: current.is_none() && *index > 0,
+            },
+            Tween::Alternate { times, count, .. } => {
+                let finished =
+                    children.is_empty() || matches!(times, RepeatTimes::N(times) if count >= times);
+                let duration = match times {
+                    RepeatTimes::N(times) => alternate_duration(children, *times),
+                    RepeatTimes::Infinite if children.is_empty() => zero,
+                    RepeatTimes::Infinite => None,
+                };
+                Timing {
+                    duration,
+                    elapsed: if finished {
+                        duration
+                    } else {
+                        alternate_duration(children, *count)
+                            .zip(children[count % children.len()].elapsed)
+                            .map(|(played, elapsed)| played + elapsed)
+                    },
+                    finished,
+                }
+            }
+        }
+    }
+
+    /// Resets the state of this tween, but not of its children.
+    fn reset_node(&mut self) {
+        match self {
+            Tween::Once {
+                elapsed, finished, ..
+            } => {
+                *elapsed = Duration::ZERO;
+                *finished = false;
+                self.reroll();
+            }
+            Tween::Pause { elapsed, .. } => {
+                *elapsed = Duration::ZERO;
+                self.reroll();
+            }
+            Tween::Repeat { count, .. } => *count = 0,
+            Tween::Sequence { index, .. } => *index = 0,
+            Tween::Parallel { elapsed, .. } => *elapsed = Duration::ZERO,
+            Tween::Branch { chosen, .. } => *chosen = None,
+            Tween::RepeatWhile {
+                iterating, count, ..
+            } => {
+                *iterating = false;
+                *count = 0;
+            }
+            Tween::Choose { chosen, .. } => *chosen = None,
+            Tween::Speed { .. } => (),
+            Tween::Offset {
+                started, elapsed, ..
+            } => {
+                *started = false;
+                *elapsed = Duration::ZERO;
+            }
+            Tween::Reversed { started, .. } => *started = false,
+            Tween::Generate { index, current, .. } => {
+                *index = 0;
+                *current = None;
+            }
+            Tween::Alternate { count, .. } => *count = 0,
+        }
+    }
+
+    /// Moves this tween backwards by `duration` (which is updated to the time left over) like
+    /// [`Tween::resume_step`]. `Done` is returned once the start is reached. No events are sent.
+    fn resume_step_back(
+        &mut self,
+        state: &mut StepState,
+        duration: &mut Duration,
+        target: Option<&mut TargetRef<'_, T>>,
+        child: Option<TweenProgress>,
+    ) -> StepAction {
+        let done = |surplus: Duration| StepAction::Return(TweenProgress::Done { surplus });
+        match self {
+            Tween::Once {
+                duration: tween_duration,
+                elapsed,
+                function,
+                applier,
+                finished,
+                ..
+            } => {
+                let result = rewind_elapsed(elapsed, *duration);
+                *finished = false;
+                if let Some(target) = target {
+                    let v = function.interpolate_f64(progress(*elapsed, *tween_duration));
+                    target.apply(applier.as_mut(), v);
+                }
+                StepAction::Return(result)
+            }
+            Tween::Pause { elapsed, .. } => StepAction::Return(rewind_elapsed(elapsed, *duration)),
+            Tween::Repeat { count, decay, .. } => {
+                if let (Some(result), StepState::Repeat { time_scale }) = (child, &*state) {
+                    let TweenProgress::Done { surplus } = result else {
+                        return StepAction::Return(TweenProgress::Running);
+                    };
+                    *duration = scale_duration(surplus, *time_scale).min(*duration);
+                    if *count == 0 {
+                        return done(*duration);
+                    }
+                    // Continue at the end of the previous iteration
+                    *count -= 1;
+                    *state = StepState::Seeking;
+                    return StepAction::end();
+                }
+                let time_scale = decay.time_scale(*count);
+                *state = StepState::Repeat { time_scale };
+                StepAction::back(scale_duration(*duration, 1.0 / time_scale))
+            }
+            Tween::RepeatWhile {
+                iterating, count, ..
+            } => {
+                if let (Some(result), StepState::Start) = (child, &*state) {
+                    let TweenProgress::Done { surplus } = result else {
+                        return StepAction::Return(TweenProgress::Running);
+                    };
+                    *duration = surplus;
+                    if *count == 0 {
+                        *iterating = false;
+                        return done(*duration);
+                    }
+                    *count -= 1;
+                    *iterating = true;
+                    *state = StepState::Seeking;
+                    return StepAction::end();
+                }
+                *state = StepState::Start;
+                StepAction::back(*duration)
+            }
+            Tween::Sequence { index, tweens, .. } => {
+                match child {
+                    Some(TweenProgress::Done { surplus }) => {
+                        *duration = surplus;
+                        if *index == 0 {
+                            return done(*duration);
+                        }
+                        *index -= 1;
+                    }
+                    Some(TweenProgress::Running) => {
+                        return StepAction::Return(TweenProgress::Running);
+                    }
+                    None => *index = (*index).min(tweens.len().saturating_sub(1)),
+                }
+                if tweens.is_empty() {
+                    return done(*duration);
+                }
+                StepAction::back(*duration)
+            }
+            Tween::Parallel {
+                tweens, elapsed, ..
+            } => {
+                let (mut next, rewound, settling) = match (&*state, child) {
+                    (
+                        StepState::ParallelBack {
+                            child,
+                            rewound,
+                            settling,
+                        },
+                        Some(_),
+                    ) => (child + 1, *rewound, *settling),
+                    _ => (0, elapsed.saturating_sub(*duration), None),
+                };
+                let surplus = match settling {
+                    Some(surplus) => surplus,
+                    None => {
+                        // Children which ended before `elapsed` only move once the rewind reaches
+                        // their end
+                        while let Some(tween) = tweens.get(next) {
+                            let end = tween.duration().unwrap_or(Duration::MAX);
+                            let amount = (*elapsed).min(end) - rewound.min(end);
+                            if !amount.is_zero() {
+                                *state = StepState::ParallelBack {
+                                    child: next,
+                                    rewound,
+                                    settling,
+                                };
+                                return StepAction::back(amount);
+                            }
+                            next += 1;
+                        }
+                        let TweenProgress::Done { surplus } = rewind_elapsed(elapsed, *duration)
+                        else {
+                            return StepAction::Return(TweenProgress::Running);
+                        };
+                        next = 0;
+                        surplus
+                    }
+                };
+                // Make sure all children are really at their start
+                if next < tweens.len() {
+                    *state = StepState::ParallelBack {
+                        child: next,
+                        rewound,
+                        settling: Some(surplus),
+                    };
+                    return StepAction::back(Duration::ZERO);
+                }
+                done(surplus)
+            }
+            Tween::Branch { chosen, .. } => match (child, chosen) {
+                (Some(result), _) => StepAction::Return(result),
+                (None, Some(_)) => StepAction::back(*duration),
+                (None, None) => done(*duration),
+            },
+            Tween::Choose { tweens, chosen, .. } => match child {
+                Some(result) => StepAction::Return(result),
+                None if chosen.is_some_and(|index| index < tweens.len()) => {
+                    StepAction::back(*duration)
+                }
+                None => done(*duration),
+            },
+            Tween::Speed { factor, .. } => match (child, &*state) {
+                (Some(TweenProgress::Done { surplus }), StepState::Speed { factor }) => {
+                    done(scale_duration(surplus, 1.0 / factor).min(*duration))
+                }
+                (Some(result), _) => StepAction::Return(result),
+                (None, _) => {
+                    let factor = factor.get().max(0.0) as f64;
+                    *state = StepState::Speed { factor };
+                    StepAction::back(scale_duration(*duration, factor))
+                }
+            },
+            Tween::Offset {
+                started, elapsed, ..
+            } => match child {
+                None if !*started => done(*duration),
+                // The child is never rewound before the offset
+                None => StepAction::back((*duration).min(*elapsed)),
+                Some(_) => StepAction::Return(rewind_elapsed(elapsed, *duration)),
+            },
+            Tween::Reversed { started, .. } => match child {
+                None if !*started => done(*duration),
+                None => StepAction::Descend {
+                    duration: *duration,
+                    silent: true,
+                    mode: StepMode::Forward,
+                },
+                Some(result) => StepAction::Return(result),
+            },
+            Tween::Generate { current, .. } => match (child, current) {
+                (Some(result), _) => StepAction::Return(result),
+                (None, Some(_)) => StepAction::back(*duration),
+                (None, None) => done(*duration),
+            },
+            Tween::Alternate {
+                tweens,
+                times,
+                count,
+                ..
+            } => {
+                if let (Some(result), StepState::Start) = (child, &*state) {
+                    let TweenProgress::Done { surplus } = result else {
+                        return StepAction::Return(TweenProgress::Running);
+                    };
+                    *duration = surplus;
+                    if *count == 0 {
+                        return done(*duration);
+                    }
+                    // Continue at the end of the previous iteration
+                    *count -= 1;
+                    *state = StepState::Seeking;
+                    return StepAction::end();
+                }
+                if tweens.is_empty() {
+                    return done(*duration);
+                }
+                let finished = matches!(times, RepeatTimes::N(times) if *count >= *times);
+                if child.is_none() && finished {
+                    // The last iteration played isn't the one `count` points to
+                    if *count == 0 {
+                        return done(*duration);
+                    }
+                    *count -= 1;
+                    *state = StepState::Seeking;
+                    return StepAction::end();
+                }
+                *state = StepState::Start;
+                StepAction::back(*duration)
+            }
+        }
+    }
+
+    /// Moves this tween to its end like [`Tween::resume_step`], applying the final values but
+    /// sending no events. Returns `Done` if it ended, or `Running` if it never ends.
+    fn resume_seek_end(
+        &mut self,
+        state: &mut StepState,
+        target: Option<&mut TargetRef<'_, T>>,
+        child: Option<TweenProgress>,
+    ) -> StepAction {
+        let child_ended = child.map(|result| matches!(result, TweenProgress::Done { .. }));
+        match self {
+            Tween::Once {
+                duration,
+                elapsed,
+                function,
+                applier,
+                finished,
+                ..
+            } => {
+                *elapsed = *duration;
+                *finished = true;
+                if let Some(target) = target {
+                    target.apply(applier.as_mut(), function.interpolate_f64(1.0));
+                }
+                StepAction::ended(true)
+            }
+            Tween::Pause {
+                duration, elapsed, ..
+            } => {
+                *elapsed = *duration;
+                StepAction::ended(true)
+            }
+            Tween::Repeat {
+                tween,
+                times,
+                count,
+                ..
+            } => {
+                let RepeatTimes::N(times) = *times else {
+                    return StepAction::ended(false);
+                };
+                match child_ended {
+                    None if *count < times => {
+                        if *count + 1 < times {
+                            tween.reset();
+                        }
+                        return StepAction::end();
+                    }
+                    Some(false) => return StepAction::ended(false),
+                    Some(true) => {
+                        *count = times;
+                        tween.reset();
+                    }
+                    None => (),
+                }
+                StepAction::ended(true)
+            }
+            Tween::RepeatWhile { .. } | Tween::Generate { .. } => StepAction::ended(false),
+            Tween::Alternate {
+                tweens,
+                times,
+                count,
+                ..
+            } => {
+                let RepeatTimes::N(times) = *times else {
+                    return StepAction::ended(tweens.is_empty());
+                };
+                let next = match child_ended {
+                    // Only the last cycle is seen at the end, the ones before are skipped
+                    None if *count + tweens.len() < times => {
+                        *count = times - tweens.len();
+                        true
+                    }
+                    None => false,
+                    Some(false) => return StepAction::ended(false),
+                    Some(true) => {
+                        *count += 1;
+                        true
+                    }
+                };
+                if *count >= times || tweens.is_empty() {
+                    return StepAction::ended(true);
+                }
+                if next {
+                    let len = tweens.len();
+                    tweens[*count % len].reset();
+                }
+                StepAction::end()
+            }
+            Tween::Sequence { index, tweens, .. } => {
+                match child_ended {
+                    Some(false) => return StepAction::ended(false),
+                    Some(true) => *index += 1,
+                    None => (),
+                }
+                if *index < tweens.len() {
+                    StepAction::end()
+                } else {
+                    StepAction::ended(true)
+                }
+            }
+            Tween::Parallel {
+                tweens,
+                policy: ParallelPolicy::WhenAny,
+                elapsed,
+                ..
+            } => {
+                let (next, end) = match (&*state, child) {
+                    (StepState::RaceEnd { child, end }, Some(_)) => (child + 1, *end),
+                    _ => match tweens.iter().filter_map(Tween::duration).min() {
+                        Some(end) => (0, end),
+                        None if tweens.is_empty() => (0, Duration::ZERO),
+                        None => return StepAction::ended(false),
+                    },
+                };
+                if next < tweens.len() {
+                    *state = StepState::RaceEnd { child: next, end };
+                    return StepAction::Descend {
+                        duration: end.saturating_sub(*elapsed),
+                        silent: true,
+                        mode: StepMode::Forward,
+                    };
+                }
+                *elapsed = end;
+                StepAction::ended(true)
+            }
+            Tween::Parallel {
+                tweens,
+                policy: ParallelPolicy::WhenAll,
+                elapsed,
+                ..
+            } => {
+                let (next, end) = match (&*state, child_ended) {
+                    (StepState::ParallelEnd { child, end }, Some(ended)) => (
+                        child + 1,
+                        end.zip(tweens[*child].duration().filter(|_| ended))
+                            .map(|(a, b)| a.max(b)),
+                    ),
+                    _ => (0, Some(Duration::ZERO)),
+                };
+                if next < tweens.len() {
+                    *state = StepState::ParallelEnd { child: next, end };
+                    return StepAction::end();
+                }
+                match end {
+                    Some(end) => {
+                        *elapsed = end;
+                        StepAction::ended(true)
+                    }
+                    None => StepAction::ended(false),
                 }
             }
-            Tween::Repeat {
+            Tween::Branch {
+                predicate, chosen, ..
+            } => match child {
+                Some(result) => StepAction::Return(result),
+                None => {
+                    chosen.get_or_insert_with(|| {
+                        target
+                            .as_deref()
+                            .is_some_and(|target| predicate.test(target.target))
+                    });
+                    StepAction::end()
+                }
+            },
+            Tween::Choose {
+                tweens,
+                weights,
+                chosen,
+                rng,
+                ..
+            } => match child {
+                Some(result) => StepAction::Return(result),
+                None => {
+                    if chosen.is_none() {
+                        *chosen = rng.pick_weighted(weights);
+                    }
+                    if chosen.is_some_and(|index| index < tweens.len()) {
+                        StepAction::end()
+                    } else {
+                        StepAction::ended(true)
+                    }
+                }
+            },
+            Tween::Speed { factor, .. } => match child {
+                Some(result) => StepAction::Return(result),
+                None if factor.get() > 0.0 => StepAction::end(),
+                None => StepAction::ended(false),
+            },
+            Tween::Offset {
+                offset,
                 tween,
-                times,
-                count,
+                started,
+                elapsed,
                 ..
-            } => loop {
-                let done = match times {
-                    RepeatTimes::N(amount) => count >= amount,
-                    RepeatTimes::Infinite => false,
-                };
-                if done {
-                    return TweenProgress::Done { surplus: duration };
+            } => match child_ended {
+                None => {
+                    *started = true;
+                    StepAction::end()
                 }
-                let delegate_result = tween.skip(duration);
-                match delegate_result {
-                    TweenProgress::Done { surplus } => {
-                        *count += 1;
-                        if duration <= surplus && *times == RepeatTimes::Infinite {
-                            #[cfg(feature = "bevy")]
-                            bevy::log::error!("Found infinite repeating tween with zero duration child (infinite loop)");
-                            return TweenProgress::Running;
-                        }
-                        duration = surplus;
-                        tween.reset();
-                    }
-                    TweenProgress::Running => {
-                        break TweenProgress::Running;
+                Some(false) => StepAction::ended(false),
+                Some(true) => {
+                    *elapsed = tween
+                        .duration()
+                        .map_or(Duration::ZERO, |end| end.saturating_sub(*offset));
+                    StepAction::ended(true)
+                }
+            },
+            Tween::Reversed { tween, started, .. } => match child {
+                None => {
+                    *started = true;
+                    tween.reset();
+                    StepAction::Descend {
+                        duration: Duration::ZERO,
+                        silent: true,
+                        mode: StepMode::Forward,
                     }
                 }
+                Some(_) => StepAction::ended(true),
             },
-            Tween::Sequence { index, tweens, .. } => {
-                while let Some(tween) = tweens.get_mut(*index) {
-                    let delegate_result = tween.skip(duration);
-                    match delegate_result {
-                        TweenProgress::Done { surplus } => {
-                            *index += 1;
-                            duration = surplus;
-                        }
-                        TweenProgress::Running => {
-                            return TweenProgress::Running;
-                        }
+        }
+    }
+
+    /// Whether this tween played to its end. Repeats while a predicate holds only know that once
+    /// they are advanced again, so they are never reported as finished.
+    pub fn is_finished(&self) -> bool {
+        self.timing().finished
+    }
+
+    /// Time played so far, `None` if it can't be known (ie. for generated tweens).
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.timing().elapsed
+    }
+
+    /// Rescales all durations in this tween proportionally, so it takes `total` in the end. Has no
+    /// effect on tweens with unknown or zero duration.
+    pub fn with_total_duration(mut self, total: Duration) -> Self {
+        if let Some(duration) = self.duration().filter(|duration| !duration.is_zero()) {
+            self.scale_time(total.as_secs_f64() / duration.as_secs_f64());
+        }
+        self
+    }
+
+    /// Multiplies all durations (and the time already elapsed) by `factor`. Generated tweens are
+    /// not affected.
+    fn scale_time(&mut self, factor: f64) {
+        let scale = |duration: &mut Duration| *duration = scale_duration(*duration, factor);
+        let scale_random = |random: &mut Option<Box<Rand<Duration>>>| {
+            if let Some(random) = random {
+                scale(&mut random.min);
+                scale(&mut random.max);
+            }
+        };
+        let mut pending = vec![self];
+        while let Some(tween) = pending.pop() {
+            match tween {
+                Tween::Once {
+                    duration,
+                    elapsed,
+                    random_duration,
+                    markers,
+                    ..
+                } => {
+                    scale(duration);
+                    scale(elapsed);
+                    scale_random(random_duration);
+                    markers.iter_mut().for_each(|(at, _)| scale(at));
+                }
+                Tween::Pause {
+                    duration,
+                    elapsed,
+                    random_duration,
+                    ..
+                } => {
+                    scale(duration);
+                    scale(elapsed);
+                    scale_random(random_duration);
+                }
+                Tween::Parallel { elapsed, .. } => scale(elapsed),
+                Tween::Offset {
+                    offset, elapsed, ..
+                } => {
+                    scale(offset);
+                    scale(elapsed);
+                }
+                Tween::Generate { .. } => continue,
+                _ => (),
+            }
+            pending.extend(tween.children_mut());
+        }
+    }
+
+    /// Progress of the whole tween from `0.0` to `1.0` (including all repetitions) and the time
+    /// remaining, `None` if the tween never ends or its duration is unknown.
+    pub fn overall_progress(&self) -> Option<(f32, Duration)> {
+        let Timing {
+            duration: Some(duration),
+            elapsed: Some(elapsed),
+            ..
+        } = self.timing()
+        else {
+            return None;
+        };
+        if duration.is_zero() {
+            return Some((1.0, Duration::ZERO));
+        }
+        Some((
+            (elapsed.as_secs_f64() / duration.as_secs_f64()).min(1.0) as f32,
+            duration.saturating_sub(elapsed),
+        ))
+    }
+
+    /// The total duration of this tween, or `None` if it never ends or can't be known in advance
+    /// (ie. undecided branches with different durations).
+    pub fn duration(&self) -> Option<Duration> {
+        self.timing().duration
+    }
+
+    /// Resets the whole tree to its initial state, including parts that never started. Used to
+    /// reuse finished tweens, ie. from a [`TweenPool`](crate::TweenPool).
+    pub fn reset_deep(&mut self) {
+        self.for_each_mut(Tween::reset_node);
+    }
+
+    pub(crate) fn reset(&mut self) {
+        let mut pending = vec![self];
+        while let Some(tween) = pending.pop() {
+            // Only children which were started are reset, as this runs on every loop of a repeat.
+            // The children of a sequence after `index` never started or were rewound to their start
+            // already, the one at `index` might be in progress.
+            match tween {
+                Tween::Sequence { index, tweens, .. } => {
+                    pending.extend(tweens.iter_mut().take(*index + 1));
+                    *index = 0;
+                    continue;
+                }
+                Tween::Branch {
+                    if_true,
+                    if_false,
+                    chosen,
+                    ..
+                } => {
+                    match chosen.take() {
+                        Some(true) => pending.push(if_true),
+                        Some(false) => pending.push(if_false),
+                        None => (),
                     }
+                    continue;
                 }
-                TweenProgress::Done { surplus: duration }
-            }
-            Tween::Parallel { tweens, .. } => {
-                tweens
-                    .iter_mut()
-                    .fold(TweenProgress::Done { surplus: duration }, |acc, tween| {
-                        let delegate_result = tween.skip(duration);
-                        if let (
-                            TweenProgress::Done {
-                                surplus: acc_surplus,
-                            },
-                            TweenProgress::Done {
-                                surplus: delegate_surplus,
-                            },
-                        ) = (acc, delegate_result)
-                        {
-                            TweenProgress::Done {
-                                surplus: acc_surplus.min(delegate_surplus),
-                            }
-                        } else {
-                            TweenProgress::Running
-                        }
-                    })
+                Tween::Choose { tweens, chosen, .. } => {
+                    pending.extend(chosen.take().and_then(|index| tweens.get_mut(index)));
+                    continue;
+                }
+                Tween::Alternate { tweens, count, .. } => {
+                    pending.extend(tweens.iter_mut().take(*count + 1));
+                    *count = 0;
+                    continue;
+                }
+                _ => tween.reset_node(),
             }
-            Tween::Pause {
-                duration: tween_duration,
-                elapsed,
-                ..
-            } => {
-                *elapsed += duration;
-                if elapsed >= tween_duration {
-                    let surplus = *elapsed - *tween_duration;
-                    *elapsed = *tween_duration;
-                    TweenProgress::Done { surplus }
-                } else {
-                    TweenProgress::Running
+            pending.extend(tween.children_mut());
+        }
+    }
+}
+
+/// Writes the value as it is into the target, for tests.
+#[cfg(test)]
+#[derive(Clone)]
+pub(crate) struct SetValue;
+
+#[cfg(test)]
+impl TweenApplier<f32> for SetValue {
+    fn apply(&mut self, target: &mut f32, value: f32) {
+        *target = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl TweenApplier<f32> for f32 {
+        fn apply(&mut self, target: &mut f32, value: f32) {
+            *target = *self * value * 2.0;
+        }
+    }
+
+    /// Applies to a single element of the target
+    #[derive(Clone)]
+    struct Element(usize);
+
+    impl TweenApplier<[f32; 2]> for Element {
+        fn apply(&mut self, target: &mut [f32; 2], value: f32) {
+            target[self.0] = value;
+        }
+    }
+
+    #[test]
+    fn tween_once() {
+        let mut tween = Tween::new(Duration::from_secs(2), Lerp, 1.0_f32);
+        tween.skip(Duration::from_millis(3));
+
+        let mut value = 0.0;
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1));
+
+        let Tween::Once { elapsed, .. } = tween else {
+            panic!()
+        };
+        assert_eq!(elapsed, Duration::from_millis(4));
+        assert_eq!(value, 0.004);
+    }
+
+    #[test]
+    fn tween_repeat() {
+        let mut tween = Tween::repeat(
+            RepeatTimes::N(2),
+            Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
+        );
+
+        let mut value = 0.0;
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1500));
+        assert_eq!(progress, TweenProgress::Running);
+
+        let Tween::Repeat {
+            count,
+            tween: ref delegate,
+            ..
+        } = tween
+        else {
+            panic!()
+        };
+        assert_eq!(count, 1);
+        let Tween::Once { elapsed, .. } = **delegate else {
+            panic!();
+        };
+        assert_eq!(elapsed, Duration::from_millis(500));
+        assert_eq!(value, 2.0);
+
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(505));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(5)
+            }
+        );
+        let Tween::Repeat {
+            count,
+            tween: delegate,
+            ..
+        } = tween
+        else {
+            panic!()
+        };
+        assert_eq!(count, 2);
+        let Tween::Once { elapsed, .. } = *delegate else {
+            panic!();
+        };
+        assert_eq!(elapsed, Duration::from_millis(0));
+        assert_eq!(value, 4.0);
+    }
+
+    #[test]
+    fn tween_sequence() {
+        let mut tween = Tween::sequence(vec![
+            Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
+            Tween::repeat(
+                RepeatTimes::N(2),
+                Tween::new(Duration::from_secs(1), Lerp, 4.0_f32),
+            ),
+        ]);
+
+        let mut value = 0.0;
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(500));
+        assert_eq!(progress, TweenProgress::Running);
+        assert_eq!(value, 2.0);
+
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(progress, TweenProgress::Running);
+        assert_eq!(value, 4.0);
+
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(2000));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(500)
+            }
+        );
+        assert_eq!(value, 8.0);
+    }
+
+    #[test]
+    fn tween_sequence_with_endless_loop() {
+        let mut tween = Tween::sequence(vec![
+            Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
+            Tween::repeat(
+                RepeatTimes::Infinite,
+                Tween::new(Duration::from_secs(1), Lerp, 5.0_f32),
+            ),
+        ]);
+
+        let mut value = 0.0;
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(20000));
+        assert_eq!(progress, TweenProgress::Running);
+        assert_eq!(value, 0.0);
+    }
+
+    #[test]
+    fn tween_parallel() {
+        let mut tween = Tween::parallel(vec![
+            Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
+            Tween::repeat(
+                RepeatTimes::N(2),
+                Tween::new(Duration::from_secs(1), Lerp, 4.0_f32),
+            ),
+        ]);
+
+        let mut value = 0.0;
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(progress, TweenProgress::Running);
+
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::ZERO
+            }
+        );
+    }
+
+    #[test]
+    fn tween_branch() {
+        let mut tween = Tween::repeat(
+            RepeatTimes::N(2),
+            Tween::branch(
+                |value: &f32| *value > 1.0,
+                Tween::new(Duration::from_secs(1), Lerp, 1.0_f32),
+                Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
+            ),
+        );
+
+        let mut value = 0.0;
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(500));
+        assert_eq!(value, 2.0);
+
+        // The decision is kept while the branch is running
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(250));
+        assert_eq!(value, 3.0);
+
+        // The repeat resets the branch, which then takes the other path
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(750));
+        assert_eq!(value, 1.0);
+    }
+
+    #[test]
+    fn tween_repeat_while() {
+        #[derive(Clone)]
+        struct Counter;
+
+        impl TweenApplier<(u32, f32)> for Counter {
+            fn apply(&mut self, target: &mut (u32, f32), value: f32) {
+                if value >= 1.0 {
+                    target.0 += 1;
                 }
+                target.1 = value;
+            }
+        }
+
+        let mut tween = Tween::repeat_while(
+            |target: &(u32, f32)| target.0 < 3,
+            Tween::new(Duration::from_secs(1), Lerp, Counter),
+        );
+
+        let mut target = (0, 0.0);
+        let progress = tween.advance(&mut target, &mut NoEvent, Duration::from_millis(2500));
+        assert_eq!(progress, TweenProgress::Running);
+        assert_eq!(target, (2, 0.5));
+
+        let progress = tween.advance(&mut target, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(500)
             }
+        );
+        assert_eq!(target, (3, 1.0));
+    }
+
+    #[test]
+    fn tween_choose() {
+        let mut tween = Tween::repeat(
+            RepeatTimes::Infinite,
+            Tween::choose_weighted([
+                (1.0, Tween::new(Duration::from_secs(1), Lerp, 1.0_f32)),
+                (0.0, Tween::new(Duration::from_secs(1), Lerp, 2.0_f32)),
+                (1.0, Tween::new(Duration::from_secs(1), Lerp, 3.0_f32)),
+            ]),
+        );
+
+        let mut seen = Vec::new();
+        let mut value = 0.0;
+        for _ in 0..50 {
+            tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1500));
+            seen.push(value);
         }
+        assert!(seen.contains(&1.0));
+        assert!(seen.contains(&3.0));
+        assert!(!seen.contains(&2.0));
     }
 
-    pub fn advance<'a, ES: EventSender<E>>(
-        &'a mut self,
-        target: &'a mut T,
-        event_sender: &'a mut ES,
-        mut duration: Duration,
-    ) -> TweenProgress {
-        match self {
-            Tween::Once {
-                duration: tween_duration,
-                elapsed,
-                function,
-                applier,
-                completed_event,
-            } => {
-                *elapsed += duration;
-                let result = if elapsed >= tween_duration {
-                    let surplus = *elapsed - *tween_duration;
-                    *elapsed = *tween_duration;
-                    if let Some(e) = completed_event {
-                        event_sender.send(e);
-                    }
-                    TweenProgress::Done { surplus }
-                } else {
-                    TweenProgress::Running
-                };
-                let v = function.interpolate(elapsed.as_secs_f32() / tween_duration.as_secs_f32());
-                applier.apply(target, v);
-                result
+    #[test]
+    fn tween_random_duration_seeded() {
+        let make_tween = || {
+            let mut tween = Tween::repeat(
+                RepeatTimes::N(10),
+                Tween::new(Duration::from_secs(1), Lerp, 1.0_f32).with_random_duration(Rand::new(
+                    Duration::from_secs(1),
+                    Duration::from_secs(2),
+                )),
+            );
+            tween.seed(42);
+            tween
+        };
+        let mut a = make_tween();
+        let mut b = make_tween();
+
+        let mut value_a = 0.0;
+        let mut value_b = 0.0;
+        for _ in 0..20 {
+            a.advance(&mut value_a, &mut NoEvent, Duration::from_millis(700));
+            b.advance(&mut value_b, &mut NoEvent, Duration::from_millis(700));
+            assert_eq!(value_a, value_b);
+        }
+        let progress = a.skip(Duration::from_secs(20));
+        assert!(matches!(progress, TweenProgress::Done { .. }));
+    }
+
+    #[test]
+    fn tween_repeat_decay() {
+        let mut tween = Tween::repeat(
+            RepeatTimes::N(3),
+            Tween::new(Duration::from_secs(1), Lerp, 1.0_f32),
+        )
+        .with_decay(0.5);
+
+        let mut value = 0.0;
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1250));
+        // Second iteration: half the duration, the full values
+        assert_eq!(value, 1.0);
+
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(750));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(250)
             }
-            Tween::Repeat {
-                tween,
-                times,
-                count,
-                completed_event,
-            } => loop {
-                let done = match times {
-                    RepeatTimes::N(amount) => count >= amount,
-                    RepeatTimes::Infinite => false,
-                };
-                if done {
-                    if let Some(e) = completed_event {
-                        event_sender.send(e);
-                    }
-                    return TweenProgress::Done { surplus: duration };
-                }
-                let delegate_result = tween.advance(target, event_sender, duration);
-                match delegate_result {
-                    TweenProgress::Done { surplus } => {
-                        *count += 1;
-                        if duration <= surplus && *times == RepeatTimes::Infinite {
-                            #[cfg(feature = "bevy")]
-                            bevy::log::error!("Found infinite repeating tween with zero duration child (infinite loop)");
-                            return TweenProgress::Running;
-                        }
-                        duration = surplus;
-                        tween.reset();
-                    }
-                    TweenProgress::Running => {
-                        break TweenProgress::Running;
-                    }
-                }
-            },
-            Tween::Sequence {
-                index,
-                tweens,
-                completed_event,
-            } => {
-                while let Some(tween) = tweens.get_mut(*index) {
-                    let delegate_result = tween.advance(target, event_sender, duration);
-                    match delegate_result {
-                        TweenProgress::Done { surplus } => {
-                            *index += 1;
-                            duration = surplus;
-                        }
-                        TweenProgress::Running => {
-                            return TweenProgress::Running;
-                        }
-                    }
-                }
-                if let Some(e) = completed_event {
-                    event_sender.send(e);
-                }
-                TweenProgress::Done { surplus: duration }
+        );
+        assert_eq!(value, 2.0);
+    }
+
+    #[test]
+    fn tween_speed() {
+        let speed = TweenSpeed::new(2.0);
+        let mut tween = Tween::sequence([
+            Tween::speed(
+                speed.clone(),
+                Tween::new(Duration::from_secs(2), Lerp, 1.0_f32),
+            ),
+            Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
+        ]);
+
+        let mut value = 0.0;
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(500));
+        assert_eq!(value, 1.0);
+
+        speed.set(0.5);
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(value, 1.5);
+
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(2500));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(500)
             }
-            Tween::Parallel {
-                tweens,
-                completed_event,
-            } => {
-                let result = tweens.iter_mut().fold(
-                    TweenProgress::Done { surplus: duration },
-                    |acc, tween| {
-                        let delegate_result = tween.advance(target, event_sender, duration);
-                        if let (
-                            TweenProgress::Done {
-                                surplus: acc_surplus,
-                            },
-                            TweenProgress::Done {
-                                surplus: delegate_surplus,
-                            },
-                        ) = (acc, delegate_result)
-                        {
-                            TweenProgress::Done {
-                                surplus: acc_surplus.min(delegate_surplus),
-                            }
-                        } else {
-                            TweenProgress::Running
-                        }
-                    },
-                );
-                if matches!(result, TweenProgress::Done { .. }) {
-                    if let Some(e) = completed_event {
-                        event_sender.send(e);
-                    }
-                }
-                result
+        );
+    }
+
+    #[test]
+    fn tween_offset() {
+        let mut tween = Tween::offset(
+            Duration::from_millis(1500),
+            Tween::repeat(
+                RepeatTimes::N(2),
+                Tween::new(Duration::from_secs(1), Lerp, 1.0_f32),
+            ),
+        );
+
+        let mut value = 0.0;
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(250));
+        assert_eq!(progress, TweenProgress::Running);
+        assert_eq!(value, 1.5);
+
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(500));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(250)
             }
-            Tween::Pause {
-                duration: tween_duration,
-                elapsed,
-                completed_event,
-            } => {
-                *elapsed += duration;
-                if elapsed >= tween_duration {
-                    let surplus = *elapsed - *tween_duration;
-                    *elapsed = *tween_duration;
-                    if let Some(e) = completed_event {
-                        event_sender.send(e);
-                    }
-                    TweenProgress::Done { surplus }
-                } else {
-                    TweenProgress::Running
-                }
+        );
+    }
+
+    #[test]
+    fn tween_reversed() {
+        let mut tween = Tween::reversed(Tween::sequence([
+            Tween::new(Duration::from_secs(1), Lerp, 1.0_f32),
+            Tween::repeat(
+                RepeatTimes::N(2),
+                Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
+            ),
+        ]));
+        assert_eq!(tween.duration(), Some(Duration::from_secs(3)));
+
+        let mut value = 0.0;
+        tween.advance(&mut value, &mut NoEvent, Duration::ZERO);
+        assert_eq!(value, 4.0);
+
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1500));
+        assert_eq!(value, 2.0);
+
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(value, 1.0);
+
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(500)
+            }
+        );
+        assert_eq!(value, 0.0);
+    }
+
+    #[test]
+    fn tween_reversed_parallel() {
+        let mut tween = Tween::reversed(Tween::parallel([
+            Tween::new(Duration::from_secs(1), Lerp, Element(0)),
+            Tween::new(Duration::from_secs(2), Lerp, Element(1)),
+        ]));
+
+        let mut value = [0.0; 2];
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(500));
+        // The shorter tween only starts moving backwards once its end is reached
+        assert_eq!(value, [1.0, 0.75]);
+
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(value, [0.5, 0.25]);
+
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(500)
+            }
+        );
+        assert_eq!(value, [0.0, 0.0]);
+    }
+
+    #[test]
+    fn tween_race() {
+        let mut tween = Tween::sequence([
+            Tween::race([
+                Tween::new(Duration::from_secs(2), Lerp, Element(0)),
+                Tween::new(Duration::from_secs(1), Lerp, Element(1)),
+            ]),
+            Tween::pause(Duration::from_secs(1)),
+        ]);
+        assert_eq!(tween.duration(), Some(Duration::from_secs(2)));
+
+        let mut value = [0.0; 2];
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1500));
+        assert_eq!(progress, TweenProgress::Running);
+        // The longer tween is stopped when the shorter one completes
+        assert_eq!(value, [0.5, 1.0]);
+
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(500)
+            }
+        );
+    }
+
+    #[test]
+    fn empty_race_completes() {
+        let mut tween = Tween::<[f32; 2], NoEvent>::race(vec![]);
+        assert_eq!(tween.duration(), Some(Duration::ZERO));
+
+        let mut value = [0.0; 2];
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(500));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(500)
             }
+        );
+        assert_eq!(tween.elapsed(), Some(Duration::ZERO));
+        tween.reset();
+        assert!(tween.finish(&mut value, &mut NoEvent));
+    }
+
+    #[test]
+    fn race_reuses_surpluses() {
+        let mut tween = Tween::repeat(
+            RepeatTimes::Infinite,
+            Tween::race([
+                Tween::new(Duration::from_secs(2), Lerp, Element(0)),
+                Tween::new(Duration::from_secs(1), Lerp, Element(1)),
+            ]),
+        );
+        let mut stack = TweenStack::default();
+
+        let mut value = [0.0; 2];
+        for _ in 0..3 {
+            tween.advance_with(
+                &mut stack,
+                &mut value,
+                &mut NoEvent,
+                Duration::from_millis(1500),
+            );
+            assert_eq!(stack.surpluses.len(), 1);
+            assert!(stack.surpluses[0].is_empty());
         }
+        assert_eq!(value, [0.25, 0.5]);
+    }
+
+    #[test]
+    fn sequence_reset_restarts_steps_in_progress() {
+        let mut tween = Tween::sequence([
+            Tween::new(Duration::from_secs(1), Lerp, Element(0)),
+            Tween::new(Duration::from_secs(1), Lerp, Element(1)),
+            Tween::new(Duration::from_secs(1), Lerp, Element(1)),
+        ]);
+        let mut value = [0.0; 2];
+
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1500));
+        tween.reset();
+        assert_eq!(tween.elapsed(), Some(Duration::ZERO));
+        assert!(tween
+            .children()
+            .all(|child| child.elapsed() == Some(Duration::ZERO)));
+
+        tween.advance(&mut value, &mut NoEvent, Duration::from_secs(3));
+        tween.rewind(&mut value, Duration::from_millis(1500));
+        tween.reset();
+        assert!(tween
+            .children()
+            .all(|child| child.elapsed() == Some(Duration::ZERO)));
+        assert!(!tween.children().any(Tween::is_finished));
+    }
+
+    #[test]
+    fn tween_push() {
+        let mut tween = Tween::new(Duration::from_secs(1), Lerp, 1.0_f32);
+
+        let mut value = 0.0;
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1500));
+        assert!(matches!(progress, TweenProgress::Done { .. }));
+
+        tween.push(Tween::new(Duration::from_secs(1), Lerp, 2.0_f32));
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(500));
+        assert_eq!(progress, TweenProgress::Running);
+        assert_eq!(value, 2.0);
+    }
+
+    #[test]
+    fn push_onto_completed_sequence_completes_again() {
+        let step = || Tween::new_with_event(Duration::from_secs(1), Lerp, 1.0_f32, "step");
+        let mut tween = Tween::sequence([step()]).with_completed("done");
+
+        let mut value = 0.0;
+        let mut events = Vec::new();
+        tween.advance(&mut value, &mut events, Duration::from_secs(1));
+        assert_eq!(events, ["step", "done"]);
+
+        tween.push(step());
+        assert!(!tween.is_finished());
+        tween.advance(&mut value, &mut events, Duration::from_secs(1));
+        assert_eq!(events, ["step", "done", "step", "done"]);
+    }
+
+    #[test]
+    fn tween_generate() {
+        let mut tween = Tween::generate(|index| {
+            (index < 3).then(|| Tween::new(Duration::from_secs(1), Lerp, index as f32))
+        });
+
+        let mut value = 0.0;
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(2500));
+        assert_eq!(progress, TweenProgress::Running);
+        assert_eq!(value, 2.0);
+
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_millis(500)
+            }
+        );
+        assert_eq!(value, 4.0);
+    }
+
+    #[test]
+    fn tween_skip_applying() {
+        let mut tween = Tween::sequence([
+            Tween::new_with_event(Duration::from_secs(1), Lerp, Element(0), 1),
+            Tween::repeat(
+                RepeatTimes::N(3),
+                Tween::new_with_event(Duration::from_secs(1), Lerp, Element(1), 2),
+            ),
+        ]);
+
+        let mut values = [0.0; 2];
+        let mut events = Vec::new();
+        let progress = tween.skip_applying(&mut values, &mut events, Duration::from_millis(3500));
+        assert_eq!(progress, TweenProgress::Running);
+        // The iteration in progress isn't applied yet
+        assert_eq!(values, [1.0, 1.0]);
+        assert_eq!(events, [1, 2, 2]);
+
+        values[1] = 0.0;
+        tween.advance(&mut values, &mut events, Duration::from_millis(250));
+        assert_eq!(values, [1.0, 0.75]);
     }
 
-    fn reset(&mut self) {
-        match self {
-            Tween::Once { elapsed, .. } => {
-                *elapsed = Duration::ZERO;
-            }
-            Tween::Repeat { tween, count, .. } => {
-                *count = 0;
-                tween.reset();
-            }
-            Tween::Sequence { index, tweens, .. } => {
-                for tween in tweens.iter_mut().take(*index) {
-                    tween.reset();
-                }
-                *index = 0;
+    #[test]
+    fn tween_alternate() {
+        let step = |event| Tween::new_with_event(Duration::from_secs(1), Lerp, Element(0), event);
+        let mut tween = Tween::alternate(RepeatTimes::N(3), [step('l'), step('r')]);
+        assert_eq!(tween.duration(), Some(Duration::from_secs(3)));
+
+        let mut values = [0.0; 2];
+        let mut events = Vec::new();
+        let progress = tween.advance(&mut values, &mut events, Duration::from_secs(4));
+        assert_eq!(events, ['l', 'r', 'l']);
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_secs(1)
             }
-            Tween::Parallel { tweens, .. } => {
-                for tween in tweens.iter_mut() {
-                    tween.reset();
-                }
+        );
+        assert!(tween.is_finished());
+        assert_eq!(tween.elapsed(), Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn tween_alternate_rewind_and_finish() {
+        let step =
+            |element| Tween::<_, NoEvent>::new(Duration::from_secs(1), Lerp, Element(element));
+        let mut tween = Tween::alternate(RepeatTimes::N(3), [step(0), step(1)]);
+
+        let mut values = [0.0; 2];
+        tween.advance(&mut values, &mut NoEvent, Duration::from_millis(2500));
+        assert_eq!(values, [0.5, 1.0]);
+        assert_eq!(
+            tween.overall_progress(),
+            Some((2.5 / 3.0, Duration::from_millis(500)))
+        );
+
+        tween.rewind(&mut values, Duration::from_secs(1));
+        assert_eq!(values, [0.0, 0.5]);
+        assert_eq!(tween.elapsed(), Some(Duration::from_millis(1500)));
+
+        assert!(tween.finish(&mut values, &mut NoEvent));
+        assert_eq!(values, [1.0, 1.0]);
+        assert!(tween.is_finished());
+
+        tween.rewind(&mut values, Duration::from_millis(500));
+        assert_eq!(values, [0.5, 1.0]);
+    }
+
+    #[test]
+    fn tween_alternate_infinite() {
+        let step =
+            |element| Tween::<_, NoEvent>::new(Duration::from_secs(1), Lerp, Element(element));
+        let mut tween = Tween::alternate(RepeatTimes::Infinite, [step(0), step(1)]);
+
+        let mut values = [0.0; 2];
+        let progress = tween.advance(&mut values, &mut NoEvent, Duration::from_millis(10_500));
+        assert_eq!(progress, TweenProgress::Running);
+        assert_eq!(values, [0.5, 1.0]);
+        assert_eq!(tween.duration(), None);
+        assert_eq!(tween.elapsed(), Some(Duration::from_millis(10_500)));
+        let Tween::Alternate { count, .. } = tween else {
+            panic!()
+        };
+        assert_eq!(count, 10);
+
+        // A cycle taking no time never ends
+        let mut tween = Tween::<[f32; 2], NoEvent>::alternate(
+            RepeatTimes::Infinite,
+            [Tween::pause(Duration::ZERO), Tween::pause(Duration::ZERO)],
+        );
+        let progress = tween.advance(&mut values, &mut NoEvent, Duration::from_secs(1));
+        assert_eq!(progress, TweenProgress::Running);
+    }
+
+    #[test]
+    fn builtin_interpolators_are_inline() {
+        #[derive(Clone)]
+        struct Half;
+
+        impl Interpolator for Half {
+            fn interpolate(&self, position: f32) -> f32 {
+                position / 2.0
             }
-            Tween::Pause { elapsed, .. } => *elapsed = Duration::ZERO,
         }
+
+        let function = |tween: Tween<f32, NoEvent>| match tween {
+            Tween::Once { function, .. } => function,
+            _ => unreachable!(),
+        };
+        let second = Duration::from_secs(1);
+        assert!(matches!(
+            function(Tween::new(second, EaseFunction::QuadraticIn, 1.0)),
+            Ease::Function(EaseFunction::QuadraticIn)
+        ));
+        assert!(matches!(
+            function(Tween::new(second, Lerp, 1.0)),
+            Ease::Lerp
+        ));
+        let custom = function(Tween::new(second, Half, 1.0));
+        assert!(matches!(custom, Ease::Custom(_)));
+        assert_eq!(custom.interpolate(0.5), 0.25);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn nested_sequences_are_kept() {
+        let once = |value: f32| Tween::new(Duration::from_secs(1), Lerp, value);
+        let mut tween = Tween::sequence([
+            once(1.0),
+            Tween::sequence([once(2.0), Tween::sequence([once(3.0)])]),
+            Tween::sequence([once(4.0)]).with_completed(NoEvent),
+        ]);
+        let Tween::Sequence { tweens, .. } = &tween else {
+            unreachable!()
+        };
+        assert_eq!(tweens.len(), 3);
 
-    impl TweenApplier<f32> for f32 {
-        fn apply(&mut self, target: &mut f32, value: f32) {
-            *target = *self * value * 2.0;
+        let mut value = 0.0;
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(2500));
+        assert_eq!(value, 3.0);
+    }
+
+    #[test]
+    fn deeply_nested_tween() {
+        let mut tween = Tween::new(Duration::from_secs(1), Lerp, 1.0_f32);
+        for _ in 0..10_000 {
+            tween = Tween::speed(1.0, tween);
         }
+
+        let mut value = 0.0;
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(500));
+        assert_eq!(progress, TweenProgress::Running);
+        assert_eq!(value, 1.0);
     }
 
     #[test]
-    fn tween_once() {
-        let mut tween = Tween::new(Duration::from_secs(2), Lerp, 1.0_f32);
-        tween.skip(Duration::from_millis(3));
+    fn deeply_nested_traversals() {
+        let second = Duration::from_secs(1);
+        let mut tween = Tween::new(second, Lerp, 1.0_f32);
+        for i in 0..10_000 {
+            tween = if i % 2 == 0 {
+                Tween::speed(1.0, tween)
+            } else {
+                Tween::sequence([tween])
+            };
+        }
+        let tween = Tween::reversed(tween).with_total_duration(second * 2);
+        let mut tween = Tween::sequence([tween, Tween::pause(second)]);
 
         let mut value = 0.0;
-        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1));
+        tween.advance(&mut value, &mut NoEvent, second);
+        assert_eq!(tween.duration(), Some(second * 3));
+        assert_eq!(tween.elapsed(), Some(second));
+        assert!(!tween.is_finished());
 
-        let Tween::Once { elapsed, .. } = tween else {
-            panic!()
-        };
-        assert_eq!(elapsed, Duration::from_millis(4));
-        assert_eq!(value, 0.004);
+        let state = tween.state();
+        tween.state_into(&mut Tween::<f32, NoEvent>::pause(second).state());
+        tween.rewind(&mut value, second / 2);
+        assert_eq!(tween.elapsed(), Some(second / 2));
+        tween.restore_state(&state).unwrap();
+        assert_eq!(tween.elapsed(), Some(second));
+
+        assert!(tween.finish(&mut value, &mut NoEvent));
+        tween.reset();
+        tween.reset_deep();
+        tween.seed(1);
+        assert_eq!(tween.elapsed(), Some(Duration::ZERO));
     }
 
     #[test]
-    fn tween_repeat() {
-        let mut tween = Tween::repeat(
-            RepeatTimes::N(2),
-            Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
-        );
+    fn panicking_applier_keeps_tree() {
+        #[derive(Clone)]
+        struct Panicking;
+
+        impl TweenApplier<f32> for Panicking {
+            fn apply(&mut self, _target: &mut f32, value: f32) {
+                assert!(value < 0.5, "applier failed");
+            }
+        }
 
+        let second = Duration::from_secs(1);
+        let mut tween = Tween::sequence([
+            Tween::new(second, Lerp, 1.0_f32),
+            Tween::speed(1.0, Tween::new(second, Lerp, Panicking)),
+        ]);
         let mut value = 0.0;
-        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1500));
-        assert_eq!(progress, TweenProgress::Running);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            tween.advance(&mut value, &mut NoEvent, second * 7 / 4)
+        }));
+        assert!(result.is_err());
+        // Children stepped when the applier panicked are back in place
+        assert_eq!(tween.duration(), Some(second * 2));
+    }
 
-        let Tween::Repeat {
-            count,
-            tween: ref delegate,
-            ..
-        } = tween
-        else {
-            panic!()
-        };
-        assert_eq!(count, 1);
-        let Tween::Once { elapsed, .. } = **delegate else {
-            panic!();
-        };
-        assert_eq!(elapsed, Duration::from_millis(500));
-        assert_eq!(value, 2.0);
+    #[test]
+    fn tween_set() {
+        #[derive(Clone)]
+        struct Count;
 
-        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(505));
-        assert_eq!(
-            progress,
-            TweenProgress::Done {
-                surplus: Duration::from_millis(5)
+        impl TweenApplier<(f32, usize)> for Count {
+            fn apply(&mut self, target: &mut (f32, usize), value: f32) {
+                target.0 = value;
+                target.1 += 1;
             }
-        );
-        let Tween::Repeat {
-            count,
-            tween: delegate,
-            ..
-        } = tween
-        else {
-            panic!()
-        };
-        assert_eq!(count, 2);
-        let Tween::Once { elapsed, .. } = *delegate else {
-            panic!();
-        };
-        assert_eq!(elapsed, Duration::from_millis(0));
-        assert_eq!(value, 4.0);
+        }
+
+        let mut tween: Tween<_, NoEvent> =
+            Tween::parallel([Tween::set(Count), Tween::pause(Duration::from_secs(1))]);
+        let mut target = (0.0, 0);
+        tween.advance(&mut target, &mut NoEvent, Duration::ZERO);
+        assert_eq!(target, (1.0, 1));
+        tween.advance(&mut target, &mut NoEvent, Duration::from_millis(500));
+        assert_eq!(target, (1.0, 1));
     }
 
     #[test]
-    fn tween_sequence() {
-        let mut tween = Tween::sequence(vec![
-            Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
+    fn tween_rewind() {
+        let mut tween = Tween::sequence([
+            Tween::new(Duration::from_secs(1), Lerp, 1.0_f32),
             Tween::repeat(
                 RepeatTimes::N(2),
-                Tween::new(Duration::from_secs(1), Lerp, 4.0_f32),
+                Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
             ),
         ]);
 
         let mut value = 0.0;
-        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(500));
+        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_secs(3));
+        assert!(matches!(progress, TweenProgress::Done { .. }));
+        assert_eq!(value, 4.0);
+
+        let progress = tween.rewind(&mut value, Duration::from_millis(1500));
         assert_eq!(progress, TweenProgress::Running);
         assert_eq!(value, 2.0);
 
-        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        let progress = tween.rewind(&mut value, Duration::from_millis(1000));
         assert_eq!(progress, TweenProgress::Running);
-        assert_eq!(value, 4.0);
+        assert_eq!(value, 1.0);
 
-        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(2000));
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(value, 2.0);
+
+        let progress = tween.rewind(&mut value, Duration::from_secs(5));
         assert_eq!(
             progress,
             TweenProgress::Done {
-                surplus: Duration::from_millis(500)
+                surplus: Duration::from_millis(3500)
             }
         );
-        assert_eq!(value, 8.0);
+        assert_eq!(value, 0.0);
     }
 
     #[test]
-    fn tween_sequence_with_endless_loop() {
-        let mut tween = Tween::sequence(vec![
-            Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
+    fn tween_quantized() {
+        let mut tween = Tween::new(
+            Duration::from_secs(3),
+            EaseFunction::SineInOut,
+            Quantized::new(0.5_f32, 4),
+        );
+
+        let mut value = 0.0;
+        tween.advance(&mut value, &mut NoEvent, Duration::from_secs(1));
+        assert_eq!(value, 0.25);
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(800));
+        assert_eq!(value, 0.75);
+    }
+
+    #[test]
+    fn interpolator_sample_n() {
+        assert_eq!(Lerp.sample_n(5), [0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(Lerp.sample_n(1), [0.0]);
+    }
+
+    #[test]
+    fn tween_elapsed() {
+        let mut tween = Tween::sequence([
+            Tween::new(Duration::from_secs(1), Lerp, 1.0_f32),
             Tween::repeat(
-                RepeatTimes::Infinite,
-                Tween::new(Duration::from_secs(1), Lerp, 5.0_f32),
+                RepeatTimes::N(3),
+                Tween::speed(2.0, Tween::pause(Duration::from_secs(2))),
             ),
         ]);
 
+        tween.advance(&mut 0.0, &mut NoEvent, Duration::from_millis(2500));
+        assert_eq!(tween.elapsed(), Some(Duration::from_millis(2500)));
+        tween.advance(&mut 0.0, &mut NoEvent, Duration::from_secs(10));
+        assert_eq!(tween.elapsed(), tween.duration());
+    }
+
+    #[test]
+    fn tween_parallel_padded() {
+        let mut tween = Tween::parallel_padded([
+            Tween::new_with_event(Duration::from_secs(1), Lerp, 1.0_f32, 1),
+            Tween::new_with_event(Duration::from_secs(3), Lerp, 1.0_f32, 2),
+            Tween::pause(Duration::from_secs(2)),
+        ]);
+
+        let mut events = Vec::new();
+        let Tween::Parallel { tweens, .. } = &tween else {
+            panic!("Expected a parallel tween");
+        };
+        for tween in tweens {
+            assert_eq!(tween.duration(), Some(Duration::from_secs(3)));
+        }
+        let progress = tween.advance(&mut 0.0, &mut events, Duration::from_secs(4));
+        assert_eq!(
+            progress,
+            TweenProgress::Done {
+                surplus: Duration::from_secs(1)
+            }
+        );
+        assert_eq!(events, [1, 2]);
+    }
+
+    #[test]
+    fn tween_parallel_synced() {
+        let mut tween = Tween::parallel_synced([
+            Tween::sequence([
+                Tween::pause(Duration::from_secs(1)),
+                Tween::new(Duration::from_secs(1), Lerp, Element(0)),
+            ]),
+            Tween::new(Duration::from_secs(4), Lerp, Element(1)),
+        ]);
+
+        let mut values = [0.0; 2];
+        tween.advance(&mut values, &mut NoEvent, Duration::from_secs(3));
+        assert_eq!(values, [0.5, 0.75]);
+        assert_eq!(tween.duration(), Some(Duration::from_secs(4)));
+    }
+
+    #[test]
+    fn tween_with_total_duration() {
+        let mut tween = Tween::sequence([
+            Tween::new(Duration::from_secs(1), Lerp, Element(0)),
+            Tween::repeat(
+                RepeatTimes::N(3),
+                Tween::new(Duration::from_secs(1), Lerp, Element(1)),
+            ),
+        ])
+        .with_total_duration(Duration::from_secs(2));
+
+        let mut values = [0.0; 2];
+        tween.advance(&mut values, &mut NoEvent, Duration::from_millis(750));
+        assert_eq!(values, [1.0, 0.5]);
+        assert_eq!(tween.duration(), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn tween_from_velocity() {
+        let mut tween = Tween::<_, NoEvent>::from_velocity(10.0, 5.0, |distance| TweenF64 {
+            start: 0.0,
+            end: distance as f64,
+        });
+
         let mut value = 0.0;
-        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(20000));
-        assert_eq!(progress, TweenProgress::Running);
-        assert_eq!(value, 0.0);
+        assert_eq!(tween.duration(), Some(Duration::from_secs(2)));
+        tween.advance(&mut value, &mut NoEvent, Duration::from_secs(1));
+        assert!((value - 7.5).abs() < 1e-5);
+        tween.advance(&mut value, &mut NoEvent, Duration::from_secs(1));
+        assert_eq!(value, 10.0);
     }
 
     #[test]
-    fn tween_parallel() {
-        let mut tween = Tween::parallel(vec![
-            Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
+    fn tween_output_velocity() {
+        let mut tween = Tween::<_, NoEvent>::new(
+            Duration::from_secs(2),
+            Lerp,
+            TweenF64 {
+                start: 0.0,
+                end: 10.0,
+            },
+        );
+        let mut value = 0.0;
+        tween.advance(&mut value, &mut NoEvent, Duration::from_secs(1));
+
+        let velocity = tween.output_velocity(&value, |value| *value as f32);
+        assert!((velocity - 5.0).abs() < 1e-3);
+
+        tween.advance(&mut value, &mut NoEvent, Duration::from_secs(1));
+        assert_eq!(tween.output_velocity(&value, |value| *value as f32), 0.0);
+    }
+
+    #[test]
+    fn tween_seek() {
+        let mut tween = Tween::sequence([
+            Tween::new_with_event(Duration::from_secs(1), Lerp, Element(0), 1),
+            Tween::new_with_event(Duration::from_secs(1), Lerp, Element(1), 2),
+        ]);
+
+        let mut values = [0.0; 2];
+        assert!(tween.seek(&mut values, Duration::from_millis(1500)));
+        assert_eq!(values, [1.0, 0.5]);
+        assert!(tween.seek(&mut values, Duration::from_millis(500)));
+        assert_eq!(values, [0.5, 0.0]);
+        assert_eq!(tween.elapsed(), Some(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn tween_applier_hooks() {
+        #[derive(Clone)]
+        struct Hooks;
+
+        impl TweenApplier<Vec<&'static str>> for Hooks {
+            fn apply(&mut self, _target: &mut Vec<&'static str>, _value: f32) {}
+
+            fn on_start(&mut self, target: &mut Vec<&'static str>) {
+                target.push("start");
+            }
+
+            fn on_complete(&mut self, target: &mut Vec<&'static str>) {
+                target.push("complete");
+            }
+
+            fn on_loop(&mut self, target: &mut Vec<&'static str>) {
+                target.push("loop");
+            }
+        }
+
+        let mut tween = Tween::<_, NoEvent>::repeat(
+            RepeatTimes::N(2),
+            Tween::new(Duration::from_secs(1), Lerp, Hooks),
+        );
+        let mut calls = Vec::new();
+        tween.advance(&mut calls, &mut NoEvent, Duration::from_millis(500));
+        tween.advance(&mut calls, &mut NoEvent, Duration::from_secs(2));
+
+        assert_eq!(calls, ["start", "complete", "loop", "start", "complete"]);
+    }
+
+    #[test]
+    fn tween_markers() {
+        let mut tween = Tween::new_with_event(Duration::from_secs(1), Lerp, 1.0_f32, "done")
+            .with_marker(Duration::from_millis(350), "impact")
+            .with_marker(Duration::ZERO, "swing");
+
+        let mut events = Vec::new();
+        tween.advance(&mut 0.0, &mut events, Duration::from_millis(100));
+        assert_eq!(events, ["swing"]);
+        tween.advance(&mut 0.0, &mut events, Duration::from_secs(2));
+        assert_eq!(events, ["swing", "impact", "done"]);
+    }
+
+    #[test]
+    fn tween_overall_progress() {
+        let mut tween = Tween::<f32, NoEvent>::repeat(
+            RepeatTimes::N(4),
+            Tween::new(Duration::from_secs(1), Lerp, 1.0_f32),
+        );
+
+        tween.advance(&mut 0.0, &mut NoEvent, Duration::from_millis(1000));
+        assert_eq!(
+            tween.overall_progress(),
+            Some((0.25, Duration::from_secs(3)))
+        );
+        tween.advance(&mut 0.0, &mut NoEvent, Duration::from_secs(10));
+        assert_eq!(tween.overall_progress(), Some((1.0, Duration::ZERO)));
+        assert_eq!(
+            Tween::<f32, NoEvent>::repeat(RepeatTimes::Infinite, Tween::pause(Duration::ZERO))
+                .overall_progress(),
+            None
+        );
+    }
+
+    #[test]
+    fn tween_finish() {
+        let mut tween = Tween::sequence([
+            Tween::new_with_event(Duration::from_secs(1), Lerp, 1.0_f32, 1),
             Tween::repeat(
                 RepeatTimes::N(2),
-                Tween::new(Duration::from_secs(1), Lerp, 4.0_f32),
+                Tween::new_with_event(Duration::from_secs(1), Lerp, 2.0_f32, 2),
             ),
         ]);
 
         let mut value = 0.0;
-        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
-        assert_eq!(progress, TweenProgress::Running);
+        let mut events = Vec::new();
+        tween.advance(&mut value, &mut events, Duration::from_millis(500));
+        assert!(tween.finish(&mut value, &mut events));
+        assert_eq!(value, 4.0);
+        assert_eq!(events, [1, 2, 2]);
+
+        let mut infinite = Tween::repeat(
+            RepeatTimes::Infinite,
+            Tween::new(Duration::from_secs(1), Lerp, 1.0_f32),
+        );
+        assert!(!infinite.finish(&mut value, &mut NoEvent));
+    }
+
+    #[test]
+    fn tween_is_finished() {
+        let mut tween = Tween::sequence([
+            Tween::new(Duration::from_secs(1), Lerp, 1.0_f32),
+            Tween::parallel([
+                Tween::pause(Duration::from_secs(1)),
+                Tween::reversed(Tween::new(Duration::from_secs(2), Lerp, 1.0_f32)),
+            ]),
+        ]);
+
+        tween.advance(&mut 0.0, &mut NoEvent, Duration::from_secs(2));
+        assert!(!tween.is_finished());
+        tween.advance(&mut 0.0, &mut NoEvent, Duration::from_secs(1));
+        assert!(tween.is_finished());
+    }
+
+    #[test]
+    fn tween_f64_precision() {
+        let mut tween = Tween::new(
+            Duration::from_secs(3),
+            Lerp,
+            TweenF64 {
+                start: 0.0,
+                end: 1e12,
+            },
+        );
+
+        let mut value = 0.0;
+        tween.advance(&mut value, &mut NoEvent, Duration::from_secs(1));
+        assert!((value - 1e12 / 3.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn tween_restore_state() {
+        let build = || {
+            Tween::sequence([
+                Tween::new(Duration::from_secs(1), Lerp, 1.0_f32),
+                Tween::repeat(
+                    RepeatTimes::N(3),
+                    Tween::new(Duration::from_secs(1), Lerp, 2.0_f32),
+                ),
+            ])
+        };
+        let mut tween = build();
+        let mut value = 0.0;
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(2500));
+        let state = tween.state();
+
+        let mut restored = build();
+        restored.restore_state(&state).unwrap();
+        assert_eq!(restored.state(), state);
+        let mut restored_value = 0.0;
+        tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1250));
+        restored.advance(
+            &mut restored_value,
+            &mut NoEvent,
+            Duration::from_millis(1250),
+        );
+        assert_eq!(restored_value, value);
+
+        let mut reused = build().state();
+        tween.state_into(&mut reused);
+        assert_eq!(reused, tween.state());
+        let mut mismatched = Tween::<f32, NoEvent>::pause(Duration::from_secs(1)).state();
+        tween.state_into(&mut mismatched);
+        assert_eq!(mismatched, tween.state());
 
-        let progress = tween.advance(&mut value, &mut NoEvent, Duration::from_millis(1000));
         assert_eq!(
-            progress,
-            TweenProgress::Done {
-                surplus: Duration::ZERO
-            }
+            Tween::<f32, NoEvent>::pause(Duration::from_secs(1)).restore_state(&state),
+            Err(TweenStateMismatch)
         );
     }
 }
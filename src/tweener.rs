@@ -0,0 +1,246 @@
+use crate::tween::*;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Plays a tween without Bevy, ie. in tools, headless servers or other engines. Completion events
+/// are collected until taken with [`Tweener::drain_events`].
+#[derive(Clone)]
+pub struct Tweener<T, E = NoEvent> {
+    tween: Tween<T, E>,
+    stack: TweenStack<T, E>,
+    progress: TweenProgress,
+    events: Vec<E>,
+}
+
+impl<T, E: Clone> Tweener<T, E> {
+    pub fn new(tween: Tween<T, E>) -> Self {
+        Self {
+            tween,
+            stack: TweenStack::default(),
+            progress: TweenProgress::Running,
+            events: Vec::new(),
+        }
+    }
+
+    /// Advances the tween by `dt` and applies its values to `target`. Does nothing once the tween
+    /// completed.
+    pub fn update(&mut self, dt: Duration, target: &mut T) -> TweenProgress {
+        if self.progress == TweenProgress::Running {
+            self.progress = self
+                .tween
+                .advance_with(&mut self.stack, target, &mut self.events, dt);
+        }
+        self.progress
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress != TweenProgress::Running
+    }
+
+    pub fn drain_events(&mut self) -> impl Iterator<Item = E> + '_ {
+        self.events.drain(..)
+    }
+
+    pub fn tween(&self) -> &Tween<T, E> {
+        &self.tween
+    }
+
+    /// Changes to the tween take effect with the next update - even if it already completed.
+    pub fn tween_mut(&mut self) -> &mut Tween<T, E> {
+        self.progress = TweenProgress::Running;
+        &mut self.tween
+    }
+}
+
+/// Plays `tween` on `target` in steps of `step` until `total`, recording the target after each
+/// step - starting with the unchanged target at `0`. Ie. for golden file tests or plotting
+/// animations. The last step is shortened to end at `total`, events are dropped.
+///
+/// Panics if `step` is zero.
+pub fn simulate<T: Clone, E>(
+    mut tween: Tween<T, E>,
+    mut target: T,
+    step: Duration,
+    total: Duration,
+) -> Vec<(Duration, T)> {
+    assert!(!step.is_zero(), "Simulation step must not be zero");
+    let mut elapsed = Duration::ZERO;
+    let mut samples = vec![(elapsed, target.clone())];
+    while elapsed < total {
+        let dt = step.min(total - elapsed);
+        tween.advance(&mut target, &mut NoEvent, dt);
+        elapsed += dt;
+        samples.push((elapsed, target.clone()));
+    }
+    samples
+}
+
+/// The values of a tween over time, sampled at any time - see [`Tween::to_curve`].
+///
+/// Samples are played on a copy of the tween, which continues from the previous sample when
+/// sampling forward and starts over otherwise. Randomized tweens give the same values each time,
+/// as the copies share the random state of the tween.
+pub struct TweenCurve<T, E> {
+    tween: Tween<T, E>,
+    initial: T,
+    scratch: Mutex<CurveScratch<T, E>>,
+}
+
+struct CurveScratch<T, E> {
+    tween: Tween<T, E>,
+    target: T,
+    elapsed: Duration,
+}
+
+impl<T: Clone, E: Clone> Clone for TweenCurve<T, E> {
+    fn clone(&self) -> Self {
+        Self::new(self.tween.clone(), self.initial.clone())
+    }
+}
+
+impl<T: Clone, E: Clone> TweenCurve<T, E> {
+    fn new(tween: Tween<T, E>, initial: T) -> Self {
+        let scratch = CurveScratch {
+            tween: tween.clone(),
+            target: initial.clone(),
+            elapsed: Duration::ZERO,
+        };
+        Self {
+            tween,
+            initial,
+            scratch: Mutex::new(scratch),
+        }
+    }
+
+    /// The time the curve spans, if the duration of the tween is known.
+    pub fn domain(&self) -> Option<Duration> {
+        self.tween.duration()
+    }
+
+    /// The target after playing the tween for `time`, starting with the initial target. Events
+    /// are dropped.
+    pub fn sample(&self, time: Duration) -> T {
+        let mut scratch = self.scratch.lock().unwrap_or_else(|poisoned| {
+            // An applier panicked while sampling, start over
+            let mut scratch = poisoned.into_inner();
+            scratch.elapsed = Duration::MAX;
+            scratch
+        });
+        let scratch = &mut *scratch;
+        if time < scratch.elapsed {
+            scratch.tween.clone_from(&self.tween);
+            scratch.target.clone_from(&self.initial);
+            scratch.elapsed = Duration::ZERO;
+        }
+        scratch
+            .tween
+            .advance(&mut scratch.target, &mut NoEvent, time - scratch.elapsed);
+        scratch.elapsed = time;
+        scratch.target.clone()
+    }
+}
+
+impl<T: Clone, E: Clone> Tween<T, E> {
+    /// This tween as a curve, playing it from its start on `initial` - ie. to sample it in other
+    /// code taking curves.
+    pub fn to_curve(&self, initial: T) -> TweenCurve<T, E> {
+        let mut tween = self.clone();
+        tween.reset_deep();
+        TweenCurve::new(tween, initial)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tweener_update() {
+        let mut tweener = Tweener::new(Tween::new_with_event(
+            Duration::from_secs(1),
+            Lerp,
+            SetValue,
+            "done",
+        ));
+
+        let mut value = 0.0;
+        tweener.update(Duration::from_millis(500), &mut value);
+        assert_eq!(value, 0.5);
+        assert!(!tweener.is_finished());
+        assert_eq!(tweener.drain_events().count(), 0);
+
+        tweener.update(Duration::from_millis(750), &mut value);
+        assert_eq!(value, 1.0);
+        assert!(tweener.is_finished());
+        assert_eq!(tweener.drain_events().collect::<Vec<_>>(), ["done"]);
+
+        tweener.update(Duration::from_millis(500), &mut value);
+        assert_eq!(tweener.drain_events().count(), 0);
+    }
+
+    #[test]
+    fn curve_samples_tween() {
+        let mut tween = Tween::<f32, NoEvent>::new(Duration::from_secs(1), Lerp, SetValue);
+        tween.skip(Duration::from_millis(500));
+
+        let curve = tween.to_curve(0.0);
+        assert_eq!(curve.domain(), Some(Duration::from_secs(1)));
+        assert_eq!(curve.sample(Duration::from_millis(250)), 0.25);
+        assert_eq!(curve.sample(Duration::from_secs(2)), 1.0);
+        assert_eq!(curve.sample(Duration::from_millis(750)), 0.75);
+    }
+
+    #[test]
+    fn curve_samples_random_tween_consistently() {
+        #[derive(Clone)]
+        struct Offset(f32);
+
+        impl TweenApplier<f32> for Offset {
+            fn apply(&mut self, target: &mut f32, value: f32) {
+                *target = self.0 + value;
+            }
+        }
+
+        let tween = Tween::<f32, NoEvent>::repeat(
+            RepeatTimes::N(8),
+            Tween::choose(
+                (0..4)
+                    .map(|offset| Tween::new(Duration::from_secs(1), Lerp, Offset(offset as f32)))
+                    .collect::<Vec<_>>(),
+            ),
+        );
+
+        let curve = tween.to_curve(0.0);
+        let samples: Vec<_> = (0..16)
+            .map(|i| curve.sample(Duration::from_millis(500 * i)))
+            .collect();
+        for (i, sample) in samples.iter().enumerate().rev() {
+            assert_eq!(curve.sample(Duration::from_millis(500 * i as u64)), *sample);
+        }
+        assert_eq!(
+            curve.clone().sample(Duration::from_millis(7500)),
+            samples[15]
+        );
+    }
+
+    #[test]
+    fn simulate_samples_target() {
+        let tween = Tween::<f32, NoEvent>::new(Duration::from_secs(1), Lerp, SetValue);
+
+        let samples = simulate(
+            tween,
+            0.0,
+            Duration::from_millis(400),
+            Duration::from_secs(1),
+        );
+        assert_eq!(
+            samples,
+            [
+                (Duration::ZERO, 0.0),
+                (Duration::from_millis(400), 0.4),
+                (Duration::from_millis(800), 0.8),
+                (Duration::from_secs(1), 1.0),
+            ]
+        );
+    }
+}
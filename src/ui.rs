@@ -0,0 +1,327 @@
+use crate::easing::Easing;
+use crate::plugin::PlayTween;
+use crate::tween::{Tween, TweenApplier};
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// A property of a UI node, which a [`UiTransition`] animates.
+pub trait TransitionProperty: Send + Sync + 'static {
+    type Target: Component;
+    type Value: Copy + Send + Sync + 'static;
+
+    fn get(target: &Self::Target) -> Self::Value;
+    fn set(target: &mut Self::Target, value: Self::Value);
+    fn mix(start: Self::Value, end: Self::Value, value: f32) -> Self::Value;
+
+    /// Like [`TransitionProperty::mix`], but in full precision. Rounds to `f32` by default.
+    fn mix_f64(start: Self::Value, end: Self::Value, value: f64) -> Self::Value {
+        Self::mix(start, end, value as f32)
+    }
+}
+
+/// Transitions the [`BackgroundColor`].
+pub struct TransitionBackgroundColor;
+
+/// Transitions the scale of the [`Transform`].
+pub struct TransitionScale;
+
+/// Where a [`UiTransition`] goes, once its state is reached.
+#[derive(Clone, Copy, Debug)]
+pub struct TransitionTo<V> {
+    pub value: V,
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl<V> TransitionTo<V> {
+    pub fn new(value: V, duration: Duration, easing: Easing) -> Self {
+        Self {
+            value,
+            duration,
+            easing,
+        }
+    }
+}
+
+/// Animates a property of a UI node whenever its [`Interaction`] changes, starting from the
+/// current value. The transition replaces a running [`PlayTween`] of the same target.
+#[derive(Component)]
+pub struct UiTransition<P: TransitionProperty> {
+    pub none: TransitionTo<P::Value>,
+    pub hovered: TransitionTo<P::Value>,
+    pub pressed: TransitionTo<P::Value>,
+}
+
+impl<P: TransitionProperty> UiTransition<P> {
+    pub fn new(
+        none: TransitionTo<P::Value>,
+        hovered: TransitionTo<P::Value>,
+        pressed: TransitionTo<P::Value>,
+    ) -> Self {
+        Self {
+            none,
+            hovered,
+            pressed,
+        }
+    }
+
+    pub fn get(&self, interaction: Interaction) -> TransitionTo<P::Value> {
+        match interaction {
+            Interaction::None => self.none,
+            Interaction::Hovered => self.hovered,
+            Interaction::Pressed => self.pressed,
+        }
+    }
+}
+
+impl UiTransition<TransitionScale> {
+    /// Squashes a button when pressed and lets it overshoot back on release. `intensity` is the
+    /// fraction the height shrinks by, the width shrinks by half of it.
+    pub fn squash(intensity: f32, press: Duration, release: Duration) -> Self {
+        let rest = TransitionTo::new(Vec3::ONE, release, Easing::BackOut);
+        let squashed = Vec3::new(1.0 - intensity / 2.0, 1.0 - intensity, 1.0);
+        Self::new(
+            rest,
+            rest,
+            TransitionTo::new(squashed, press, Easing::QuadraticOut),
+        )
+    }
+}
+
+impl<P: TransitionProperty> Clone for UiTransition<P> {
+    fn clone(&self) -> Self {
+        Self::new(self.none, self.hovered, self.pressed)
+    }
+}
+
+struct TransitionApplier<P: TransitionProperty> {
+    start: P::Value,
+    end: P::Value,
+}
+
+impl<P: TransitionProperty> Clone for TransitionApplier<P> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<P: TransitionProperty> TweenApplier<P::Target> for TransitionApplier<P> {
+    fn apply(&mut self, target: &mut P::Target, value: f32) {
+        P::set(target, P::mix(self.start, self.end, value));
+    }
+
+    fn apply_f64(&mut self, target: &mut P::Target, value: f64) {
+        P::set(target, P::mix_f64(self.start, self.end, value));
+    }
+}
+
+/// Starts the transitions of nodes whose [`Interaction`] changed. They are played in real time,
+/// so the UI stays responsive while the game is paused.
+pub fn ui_transition<P: TransitionProperty, E: Send + Sync + 'static>(
+    nodes: Query<(Entity, &Interaction, &UiTransition<P>, &P::Target), Changed<Interaction>>,
+    mut commands: Commands,
+) {
+    for (entity, interaction, transition, target) in nodes.iter() {
+        let to = transition.get(*interaction);
+        let applier = TransitionApplier::<P> {
+            start: P::get(target),
+            end: to.value,
+        };
+        let tween = Tween::<P::Target, E>::once(to.duration, to.easing, applier, None);
+        commands
+            .entity(entity)
+            .insert(PlayTween::new_with_time(tween) as PlayTween<_, _, Real>);
+    }
+}
+
+impl TransitionProperty for TransitionBackgroundColor {
+    type Target = BackgroundColor;
+    type Value = Color;
+
+    fn get(target: &BackgroundColor) -> Color {
+        target.0
+    }
+
+    fn set(target: &mut BackgroundColor, value: Color) {
+        target.0 = value;
+    }
+
+    fn mix(start: Color, end: Color, value: f32) -> Color {
+        start.mix(&end, value)
+    }
+}
+
+impl TransitionProperty for TransitionScale {
+    type Target = Transform;
+    type Value = Vec3;
+
+    fn get(target: &Transform) -> Vec3 {
+        target.scale
+    }
+
+    fn set(target: &mut Transform, value: Vec3) {
+        target.scale = value;
+    }
+
+    fn mix(start: Vec3, end: Vec3, value: f32) -> Vec3 {
+        start.lerp(end, value)
+    }
+}
+
+/// Scrolls content horizontally by moving it to the left, setting [`Style::left`] to `-offset`
+/// pixels.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct TweenScrollOffset {
+    pub start: f32,
+    pub end: f32,
+}
+
+impl TweenApplier<Style> for TweenScrollOffset {
+    fn apply(&mut self, target: &mut Style, value: f32) {
+        target.left = Val::Px(-self.start.lerp(self.end, value));
+    }
+}
+
+/// Snaps horizontally scrolled pages (ie. of a carousel) into place, once dragging them ended.
+#[derive(Debug, Clone, Copy)]
+pub struct PageSnap {
+    pub page_width: f32,
+    pub pages: usize,
+    /// Releasing faster than this (in pixels per second) moves on to the next page in the
+    /// direction of the drag, instead of the nearest one.
+    pub fling_velocity: f32,
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl PageSnap {
+    pub fn new(page_width: f32, pages: usize) -> Self {
+        Self {
+            page_width,
+            pages,
+            fling_velocity: 300.0,
+            duration: Duration::from_millis(300),
+            easing: Easing::CubicOut,
+        }
+    }
+
+    /// The page to snap to, when released at `offset` moving at `velocity` (positive towards
+    /// later pages).
+    pub fn target_page(&self, offset: f32, velocity: f32) -> usize {
+        let position = offset / self.page_width.max(f32::EPSILON);
+        let page = if velocity >= self.fling_velocity {
+            position.floor() + 1.0
+        } else if velocity <= -self.fling_velocity {
+            position.ceil() - 1.0
+        } else {
+            position.round()
+        };
+        (page.max(0.0) as usize).min(self.pages.saturating_sub(1))
+    }
+
+    /// A tween from `offset` to the page to snap to, to be played on the scrolled node.
+    pub fn snap<E>(&self, offset: f32, velocity: f32) -> Tween<Style, E> {
+        let end = self.target_page(offset, velocity) as f32 * self.page_width;
+        Tween::once(
+            self.duration,
+            self.easing,
+            TweenScrollOffset { start: offset, end },
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::play_tween_animation;
+    use crate::tween::NoEvent;
+
+    #[test]
+    fn test_ui_transition() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<Real>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let transition_id =
+            world.register_system(ui_transition::<TransitionBackgroundColor, NoEvent>);
+        let play_tween_id =
+            world.register_system(play_tween_animation::<BackgroundColor, NoEvent, Real>);
+        let to = |color: Color| TransitionTo::new(color, Duration::from_secs(2), Easing::Linear);
+        let button = world
+            .spawn((
+                Interaction::Hovered,
+                BackgroundColor(Color::BLACK),
+                UiTransition::<TransitionBackgroundColor>::new(
+                    to(Color::BLACK),
+                    to(Color::WHITE),
+                    to(Color::BLACK),
+                ),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(transition_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        assert_eq!(
+            world.get::<BackgroundColor>(button).unwrap().0,
+            Color::BLACK.mix(&Color::WHITE, 0.5)
+        );
+    }
+
+    #[test]
+    fn test_squash_preset() {
+        // GIVEN
+        let mut world = World::new();
+        let mut time = Time::<Real>::default();
+        time.advance_by(Duration::from_millis(100));
+        world.insert_resource(time);
+        world.init_resource::<Events<NoEvent>>();
+        let transition_id = world.register_system(ui_transition::<TransitionScale, NoEvent>);
+        let play_tween_id = world.register_system(play_tween_animation::<Transform, NoEvent, Real>);
+        let button = world
+            .spawn((
+                Interaction::Pressed,
+                Transform::default(),
+                UiTransition::squash(0.2, Duration::from_millis(100), Duration::from_millis(300)),
+            ))
+            .id();
+
+        // WHEN
+        world.run_system(transition_id).unwrap();
+        world.run_system(play_tween_id).unwrap();
+
+        // THEN
+        assert_eq!(
+            world.get::<Transform>(button).unwrap().scale,
+            Vec3::new(0.9, 0.8, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_page_snap() {
+        let snap = PageSnap::new(100.0, 3);
+
+        assert_eq!(snap.target_page(140.0, 0.0), 1);
+        assert_eq!(snap.target_page(140.0, 500.0), 2);
+        assert_eq!(snap.target_page(160.0, -500.0), 1);
+        assert_eq!(snap.target_page(260.0, 500.0), 2);
+
+        let mut style = Style::default();
+        let mut events = Vec::new();
+        snap.snap(140.0, 500.0).with_completed("snapped").advance(
+            &mut style,
+            &mut events,
+            Duration::from_secs(1),
+        );
+        assert_eq!(style.left, Val::Px(-200.0));
+        assert_eq!(events, ["snapped"]);
+    }
+}